@@ -38,7 +38,10 @@ fn default_config() -> cbindgen::Config {
                 ("VoidArg".into(), "void".into()),
                 ("KeyEventArg".into(), "KeyEvent".into()),
                 ("PointerEventArg".into(), "PointerEvent".into()),
+                ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
                 ("PointArg".into(), "Point".into()),
+                ("DropEventArg".into(), "DropEvent".into()),
+                ("SwipeDirectionArg".into(), "SwipeDirection".into()),
             ]
             .iter()
             .cloned()
@@ -93,6 +96,11 @@ fn gen_corelib(
         "ClippedImage",
         "TouchArea",
         "FocusScope",
+        "DragArea",
+        "DropArea",
+        "GestureArea",
+        "Shortcut",
+        "TimerItem",
         "Flickable",
         "Text",
         "Path",
@@ -110,6 +118,8 @@ fn gen_corelib(
         "WindowRcOpaque",
         "PropertyAnimation",
         "EasingCurve",
+        "AnimationDirection",
+        "KeyFrame",
         "TextHorizontalAlignment",
         "TextVerticalAlignment",
         "TextOverflow",
@@ -122,6 +132,14 @@ fn gen_corelib(
         "PointerEventKind",
         "PointerEventButton",
         "PointerEvent",
+        "PointerScrollEvent",
+        "DropEvent",
+        "SwipeDirection",
+        "CloseRequestResponse",
+        "AccessibleRole",
+        "AccessibleStringProperty",
+        "ElementMouseEvent",
+        "ElementTouchGesture",
     ]
     .iter()
     .chain(items.iter())
@@ -146,18 +164,22 @@ fn gen_corelib(
         "sixtyfps_property_listener_scope_evaluate",
         "sixtyfps_property_listener_scope_is_dirty",
         "PropertyTrackerOpaque",
+        "ChangeTrackerOpaque",
         "CallbackOpaque",
         "WindowRc",
         "VoidArg",
         "KeyEventArg",
         "PointerEventArg",
+        "PointerScrollEventArg",
         "PointArg",
         "Point",
+        "Size",
         "sixtyfps_color_brighter",
         "sixtyfps_color_darker",
         "sixtyfps_image_size",
         "sixtyfps_image_path",
-        "TimerMode", // included in generated_public.h
+        "TimerMode",   // included in generated_public.h
+        "WindowState", // included in generated_public.h
     ]
     .iter()
     .map(|x| x.to_string())
@@ -240,7 +262,7 @@ fn gen_corelib(
             "sixtyfps_pathdata_internal.h",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop"],
+            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradientBrush"],
             vec!["Color"],
             "sixtyfps_brush_internal.h",
         ),
@@ -261,6 +283,16 @@ fn gen_corelib(
             "sixtyfps_windowrc_set_focus_item",
             "sixtyfps_windowrc_set_component",
             "sixtyfps_windowrc_show_popup",
+            "sixtyfps_windowrc_is_child_window_shown",
+            "sixtyfps_windowrc_show_child_window",
+            "sixtyfps_windowrc_hide_child_window",
+            "sixtyfps_windowrc_position",
+            "sixtyfps_windowrc_set_position",
+            "sixtyfps_windowrc_size",
+            "sixtyfps_windowrc_set_size",
+            "sixtyfps_windowrc_window_state",
+            "sixtyfps_windowrc_set_window_state",
+            "sixtyfps_windowrc_set_title",
             "sixtyfps_new_path_elements",
             "sixtyfps_new_path_events",
             "sixtyfps_color_brighter",
@@ -308,12 +340,13 @@ fn gen_corelib(
     let mut public_config = config.clone();
     public_config.namespaces = Some(vec!["sixtyfps".into()]);
     public_config.export.item_types = vec![cbindgen::ItemType::Enums];
-    public_config.export.include = vec!["TimerMode".into()];
+    public_config.export.include = vec!["TimerMode".into(), "WindowState".into()];
     public_config.export.exclude.clear();
 
     cbindgen::Builder::new()
         .with_config(public_config)
         .with_src(crate_dir.join("timers.rs"))
+        .with_src(crate_dir.join("window.rs"))
         .with_after_include(format!(
             r"
 /// This macro expands to the to the numeric value of the major version of SixtyFPS you're
@@ -347,7 +380,10 @@ fn gen_corelib(
     config.export.body.insert(
         "EasingCurve".to_owned(),
         "    constexpr EasingCurve() : tag(Tag::Linear), cubic_bezier{{0,0,1,1}} {}
-    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr explicit EasingCurve(Spring_Body spring) : tag(Tag::Spring), spring(spring) {}
+    constexpr explicit EasingCurve(Steps_Body steps) : tag(Tag::Steps), steps(steps) {}
+    constexpr explicit EasingCurve(Custom_Body custom) : tag(Tag::Custom), custom(custom) {}".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -364,6 +400,11 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("TimerItem".to_owned(), "    inline TimerItem(); inline ~TimerItem();".into());
+    config.export.pre_body.insert("TimerItemDataBox".to_owned(), "struct TimerItemData;".into());
     config.export.include.push("StandardListViewItem".into());
     cbindgen::Builder::new()
         .with_config(config)
@@ -386,7 +427,7 @@ fn gen_corelib(
     namespace cbindgen_private {
         using sixtyfps::private_api::WindowRc;
         using namespace vtable;
-        struct KeyEvent; struct PointerEvent;
+        struct KeyEvent; struct PointerEvent; struct PointerScrollEvent; struct DropEvent;
         using private_api::Property;
         using private_api::PathData;
         using private_api::Point;
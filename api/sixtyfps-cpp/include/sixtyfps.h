@@ -10,6 +10,7 @@
 
 #include <vector>
 #include <memory>
+#include <functional>
 #include <algorithm>
 #include <iostream> // FIXME: remove: iostream always bring it lots of code so we should not have it in this header
 #include <chrono>
@@ -18,6 +19,7 @@
 #include <mutex>
 #include <condition_variable>
 #include <span>
+#include <concepts>
 
 namespace sixtyfps::cbindgen_private {
 // Workaround https://github.com/eqrion/cbindgen/issues/43
@@ -54,6 +56,8 @@ using ItemTreeNode = cbindgen_private::ItemTreeNode<uint8_t>;
 using cbindgen_private::KeyboardModifiers;
 using cbindgen_private::KeyEvent;
 using cbindgen_private::PointerEvent;
+using cbindgen_private::PointerScrollEvent;
+using cbindgen_private::DropEvent;
 using cbindgen_private::StandardListViewItem;
 
 /// Internal function that checks that the API that must be called from the main
@@ -106,6 +110,25 @@ public:
     float scale_factor() const { return sixtyfps_windowrc_get_scale_factor(&inner); }
     void set_scale_factor(float value) const { sixtyfps_windowrc_set_scale_factor(&inner, value); }
 
+    cbindgen_private::Point position() const
+    {
+        cbindgen_private::Point position;
+        sixtyfps_windowrc_position(&inner, &position);
+        return position;
+    }
+    void set_position(cbindgen_private::Point position) const
+    {
+        sixtyfps_windowrc_set_position(&inner, position);
+    }
+    Size size() const { return sixtyfps_windowrc_size(&inner); }
+    void set_size(Size size) const { sixtyfps_windowrc_set_size(&inner, size); }
+    WindowState window_state() const { return sixtyfps_windowrc_window_state(&inner); }
+    void set_window_state(WindowState state) const
+    {
+        sixtyfps_windowrc_set_window_state(&inner, state);
+    }
+    void set_title(const SharedString &title) const { sixtyfps_windowrc_set_title(&inner, &title); }
+
     template<typename Component, typename ItemTree>
     void free_graphics_resources(Component *c, ItemTree items) const
     {
@@ -141,6 +164,23 @@ public:
         cbindgen_private::sixtyfps_windowrc_show_popup(&inner, &popup, p, &parent_item);
     }
 
+    template<typename Component, typename Parent>
+    void show_child_window(const Parent *parent_component, uintptr_t index,
+                           cbindgen_private::ItemRc parent_item) const
+    {
+        if (!cbindgen_private::sixtyfps_windowrc_is_child_window_shown(&inner, index,
+                                                                        &parent_item)) {
+            auto child = Component::create(parent_component);
+            cbindgen_private::sixtyfps_windowrc_show_child_window(
+                    &inner, index, &parent_item, &child->m_window.window_handle().inner);
+        }
+    }
+
+    void hide_child_window(uintptr_t index, cbindgen_private::ItemRc parent_item) const
+    {
+        cbindgen_private::sixtyfps_windowrc_hide_child_window(&inner, index, &parent_item);
+    }
+
 private:
     cbindgen_private::WindowRcOpaque inner;
 };
@@ -314,6 +354,27 @@ public:
     /// De-registers the window from the windowing system, therefore hiding it.
     void hide() { inner.hide(); }
 
+    /// Returns the position of the window on the screen, in logical pixels.
+    cbindgen_private::Point position() const { return inner.position(); }
+    /// Requests the windowing system to move the window to the given \a position on the screen,
+    /// in logical pixels.
+    void set_position(cbindgen_private::Point position) const { inner.set_position(position); }
+
+    /// Returns the size of the window, in logical pixels.
+    Size size() const { return inner.size(); }
+    /// Resizes the window to the given \a size, in logical pixels. The size is still restricted
+    /// by the layout constraints of the window.
+    void set_size(Size size) const { inner.set_size(size); }
+
+    /// Returns whether the window is minimized, maximized or fullscreen.
+    WindowState window_state() const { return inner.window_state(); }
+    /// Requests the windowing system to minimize, maximize or restore the window, or to show it
+    /// fullscreen.
+    void set_window_state(WindowState state) const { inner.set_window_state(state); }
+
+    /// Sets the title of the window. This overrides the `title` property of the `Window` element.
+    void set_title(const SharedString &title) const { inner.set_title(title); }
+
     /// \private
     private_api::WindowRc &window_handle() { return inner; }
     /// \private
@@ -459,6 +520,8 @@ struct AbstractRepeaterView
     virtual void row_added(int index, int count) = 0;
     virtual void row_removed(int index, int count) = 0;
     virtual void row_changed(int index) = 0;
+    virtual void row_moved(int from, int to, int count) = 0;
+    virtual void reset() = 0;
 };
 using ModelPeer = std::weak_ptr<AbstractRepeaterView>;
 
@@ -541,6 +604,28 @@ protected:
         model_row_data_dirty_property.mark_dirty();
         for_each_peers([=](auto peer) { peer->row_removed(index, count); });
     }
+    /// Notify the views that rows were moved: the \a count rows starting at \a from are removed
+    /// and inserted back so that the first of them ends up at index \a to.
+    /// The views reuse the components of the moved rows.
+    void row_moved(int from, int to, int count)
+    {
+        tracked_rows.clear();
+        model_row_data_dirty_property.mark_dirty();
+        for_each_peers([=](auto peer) { peer->row_moved(from, to, count); });
+    }
+    /// Notify the views that the model has changed entirely: any row may have changed, and the
+    /// row count may be different.
+    ///
+    /// The views do not compare the old and new rows: the component that showed a given row
+    /// index is kept and updated with the new data of that index. If the row count changed,
+    /// components are only created or destroyed at the end.
+    void reset()
+    {
+        model_row_count_dirty_property.mark_dirty();
+        tracked_rows.clear();
+        model_row_data_dirty_property.mark_dirty();
+        for_each_peers([=](auto peer) { peer->reset(); });
+    }
 
 private:
     template<typename F>
@@ -657,6 +742,364 @@ public:
         data.insert(data.begin() + index, value);
         this->row_added(int(index), 1);
     }
+
+    /// Replaces all the rows of the model with \a array
+    void set_vector(std::vector<ModelData> array)
+    {
+        data = std::move(array);
+        this->reset();
+    }
+};
+
+namespace private_api {
+/// Returns the row at which \a row ends up after \a count rows were moved from \a from to \a to,
+/// as described in Model::row_moved
+inline int row_after_move(int row, int from, int to, int count)
+{
+    if (row >= from && row < from + count)
+        return to + (row - from);
+    // The index in the model without the moved rows
+    row = row < from ? row : row - count;
+    return row < to ? row : row + count;
+}
+} // namespace private_api
+
+/// The FilterModel shows the rows of a source model that are accepted by a filter function.
+///
+/// The FilterModel listens to the changes of the source model and updates itself accordingly.
+/// Setting the data of a row changes the corresponding row in the source model.
+template<typename ModelData>
+class FilterModel : public Model<ModelData>
+{
+    struct Listener : private_api::AbstractRepeaterView
+    {
+        FilterModel *model;
+        Listener(FilterModel *m) : model(m) { }
+        void row_added(int index, int count) override { model->source_row_added(index, count); }
+        void row_removed(int index, int count) override
+        {
+            model->source_row_removed(index, count);
+        }
+        void row_changed(int index) override { model->source_row_changed(index); }
+        void row_moved(int from, int to, int count) override
+        {
+            model->source_row_moved(from, to, count);
+        }
+        void reset() override { model->reset(); }
+    };
+
+public:
+    /// Constructs a new FilterModel showing the rows of \a source for which \a filter
+    /// returns true.
+    FilterModel(std::shared_ptr<Model<ModelData>> source,
+                std::function<bool(const ModelData &)> filter)
+        : source_model(std::move(source)),
+          filter_fn(std::move(filter)),
+          listener(std::make_shared<Listener>(this))
+    {
+        source_model->attach_peer(listener);
+        build_mapping();
+    }
+
+    int row_count() const override { return int(mapping.size()); }
+    std::optional<ModelData> row_data(int i) const override
+    {
+        if (i >= row_count())
+            return {};
+        return source_model->row_data(mapping[i]);
+    }
+    void set_row_data(int i, const ModelData &value) override
+    {
+        if (i < row_count()) {
+            source_model->set_row_data(mapping[i], value);
+        }
+    }
+
+    /// Applies the filter function again on all the rows of the source model. Call this
+    /// when the filter function depends on state that has changed.
+    void reset()
+    {
+        mapping.clear();
+        build_mapping();
+        Model<ModelData>::reset();
+    }
+
+    /// Returns the row in the source model that corresponds to \a filtered_row.
+    int unfiltered_row(int filtered_row) const { return mapping[filtered_row]; }
+
+private:
+    bool accepts_row(int row) const
+    {
+        auto data = source_model->row_data(row);
+        return data && filter_fn(*data);
+    }
+    void build_mapping()
+    {
+        for (int i = 0, count = source_model->row_count(); i < count; ++i) {
+            if (accepts_row(i))
+                mapping.push_back(i);
+        }
+    }
+    void source_row_added(int index, int count)
+    {
+        auto insertion_point = std::lower_bound(mapping.begin(), mapping.end(), index);
+        for (auto it = insertion_point; it != mapping.end(); ++it)
+            *it += count;
+        std::vector<int> added;
+        for (int row = index; row < index + count; ++row) {
+            if (accepts_row(row))
+                added.push_back(row);
+        }
+        int insertion_index = int(insertion_point - mapping.begin());
+        mapping.insert(insertion_point, added.begin(), added.end());
+        if (!added.empty())
+            this->row_added(insertion_index, int(added.size()));
+    }
+    void source_row_removed(int index, int count)
+    {
+        auto start = std::lower_bound(mapping.begin(), mapping.end(), index);
+        auto end = std::lower_bound(start, mapping.end(), index + count);
+        int start_index = int(start - mapping.begin());
+        int removed = int(end - start);
+        for (auto it = mapping.erase(start, end); it != mapping.end(); ++it)
+            *it -= count;
+        if (removed > 0)
+            this->row_removed(start_index, removed);
+    }
+    void source_row_moved(int from, int to, int count)
+    {
+        auto index_of = [this](int row) {
+            return int(std::lower_bound(mapping.begin(), mapping.end(), row) - mapping.begin());
+        };
+        int filtered_from = index_of(from);
+        int filtered_count = index_of(from + count) - filtered_from;
+        for (auto &row : mapping)
+            row = private_api::row_after_move(row, from, to, count);
+        std::sort(mapping.begin(), mapping.end());
+        int filtered_to = index_of(to);
+        if (filtered_count > 0 && filtered_from != filtered_to)
+            this->row_moved(filtered_from, filtered_to, filtered_count);
+    }
+    void source_row_changed(int row)
+    {
+        bool accepted = accepts_row(row);
+        auto it = std::lower_bound(mapping.begin(), mapping.end(), row);
+        int index = int(it - mapping.begin());
+        if (it != mapping.end() && *it == row) {
+            if (accepted) {
+                this->row_changed(index);
+            } else {
+                mapping.erase(it);
+                this->row_removed(index, 1);
+            }
+        } else if (accepted) {
+            mapping.insert(it, row);
+            this->row_added(index, 1);
+        }
+    }
+
+    std::shared_ptr<Model<ModelData>> source_model;
+    std::function<bool(const ModelData &)> filter_fn;
+    std::shared_ptr<Listener> listener;
+    // sorted list of the rows of the source model accepted by the filter
+    std::vector<int> mapping;
+};
+
+/// The SortModel shows the rows of a source model sorted with a comparison function.
+///
+/// The comparison function returns true if the first argument is ordered before the second,
+/// like for `std::sort`. The SortModel listens to the changes of the source model and updates
+/// itself accordingly. Setting the data of a row changes the corresponding row in the source
+/// model.
+template<typename ModelData>
+class SortModel : public Model<ModelData>
+{
+    struct Listener : private_api::AbstractRepeaterView
+    {
+        SortModel *model;
+        Listener(SortModel *m) : model(m) { }
+        void row_added(int index, int count) override { model->source_row_added(index, count); }
+        void row_removed(int index, int count) override
+        {
+            model->source_row_removed(index, count);
+        }
+        void row_changed(int index) override { model->source_row_changed(index); }
+        void row_moved(int from, int to, int count) override
+        {
+            model->source_row_moved(from, to, count);
+        }
+        void reset() override { model->reset(); }
+    };
+
+public:
+    /// Constructs a new SortModel showing the rows of \a source in the order given by \a less.
+    SortModel(std::shared_ptr<Model<ModelData>> source,
+              std::function<bool(const ModelData &, const ModelData &)> less)
+        : source_model(std::move(source)),
+          less_fn(std::move(less)),
+          listener(std::make_shared<Listener>(this))
+    {
+        source_model->attach_peer(listener);
+        build_mapping();
+    }
+
+    int row_count() const override { return int(mapping.size()); }
+    std::optional<ModelData> row_data(int i) const override
+    {
+        if (i >= row_count())
+            return {};
+        return source_model->row_data(mapping[i]);
+    }
+    void set_row_data(int i, const ModelData &value) override
+    {
+        if (i < row_count()) {
+            source_model->set_row_data(mapping[i], value);
+        }
+    }
+
+    /// Sorts all the rows of the source model again. Call this when the comparison function
+    /// depends on state that has changed.
+    void reset()
+    {
+        mapping.clear();
+        build_mapping();
+        Model<ModelData>::reset();
+    }
+
+    /// Returns the row in the source model that corresponds to \a sorted_row.
+    int unsorted_row(int sorted_row) const { return mapping[sorted_row]; }
+
+private:
+    void build_mapping()
+    {
+        for (int i = 0, count = source_model->row_count(); i < count; ++i)
+            mapping.push_back(i);
+        std::stable_sort(mapping.begin(), mapping.end(), [this](int a, int b) {
+            return less_fn(*source_model->row_data(a), *source_model->row_data(b));
+        });
+    }
+    // Equal rows are inserted after the existing ones, like a stable sort would do.
+    std::vector<int>::iterator insertion_point(const ModelData &data)
+    {
+        return std::upper_bound(mapping.begin(), mapping.end(), data,
+                                [this](const ModelData &value, int row) {
+                                    return less_fn(value, *source_model->row_data(row));
+                                });
+    }
+    void source_row_added(int index, int count)
+    {
+        for (auto &row : mapping) {
+            if (row >= index)
+                row += count;
+        }
+        std::vector<int> inserted;
+        for (int row = index; row < index + count; ++row) {
+            if (auto data = source_model->row_data(row)) {
+                auto it = mapping.insert(insertion_point(*data), row);
+                inserted.push_back(int(it - mapping.begin()));
+            }
+        }
+        for (int i : inserted)
+            this->row_added(i, 1);
+    }
+    void source_row_removed(int index, int count)
+    {
+        // In decreasing order, so that each index is still valid once the previous ones are
+        // removed
+        std::vector<int> removed;
+        for (int i = int(mapping.size()) - 1; i >= 0; --i) {
+            if (mapping[i] >= index + count) {
+                mapping[i] -= count;
+            } else if (mapping[i] >= index) {
+                mapping.erase(mapping.begin() + i);
+                removed.push_back(i);
+            }
+        }
+        for (int i : removed)
+            this->row_removed(i, 1);
+    }
+    void source_row_changed(int row)
+    {
+        auto data = source_model->row_data(row);
+        auto it = std::find(mapping.begin(), mapping.end(), row);
+        if (!data || it == mapping.end())
+            return;
+        int old_index = int(it - mapping.begin());
+        mapping.erase(it);
+        int new_index = int(mapping.insert(insertion_point(*data), row) - mapping.begin());
+        if (old_index != new_index)
+            this->row_moved(old_index, new_index, 1);
+        this->row_changed(new_index);
+    }
+    void source_row_moved(int from, int to, int count)
+    {
+        // The order does not change, only the rows of the source model they refer to
+        for (auto &row : mapping)
+            row = private_api::row_after_move(row, from, to, count);
+    }
+
+    std::shared_ptr<Model<ModelData>> source_model;
+    std::function<bool(const ModelData &, const ModelData &)> less_fn;
+    std::shared_ptr<Listener> listener;
+    // the rows of the source model, in sorted order
+    std::vector<int> mapping;
+};
+
+/// The MapModel shows the rows of a source model converted with a map function.
+///
+/// The notifications of the source model are passed through unchanged. Setting the data of a
+/// row is ignored, unless an inverse function was given to the constructor: the data is then
+/// converted back with it and set in the source model.
+template<typename SourceData, typename MappedData>
+class MapModel : public Model<MappedData>
+{
+    struct Listener : private_api::AbstractRepeaterView
+    {
+        MapModel *model;
+        Listener(MapModel *m) : model(m) { }
+        void row_added(int index, int count) override { model->row_added(index, count); }
+        void row_removed(int index, int count) override { model->row_removed(index, count); }
+        void row_changed(int index) override { model->row_changed(index); }
+        void row_moved(int from, int to, int count) override
+        {
+            model->row_moved(from, to, count);
+        }
+        void reset() override { model->reset(); }
+    };
+
+public:
+    /// Constructs a new MapModel showing the rows of \a source converted with \a map.
+    /// If \a inverse is set, setting the data of a row sets the data converted with it
+    /// in \a source.
+    MapModel(std::shared_ptr<Model<SourceData>> source,
+             std::function<MappedData(const SourceData &)> map,
+             std::function<SourceData(const MappedData &)> inverse = {})
+        : source_model(std::move(source)),
+          map_fn(std::move(map)),
+          inverse_fn(std::move(inverse)),
+          listener(std::make_shared<Listener>(this))
+    {
+        source_model->attach_peer(listener);
+    }
+
+    int row_count() const override { return source_model->row_count(); }
+    std::optional<MappedData> row_data(int i) const override
+    {
+        if (auto data = source_model->row_data(i))
+            return map_fn(*data);
+        return {};
+    }
+    void set_row_data(int i, const MappedData &value) override
+    {
+        if (inverse_fn)
+            source_model->set_row_data(i, inverse_fn(value));
+    }
+
+private:
+    std::shared_ptr<Model<SourceData>> source_model;
+    std::function<MappedData(const SourceData &)> map_fn;
+    std::function<SourceData(const MappedData &)> inverse_fn;
+    std::shared_ptr<Listener> listener;
 };
 
 namespace private_api {
@@ -673,6 +1116,24 @@ class Repeater
         {
             State state = State::Dirty;
             std::optional<ComponentHandle<C>> ptr;
+            // The row and the data the component was last updated with, if the data can be
+            // compared
+            std::optional<std::pair<int, ModelData>> shown;
+
+            // Updates the component with the data of the row, unless it already shows the same
+            // data at the same row
+            void update(int row, const ModelData &data)
+            {
+                if constexpr (std::equality_comparable<ModelData>) {
+                    if (shown && shown->first == row && shown->second == data) {
+                        state = State::Clean;
+                        return;
+                    }
+                    shown = std::make_pair(row, data);
+                }
+                (*ptr)->update_data(row, data);
+                state = State::Clean;
+            }
         };
         std::vector<ComponentWithState> data;
         private_api::Property<bool> is_dirty { true };
@@ -697,6 +1158,37 @@ class Repeater
                 data[i].state = State::Dirty;
             }
         }
+        void row_moved(int from, int to, int count) override
+        {
+            if (count == 0 || from == to)
+                return;
+            int first = std::min(from, to);
+            int last = std::max(from, to) + count;
+            if (from < 0 || to < 0 || count < 0 || last > int(data.size())) {
+                // the model is not consistent with the components, update all of them instead
+                reset();
+                return;
+            }
+            is_dirty.set(true);
+            // the components are moved along with their row
+            if (from < to) {
+                std::rotate(data.begin() + from, data.begin() + from + count, data.begin() + last);
+            } else {
+                std::rotate(data.begin() + to, data.begin() + from, data.begin() + last);
+            }
+            for (int i = first; i < last; ++i) {
+                // the indexes changed
+                data[i].state = State::Dirty;
+            }
+        }
+        void reset() override
+        {
+            is_dirty.set(true);
+            // keep the components, only the ones whose data changed will be updated
+            for (auto &c : data) {
+                c.state = State::Dirty;
+            }
+        }
     };
 
 public:
@@ -730,7 +1222,7 @@ public:
                         c.ptr = C::create(parent);
                     }
                     if (c.state == RepeaterInner::State::Dirty) {
-                        (*c.ptr)->update_data(i, *m->row_data(i));
+                        c.update(i, *m->row_data(i));
                     }
                 }
             } else {
@@ -800,7 +1292,7 @@ public:
                 if (inner && inner->is_dirty.get()) {
                     auto &c = inner->data[row];
                     if (c.state == RepeaterInner::State::Dirty && c.ptr) {
-                        (*c.ptr)->update_data(row, *m->row_data(row));
+                        c.update(row, *m->row_data(row));
                     }
                 }
             }
@@ -820,6 +1312,15 @@ cbindgen_private::Flickable::~Flickable()
     sixtyfps_flickable_data_free(&data);
 }
 
+cbindgen_private::TimerItem::TimerItem()
+{
+    sixtyfps_timer_item_data_init(&data);
+}
+cbindgen_private::TimerItem::~TimerItem()
+{
+    sixtyfps_timer_item_data_free(&data);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics()
 {
     sixtyfps_native_style_metrics_init(this);
@@ -860,6 +1361,28 @@ inline void quit_event_loop()
     cbindgen_private::sixtyfps_quit_event_loop();
 }
 
+/// Processes the pending events and returns, instead of taking over the thread like
+/// sixtyfps::run_event_loop(). If there is no pending event, it waits at most \a timeout for
+/// one. Timers, animations and the functors queued with invoke_from_event_loop() are processed
+/// as well, so calling this function repeatedly allows embedding SixtyFPS in an event loop
+/// owned by the application.
+///
+/// Returns false once the event loop was asked to quit, either by sixtyfps::quit_event_loop()
+/// or because the last window was closed. It also returns false without processing anything
+/// if the backend cannot process events without running the event loop.
+inline bool process_events(std::chrono::milliseconds timeout)
+{
+    private_api::assert_main_thread();
+    return cbindgen_private::sixtyfps_process_events(timeout.count());
+}
+
+/// Processes the events that are already pending without waiting for new ones. This is the
+/// same as calling process_events() with a zero timeout.
+inline bool pump_events()
+{
+    return process_events(std::chrono::milliseconds(0));
+}
+
 /// Adds the specified functor to an internal queue, notifies the event loop to wake up.
 /// Once woken up, any queued up functors will be invoked.
 /// This function is thread-safe and can be called from any thread, including the one
@@ -1005,6 +1528,75 @@ inline std::optional<SharedString> register_font_from_data(const uint8_t *data,
     }
 }
 
+inline SharedString translate(const SharedString &original, const SharedString &context,
+                              const SharedString &domain,
+                              cbindgen_private::Slice<SharedString> arguments, int n,
+                              const SharedString &plural)
+{
+    SharedString result;
+    cbindgen_private::sixtyfps_translate(&original, &context, &domain, arguments, n, &plural,
+                                         &result);
+    return result;
+}
+
+inline SharedString key_sequence_display_text(const SharedString &keys)
+{
+    SharedString result;
+    cbindgen_private::sixtyfps_key_sequence_display_text(&keys, &result);
+    return result;
+}
+
+inline cbindgen_private::EasingCurve custom_easing_curve(const SharedString &name)
+{
+    cbindgen_private::EasingCurve result;
+    cbindgen_private::sixtyfps_custom_easing_curve(&name, &result);
+    return result;
+}
+
+}
+
+/// Sets the translator used to look up the translations of the strings marked with `@tr(...)`
+/// in .60 files, and re-evaluates all the translated strings.
+///
+/// The translator is called with the string to translate, its context and domain (both may be
+/// empty), the count `n` and the plural form (empty if the string has no plural form). It
+/// returns the translation, or an empty optional if it has none.
+template<typename F>
+void set_translator(F translator)
+{
+    cbindgen_private::sixtyfps_set_translator(
+            [](void *data, const SharedString *string, const SharedString *context,
+               const SharedString *domain, uint64_t n, const SharedString *plural,
+               SharedString *out) {
+                std::optional<SharedString> translated =
+                        (*reinterpret_cast<F *>(data))(*string, *context, *domain, n, *plural);
+                if (translated) {
+                    *out = std::move(*translated);
+                }
+                return translated.has_value();
+            },
+            new F(std::move(translator)), [](void *data) { delete reinterpret_cast<F *>(data); });
+}
+
+/// Re-evaluates all the strings marked with `@tr(...)`. Call this function when the language
+/// or the catalog used by the translator changes.
+inline void mark_all_translations_dirty()
+{
+    cbindgen_private::sixtyfps_mark_all_translations_dirty();
+}
+
+/// Registers a function that can be used as an easing curve with `custom("name")` in .60 files.
+///
+/// The function is called with the progress of the animation, between 0 and 1, and returns the
+/// progress of the value. Animations using a name that is not registered are linear.
+template<typename F>
+void register_easing_curve(std::string_view name, F curve)
+{
+    SharedString name_string(name);
+    cbindgen_private::sixtyfps_register_easing_curve(
+            &name_string,
+            [](void *data, float t) -> float { return (*reinterpret_cast<F *>(data))(t); },
+            new F(std::move(curve)), [](void *data) { delete reinterpret_cast<F *>(data); });
 }
 
 } // namespace sixtyfps
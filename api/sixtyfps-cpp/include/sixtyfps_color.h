@@ -12,6 +12,7 @@ namespace sixtyfps {
 
 namespace private_api {
 class LinearGradientBrush;
+class RadialGradientBrush;
 }
 
 class Color;
@@ -169,6 +170,7 @@ public:
 private:
     cbindgen_private::types::Color inner;
     friend class private_api::LinearGradientBrush;
+    friend class private_api::RadialGradientBrush;
     friend class Brush;
 };
 
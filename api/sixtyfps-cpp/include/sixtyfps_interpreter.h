@@ -454,6 +454,14 @@ inline Value::Value(const std::shared_ptr<sixtyfps::Model<Value>> &model)
         {
             cbindgen_private::sixtyfps_interpreter_model_notify_row_removed(&notify, index, count);
         }
+        void row_moved(int from, int to, int count) override
+        {
+            cbindgen_private::sixtyfps_interpreter_model_notify_row_moved(&notify, from, to, count);
+        }
+        void reset() override
+        {
+            cbindgen_private::sixtyfps_interpreter_model_notify_reset(&notify);
+        }
     };
 
     auto wrapper = std::make_shared<ModelWrapper>();
@@ -4,6 +4,7 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <optional>
 
 namespace sixtyfps::cbindgen_private {
 struct PropertyAnimation;
@@ -292,4 +293,48 @@ private:
     cbindgen_private::PropertyTrackerOpaque inner;
 };
 
+/// ChangeTracker calls a handler after the value returned by a functor has changed.
+/// The handler is not called from the property change itself, but later from the event loop.
+struct ChangeTracker
+{
+    /// Constructs a new change tracker instance.
+    ChangeTracker() { cbindgen_private::sixtyfps_change_tracker_construct(&inner); }
+    /// Destroys the change tracker.
+    ~ChangeTracker() { cbindgen_private::sixtyfps_change_tracker_drop(&inner); }
+    /// The copy constructor is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker(const ChangeTracker &) = delete;
+    /// The assignment operator is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker &operator=(const ChangeTracker &) = delete;
+
+    /// Evaluates \a eval_fn and records its value. \a notify_fn is called after the value
+    /// returned by \a eval_fn changed.
+    template<typename FnEval, typename FnNotify>
+    void init(FnEval eval_fn, FnNotify notify_fn) const
+    {
+        using Value = std::invoke_result_t<FnEval>;
+        struct Data
+        {
+            FnEval eval_fn;
+            FnNotify notify_fn;
+            std::optional<Value> value;
+        };
+        cbindgen_private::sixtyfps_change_tracker_init(
+                &inner, new Data { std::move(eval_fn), std::move(notify_fn), std::nullopt },
+                [](void *user_data) { delete reinterpret_cast<Data *>(user_data); },
+                [](void *user_data) -> bool {
+                    auto data = reinterpret_cast<Data *>(user_data);
+                    auto new_value = data->eval_fn();
+                    if (data->value && *data->value == new_value) {
+                        return false;
+                    }
+                    data->value = std::move(new_value);
+                    return true;
+                },
+                [](void *user_data) { reinterpret_cast<Data *>(user_data)->notify_fn(); });
+    }
+
+private:
+    cbindgen_private::ChangeTrackerOpaque inner;
+};
+
 } // namespace sixtyfps::private_api
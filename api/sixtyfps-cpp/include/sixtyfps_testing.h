@@ -3,7 +3,9 @@
 
 #pragma once
 #include "sixtyfps.h"
+#include <chrono>
 #include <iostream>
+#include <optional>
 
 namespace sixtyfps::testing {
 
@@ -16,6 +18,21 @@ inline void mock_elapsed_time(int64_t time_in_ms)
 {
     cbindgen_private::sixtyfps_mock_elapsed_time(time_in_ms);
 }
+
+/// Advances the mocked time: posted events are processed, the timers expiring within
+/// \a duration fire in order, and animations and state transitions are stepped.
+inline void mock_elapsed_time(std::chrono::milliseconds duration)
+{
+    cbindgen_private::sixtyfps_mock_elapsed_time(duration.count());
+}
+
+/// Processes the posted events and advances the mocked time until there is no pending
+/// event, running animation or single shot timer left.
+inline void run_until_idle()
+{
+    cbindgen_private::sixtyfps_run_until_idle();
+}
+
 template<typename Component>
 inline void send_mouse_click(const Component *component, float x, float y)
 {
@@ -32,6 +49,162 @@ inline void send_keyboard_string_sequence(const Component *component,
                                                     &component->m_window.window_handle());
 }
 
+/// Returns a textual dump of the accessibility tree of the component, one node per line.
+template<typename Component>
+inline sixtyfps::SharedString accessibility_tree_dump(const Component *component)
+{
+    auto crc = *component->self_weak.into_dyn().lock();
+    sixtyfps::SharedString result;
+    cbindgen_private::sixtyfps_accessibility_tree_dump(&crc, &result);
+    return result;
+}
+
+/// A handle to an element of a component, to inspect it and send it input events in tests.
+/// Use find_by_id() or find_by_text() to obtain a handle.
+///
+/// The mouse events are sent to the window at the center of the element, so they are
+/// received by the element under that position.
+template<typename Component>
+class ElementHandle
+{
+    cbindgen_private::ItemRc item;
+    vtable::VRc<private_api::ComponentVTable, Component> component;
+
+    const private_api::WindowRc &window() const { return component->m_window.window_handle(); }
+
+    void send_mouse_event(cbindgen_private::ElementMouseEvent event, float dx = 0,
+                          float dy = 0) const
+    {
+        cbindgen_private::sixtyfps_testing_element_send_mouse_event(&item, event, dx, dy,
+                                                                   &window());
+    }
+
+    void send_touch_gesture(cbindgen_private::ElementTouchGesture gesture, float a = 0,
+                            float b = 0) const
+    {
+        cbindgen_private::sixtyfps_testing_element_send_touch_gesture(&item, gesture, a, b,
+                                                                     &window());
+    }
+
+public:
+    /// \private
+    ElementHandle(cbindgen_private::ItemRc item,
+                  vtable::VRc<private_api::ComponentVTable, Component> component)
+        : item(std::move(item)), component(std::move(component))
+    {
+    }
+
+    /// Returns the geometry of the element, relative to the window.
+    cbindgen_private::Rect absolute_geometry() const
+    {
+        cbindgen_private::Rect result;
+        cbindgen_private::sixtyfps_testing_element_absolute_geometry(&item, &window(), &result);
+        return result;
+    }
+
+    /// Moves the mouse over the center of the element.
+    void hover() const { send_mouse_event(cbindgen_private::ElementMouseEvent::Hover); }
+
+    /// Clicks with the left button at the center of the element.
+    void click() const { send_mouse_event(cbindgen_private::ElementMouseEvent::Click); }
+
+    /// Clicks twice in a row at the center of the element.
+    void double_click() const
+    {
+        send_mouse_event(cbindgen_private::ElementMouseEvent::DoubleClick);
+    }
+
+    /// Presses the left button at the center of the element, moves the mouse by \a dx and \a dy
+    /// in several steps, and releases the button.
+    void drag_by(float dx, float dy) const
+    {
+        send_mouse_event(cbindgen_private::ElementMouseEvent::Drag, dx, dy);
+    }
+
+    /// Sends a mouse wheel event at the center of the element. \a dx and \a dy are the amount
+    /// of pixels to scroll.
+    void scroll(float dx, float dy) const
+    {
+        send_mouse_event(cbindgen_private::ElementMouseEvent::Scroll, dx, dy);
+    }
+
+    /// Touches the center of the element with two fingers, and moves them in several steps
+    /// until the distance between them is multiplied by \a scale and they turned by \a rotation
+    /// degrees, before lifting them.
+    void pinch(float scale, float rotation = 0) const
+    {
+        send_touch_gesture(cbindgen_private::ElementTouchGesture::Pinch, scale, rotation);
+    }
+
+    /// Touches the center of the element with one finger, moves it quickly by \a dx and \a dy
+    /// in several steps, and lifts it.
+    void swipe(float dx, float dy) const
+    {
+        send_touch_gesture(cbindgen_private::ElementTouchGesture::Swipe, dx, dy);
+    }
+
+    /// Touches the center of the element with one finger for a second without moving it.
+    void long_press() const
+    {
+        send_touch_gesture(cbindgen_private::ElementTouchGesture::LongPress);
+    }
+
+    /// Gives the keyboard focus to the first enabled TextInput or FocusScope within the element,
+    /// including the element itself. Returns false if there is no such item.
+    bool focus() const
+    {
+        return cbindgen_private::sixtyfps_testing_element_focus(&item, &window());
+    }
+
+    /// Focuses the element and sends it a key press event.
+    void key_press(const sixtyfps::SharedString &text,
+                   cbindgen_private::KeyboardModifiers modifiers = {}) const
+    {
+        cbindgen_private::sixtyfps_testing_element_send_key_event(
+                &item, cbindgen_private::KeyEventType::KeyPressed, &text, modifiers, &window());
+    }
+
+    /// Focuses the element and sends it a key release event.
+    void key_release(const sixtyfps::SharedString &text,
+                     cbindgen_private::KeyboardModifiers modifiers = {}) const
+    {
+        cbindgen_private::sixtyfps_testing_element_send_key_event(
+                &item, cbindgen_private::KeyEventType::KeyReleased, &text, modifiers, &window());
+    }
+};
+
+/// Returns the first element of the component, in drawing order, that has the given \a id in
+/// the .60 source, or an empty optional if there is none.
+template<typename Component>
+inline std::optional<ElementHandle<Component>> find_by_id(const Component *component,
+                                                          const sixtyfps::SharedString &id)
+{
+    auto crc = *component->self_weak.lock();
+    auto dyn_crc = crc.into_dyn();
+    sixtyfps::SharedVector<cbindgen_private::ItemRc> result;
+    cbindgen_private::sixtyfps_testing_element_find_by_id(
+            &dyn_crc, &id, &component->m_window.window_handle(), &result);
+    if (result.empty())
+        return {};
+    return ElementHandle<Component>(result[0], crc);
+}
+
+/// Returns the first element of the component, in drawing order, whose text or
+/// accessible-label is \a text, or an empty optional if there is none.
+template<typename Component>
+inline std::optional<ElementHandle<Component>> find_by_text(const Component *component,
+                                                            const sixtyfps::SharedString &text)
+{
+    auto crc = *component->self_weak.lock();
+    auto dyn_crc = crc.into_dyn();
+    sixtyfps::SharedVector<cbindgen_private::ItemRc> result;
+    cbindgen_private::sixtyfps_testing_element_find_by_text(
+            &dyn_crc, &text, &component->m_window.window_handle(), &result);
+    if (result.empty())
+        return {};
+    return ElementHandle<Component>(result[0], crc);
+}
+
 #define assert_eq(A, B)                                                                            \
     sixtyfps::testing::private_api::assert_eq_impl(A, B, #A, #B, __FILE__, __LINE__)
 
@@ -61,6 +61,14 @@ unsafe impl Send for UserData {}
     crate::backend().quit_event_loop();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_process_events(timeout_ms: u64) -> bool {
+    crate::backend().process_events(
+        core::time::Duration::from_millis(timeout_ms),
+        sixtyfps_corelib::backend::EventLoopQuitBehavior::QuitOnLastWindowClosed,
+    )
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn sixtyfps_register_font_from_path(
     path: &sixtyfps_corelib::SharedString,
@@ -147,6 +147,55 @@ TEST_CASE("Track model row data changes")
     REQUIRE(tracker.is_dirty());
 }
 
+TEST_CASE("Model adapters")
+{
+    auto source = std::make_shared<sixtyfps::VectorModel<int>>(std::vector<int> { 3, 1, 4, 1, 5 });
+
+    auto filtered = std::make_shared<sixtyfps::FilterModel<int>>(
+            source, [](int value) { return value % 2 == 1; });
+    auto sorted = std::make_shared<sixtyfps::SortModel<int>>(
+            source, [](int a, int b) { return a < b; });
+    auto mapped = std::make_shared<sixtyfps::MapModel<int, int>>(
+            source, [](int value) { return value * 10; });
+
+    REQUIRE(filtered->row_count() == 4);
+    REQUIRE(filtered->row_data(2) == 1);
+    REQUIRE(filtered->unfiltered_row(2) == 3);
+    REQUIRE(sorted->row_data(0) == 1);
+    REQUIRE(sorted->row_data(4) == 5);
+    REQUIRE(mapped->row_data(2) == 40);
+
+    source->push_back(2);
+    source->erase(0);
+    REQUIRE(filtered->row_count() == 3);
+    REQUIRE(filtered->row_data(0) == 1);
+    REQUIRE(sorted->row_count() == 5);
+    REQUIRE(sorted->row_data(2) == 2);
+    REQUIRE(mapped->row_data(4) == 20);
+
+    filtered->set_row_data(2, 7);
+    REQUIRE(source->row_data(3) == 7);
+    sorted->set_row_data(0, 6);
+    REQUIRE(source->row_data(0) == 6);
+    REQUIRE(sorted->row_data(4) == 7);
+    REQUIRE(sorted->row_data(3) == 6);
+    REQUIRE(filtered->row_count() == 2);
+
+    source->set_vector({ 5, 2, 9 });
+    REQUIRE(filtered->row_count() == 2);
+    REQUIRE(filtered->row_data(1) == 9);
+    REQUIRE(sorted->row_data(0) == 2);
+    REQUIRE(mapped->row_count() == 3);
+
+    mapped->set_row_data(0, 80);
+    REQUIRE(source->row_data(0) == 5);
+    auto editable = std::make_shared<sixtyfps::MapModel<int, int>>(
+            source, [](int value) { return value * 10; }, [](int value) { return value / 10; });
+    editable->set_row_data(0, 80);
+    REQUIRE(source->row_data(0) == 8);
+    REQUIRE(mapped->row_data(0) == 80);
+}
+
 TEST_CASE("Image")
 {
     using namespace sixtyfps;
@@ -33,6 +33,19 @@ class Component {
         this.comp.run();
     }
 
+    /**
+     * Processes the pending events without blocking the Node event loop for longer than
+     * `timeout` milliseconds. Returns false once the last window was closed or the event
+     * loop was asked to quit.
+     */
+    processEvents(timeout: number = 0): boolean {
+        return this.comp.process_events(timeout);
+    }
+
+    pumpEvents(): boolean {
+        return this.comp.process_events(0);
+    }
+
     show() {
         this.window.show();
     }
@@ -52,6 +65,79 @@ class Component {
     send_keyboard_string_sequence(s: String) {
         this.comp.send_keyboard_string_sequence(s)
     }
+
+    find_by_id(id: string): ElementHandle | undefined {
+        let handle = this.comp.find_by_id(id);
+        return handle === undefined ? undefined : new ElementHandle(this.comp, handle);
+    }
+
+    find_by_text(text: string): ElementHandle | undefined {
+        let handle = this.comp.find_by_text(text);
+        return handle === undefined ? undefined : new ElementHandle(this.comp, handle);
+    }
+}
+
+/**
+ * A handle to an element of a component, to inspect it and send it input events in tests.
+ * The mouse events are sent at the center of the element.
+ * @hidden
+ */
+class ElementHandle {
+    private comp: any;
+    private handle: any;
+
+    constructor(comp: any, handle: any) {
+        this.comp = comp;
+        this.handle = handle;
+    }
+
+    absolute_geometry(): { x: number, y: number, width: number, height: number } {
+        return this.comp.element_geometry(this.handle);
+    }
+
+    hover() {
+        this.comp.send_element_event(this.handle, "hover");
+    }
+
+    click() {
+        this.comp.send_element_event(this.handle, "click");
+    }
+
+    double_click() {
+        this.comp.send_element_event(this.handle, "double_click");
+    }
+
+    drag_by(dx: number, dy: number) {
+        this.comp.send_element_event(this.handle, "drag", dx, dy);
+    }
+
+    scroll(dx: number, dy: number) {
+        this.comp.send_element_event(this.handle, "scroll", dx, dy);
+    }
+
+    pinch(scale: number, rotation: number = 0) {
+        this.comp.send_element_event(this.handle, "pinch", scale, rotation);
+    }
+
+    swipe(dx: number, dy: number) {
+        this.comp.send_element_event(this.handle, "swipe", dx, dy);
+    }
+
+    long_press() {
+        this.comp.send_element_event(this.handle, "long_press");
+    }
+
+    focus(): boolean {
+        return this.comp.send_element_event(this.handle, "focus");
+    }
+
+    key_press(text: string) {
+        this.comp.send_element_event(this.handle, "key_press", text);
+    }
+
+    key_release(text: string) {
+        this.comp.send_element_event(this.handle, "key_release", text);
+    }
 }
 
 interface Window {
@@ -16,6 +16,7 @@
 struct WrappedComponentType(Option<sixtyfps_interpreter::ComponentDefinition>);
 struct WrappedComponentRc(Option<sixtyfps_interpreter::ComponentInstance>);
 struct WrappedWindow(Option<sixtyfps_corelib::window::WindowRc>);
+struct WrappedElementHandle(Option<sixtyfps_interpreter::testing::ElementHandle>);
 
 /// We need to do some gymnastic with closures to pass the ExecuteContext with the right lifetime
 type GlobalContextCallback<'c> =
@@ -226,7 +227,13 @@ fn to_eval_value<'cx>(
                     .collect::<Result<_, _>>()?,
             ))
         }
-        Type::Enumeration(_) => todo!(),
+        Type::Enumeration(e) => {
+            let value = val.to_string(cx)?.value();
+            if !e.values.contains(&value) {
+                return cx.throw_error(format!("'{}' is not a value of enum {}", value, e.name));
+            }
+            Ok(Value::EnumerationValue(e.name.clone(), value))
+        }
         Type::Invalid
         | Type::Void
         | Type::InferredProperty
@@ -292,6 +299,7 @@ fn to_js_value<'cx>(
             &format!("#{:02x}{:02x}{:02x}{:02x}", c.red(), c.green(), c.blue(), c.alpha()),
         )
         .as_value(cx),
+        Value::EnumerationValue(_, value) => JsString::new(cx, value.as_str()).as_value(cx),
         _ => todo!("converting {:?} to js has not been implemented", val),
     })
 }
@@ -353,6 +361,14 @@ fn to_js_value<'cx>(
             })?;
             Ok(JsUndefined::new().as_value(&mut cx))
         }
+        method process_events(mut cx) {
+            let timeout = cx.argument::<JsNumber>(0)?.value();
+            let this = cx.this();
+            let running = run_scoped(&mut cx,this.downcast().unwrap(), || {
+                Ok(sixtyfps_interpreter::process_events(std::time::Duration::from_millis(timeout as u64)))
+            })?;
+            Ok(JsBoolean::new(&mut cx, running).as_value(&mut cx))
+        }
         method window(mut cx) {
             let this = cx.this();
             let component = cx.borrow(&this, |x| x.0.as_ref().map(|c| c.clone_strong()));
@@ -486,6 +502,83 @@ fn to_js_value<'cx>(
             })?;
             Ok(JsUndefined::new().as_value(&mut cx))
         }
+
+        method find_by_id(mut cx) {
+            let id = cx.argument::<JsString>(0)?.value();
+            let this = cx.this();
+            let component = cx.borrow(&this, |x| x.0.as_ref().map(|c| c.clone_strong()));
+            let component = component.ok_or(()).or_else(|()| cx.throw_error("Invalid type"))?;
+            let element = run_scoped(&mut cx,this.downcast().unwrap(), || {
+                Ok(sixtyfps_interpreter::testing::find_by_id(&component, id.as_str()))
+            })?;
+            wrap_element_handle(&mut cx, element)
+        }
+
+        method find_by_text(mut cx) {
+            let text = cx.argument::<JsString>(0)?.value();
+            let this = cx.this();
+            let component = cx.borrow(&this, |x| x.0.as_ref().map(|c| c.clone_strong()));
+            let component = component.ok_or(()).or_else(|()| cx.throw_error("Invalid type"))?;
+            let element = run_scoped(&mut cx,this.downcast().unwrap(), || {
+                Ok(sixtyfps_interpreter::testing::find_by_text(&component, text.as_str()))
+            })?;
+            wrap_element_handle(&mut cx, element)
+        }
+
+        method element_geometry(mut cx) {
+            let element = cx.argument::<JsValue>(0)?;
+            let element = to_element_handle(&mut cx, element)?;
+            let this = cx.this();
+            let geometry = run_scoped(&mut cx,this.downcast().unwrap(), || {
+                Ok(element.absolute_geometry())
+            })?;
+            let obj = JsObject::new(&mut cx);
+            for (name, value) in [
+                ("x", geometry.origin.x),
+                ("y", geometry.origin.y),
+                ("width", geometry.size.width),
+                ("height", geometry.size.height),
+            ] {
+                let value = cx.number(value);
+                obj.set(&mut cx, name, value)?;
+            }
+            Ok(obj.as_value(&mut cx))
+        }
+
+        method send_element_event(mut cx) {
+            let element = cx.argument::<JsValue>(0)?;
+            let element = to_element_handle(&mut cx, element)?;
+            let event = cx.argument::<JsString>(1)?.value();
+            let (dx, dy) = if matches!(event.as_str(), "drag" | "scroll" | "pinch" | "swipe") {
+                (cx.argument::<JsNumber>(2)?.value() as f32, cx.argument::<JsNumber>(3)?.value() as f32)
+            } else {
+                (0., 0.)
+            };
+            let text = if event.starts_with("key_") {
+                cx.argument::<JsString>(2)?.value()
+            } else {
+                String::new()
+            };
+            let this = cx.this();
+            let result = run_scoped(&mut cx,this.downcast().unwrap(), || {
+                match event.as_str() {
+                    "hover" => element.hover(),
+                    "click" => element.click(),
+                    "double_click" => element.double_click(),
+                    "drag" => element.drag_by(dx, dy),
+                    "scroll" => element.scroll(dx, dy),
+                    "pinch" => element.pinch(dx, dy),
+                    "swipe" => element.swipe(dx, dy),
+                    "long_press" => element.long_press(),
+                    "focus" => return Ok(element.focus()),
+                    "key_press" => element.key_press(&text, Default::default()),
+                    "key_release" => element.key_release(&text, Default::default()),
+                    _ => return Err(format!("Unknown element event {}", event)),
+                }
+                Ok(true)
+            })?;
+            Ok(cx.boolean(result).as_value(&mut cx))
+        }
     }
 
     class SixtyFpsWindow for WrappedWindow {
@@ -509,6 +602,36 @@ fn to_js_value<'cx>(
             Ok(JsUndefined::new().as_value(&mut cx))
         }
     }
+
+    class SixtyFpsElementHandle for WrappedElementHandle {
+        init(_) {
+            Ok(WrappedElementHandle(None))
+        }
+    }
+}
+
+/// Returns a SixtyFpsElementHandle for the element, or undefined
+fn wrap_element_handle<'cx>(
+    cx: &mut impl Context<'cx>,
+    element: Option<sixtyfps_interpreter::testing::ElementHandle>,
+) -> JsResult<'cx, JsValue> {
+    match element {
+        Some(element) => {
+            let mut obj = SixtyFpsElementHandle::new::<_, JsValue, _>(cx, std::iter::empty())?;
+            cx.borrow_mut(&mut obj, |mut obj| obj.0 = Some(element));
+            Ok(obj.as_value(cx))
+        }
+        None => Ok(JsUndefined::new().as_value(cx)),
+    }
+}
+
+fn to_element_handle<'cx>(
+    cx: &mut impl Context<'cx>,
+    value: Handle<'cx, JsValue>,
+) -> NeonResult<sixtyfps_interpreter::testing::ElementHandle> {
+    let handle = value.downcast_or_throw::<SixtyFpsElementHandle, _>(cx)?;
+    let element = cx.borrow(&handle, |x| x.0.clone());
+    element.ok_or(()).or_else(|()| cx.throw_error("Invalid element handle"))
 }
 
 fn singleshot_timer_property(id: u32) -> String {
@@ -228,15 +228,18 @@ struct MyStruct {
 extern crate alloc;
 pub use sixtyfps_macros::sixtyfps;
 
+pub use sixtyfps_corelib::animations::register_easing_curve;
 pub use sixtyfps_corelib::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
 pub use sixtyfps_corelib::model::{
-    Model, ModelHandle, ModelNotify, ModelPeer, ModelTracker, StandardListViewItem, VecModel,
+    FilterModel, MapModel, Model, ModelHandle, ModelNotify, ModelPeer, ModelTracker, SortModel,
+    StandardListViewItem, VecModel,
 };
 pub use sixtyfps_corelib::sharedvector::SharedVector;
 pub use sixtyfps_corelib::string::SharedString;
 pub use sixtyfps_corelib::timers::{Timer, TimerMode};
+pub use sixtyfps_corelib::translations::{mark_all_translations_dirty, set_translator, Translator};
 
 /// This function can be used to register a custom TrueType font with SixtyFPS,
 /// for use with the `font-family` property. The provided slice must be a valid TrueType
@@ -271,6 +274,7 @@ pub mod re_exports {
     pub use once_cell::race::OnceBox;
     pub use once_cell::unsync::OnceCell;
     pub use pin_weak::rc::PinWeak;
+    pub use sixtyfps_corelib::accessibility::AccessibleStringProperty;
     pub use sixtyfps_corelib::animations::EasingCurve;
     pub use sixtyfps_corelib::callbacks::Callback;
     pub use sixtyfps_corelib::component::{
@@ -289,9 +293,10 @@ pub mod re_exports {
     pub use sixtyfps_corelib::layout::*;
     pub use sixtyfps_corelib::model::*;
     pub use sixtyfps_corelib::properties::{
-        set_state_binding, Property, PropertyTracker, StateInfo,
+        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
     };
     pub use sixtyfps_corelib::slice::Slice;
+    pub use sixtyfps_corelib::translations::translate;
     pub use sixtyfps_corelib::window::{Window, WindowHandleAccess, WindowRc};
     pub use sixtyfps_corelib::Color;
     pub use sixtyfps_corelib::ComponentVTable_static;
@@ -447,6 +452,39 @@ pub fn quit_event_loop() {
     sixtyfps_rendering_backend_default::backend().quit_event_loop();
 }
 
+/// Processes the pending events and returns, instead of taking over the thread like
+/// [`run_event_loop()`]. If there is no pending event, it waits at most `timeout` for one.
+/// Timers, animations and the events posted with [`invoke_from_event_loop()`] are processed
+/// as well, so calling this function repeatedly allows embedding SixtyFPS in an event loop
+/// owned by the application, such as a game loop.
+///
+/// Returns `false` once the event loop was asked to quit, either by [`quit_event_loop()`] or
+/// because the last window was closed. It also returns `false` without processing anything
+/// if the backend cannot process events without running the event loop. The GL backend on
+/// wasm is such a backend.
+///
+/// # Example
+/// ```rust,no_run
+/// sixtyfps::sixtyfps! { MyApp := Window { /* ... */ } }
+/// let handle = MyApp::new();
+/// handle.show();
+/// while sixtyfps::process_events(std::time::Duration::from_millis(16)) {
+///     // ... advance the rest of the application
+/// }
+/// ```
+pub fn process_events(timeout: core::time::Duration) -> bool {
+    sixtyfps_rendering_backend_default::backend().process_events(
+        timeout,
+        sixtyfps_corelib::backend::EventLoopQuitBehavior::QuitOnLastWindowClosed,
+    )
+}
+
+/// Processes the events that are already pending without waiting for new ones. This is the
+/// same as calling [`process_events()`] with a zero timeout.
+pub fn pump_events() -> bool {
+    process_events(core::time::Duration::ZERO)
+}
+
 /// Adds the specified function to an internal queue, notifies the event loop to wake up.
 /// Once woken up, any queued up functors will be invoked.
 ///
@@ -478,6 +516,37 @@ pub fn invoke_from_event_loop(func: impl FnOnce() + Send + 'static) {
     sixtyfps_rendering_backend_default::backend().post_event(alloc::boxed::Box::new(func))
 }
 
+/// Spawns a future on the thread running the event loop and returns a [`JoinHandle`]
+/// that resolves to its output. Unlike [`invoke_from_event_loop`], the future does not
+/// need to be `Send`, so it can hold component handles. It must be called from the thread
+/// running the event loop.
+///
+/// The future is polled from the event loop each time it is woken up, so it can await
+/// [`sleep`] or any other future that uses its waker, such as the result of a computation
+/// running in another thread.
+///
+/// # Example
+/// ```rust
+/// sixtyfps::sixtyfps! { MyApp := Window { property <int> foo; /* ... */ } }
+/// let handle = MyApp::new();
+/// let handle_weak = handle.as_weak();
+/// sixtyfps::spawn_local(async move {
+///     sixtyfps::sleep(std::time::Duration::from_secs(1)).await;
+///     handle_weak.unwrap().set_foo(42);
+/// });
+/// # return; // don't run the event loop in examples
+/// handle.run();
+/// ```
+#[cfg(feature = "std")]
+pub fn spawn_local<F: core::future::Future + 'static>(fut: F) -> JoinHandle<F::Output> {
+    // make sure the backend is initialized, as the waker posts events to it
+    sixtyfps_rendering_backend_default::backend();
+    sixtyfps_corelib::future::spawn_local(fut)
+}
+
+#[cfg(feature = "std")]
+pub use sixtyfps_corelib::future::{async_callback, sleep, AsyncCallback, JoinHandle, Sleep};
+
 /// This trait is used to obtain references to global singletons exported in `.60`
 /// markup. Alternatively, you can use [`ComponentHandle::global`] to obtain access.
 ///
@@ -674,7 +743,7 @@ unsafe impl<T: ComponentHandle> Send for Weak<T> {}
 
 pub use weak_handle::*;
 
-pub use sixtyfps_corelib::window::api::Window;
+pub use sixtyfps_corelib::window::api::{Window, WindowState};
 
 /// This module contains functions useful for unit tests
 #[cfg(feature = "std")]
@@ -684,7 +753,19 @@ pub mod testing {
 
     use super::ComponentHandle;
 
-    pub use sixtyfps_corelib::tests::sixtyfps_mock_elapsed_time as mock_elapsed_time;
+    /// Advance the mocked time used by the animations and the timers.
+    ///
+    /// Posted events are processed, the timers expiring within `duration` fire in order,
+    /// and property animations and state transitions are stepped, without actually sleeping.
+    pub fn mock_elapsed_time(duration: core::time::Duration) {
+        sixtyfps_corelib::tests::sixtyfps_mock_elapsed_time(duration.as_millis() as u64);
+    }
+
+    /// Process the posted events and advance the mocked time until there is no pending
+    /// event, running animation or single shot timer left.
+    pub fn run_until_idle() {
+        sixtyfps_corelib::tests::sixtyfps_run_until_idle();
+    }
 
     /// Simulate a mouse click
     pub fn send_mouse_click<
@@ -713,9 +794,11 @@ pub fn set_current_keyboard_modifiers<
             + crate::re_exports::WindowHandleAccess,
         Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + ComponentHandle,
     >(
-        _component: &Component,
+        component: &Component,
         modifiers: crate::re_exports::KeyboardModifiers,
     ) {
+        let rc = component.clone_strong().into();
+        rc.window_handle().set_current_keyboard_modifiers(modifiers);
         KEYBOARD_MODIFIERS.with(|x| x.set(modifiers))
     }
 
@@ -749,6 +832,75 @@ pub fn set_window_scale_factor<
         let component = component.clone_strong().into();
         component.window_handle().set_scale_factor(factor)
     }
+
+    /// Returns the accessibility tree of the component, as exposed to assistive technologies.
+    /// Its `Display` implementation produces a dump of the tree that can be compared in tests.
+    pub fn accessibility_tree<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable> + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+    ) -> AccessibilityNode {
+        let rc = component.clone_strong().into();
+        sixtyfps_corelib::accessibility::accessibility_tree(&vtable::VRc::into_dyn(rc))
+    }
+
+    /// Returns the first element of the component, in drawing order, that has the given `id`
+    /// in the .60 source.
+    ///
+    /// ```ignore
+    /// sixtyfps::testing::find_by_id(&app, "ok-button").unwrap().click();
+    /// ```
+    pub fn find_by_id<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable>
+            + crate::re_exports::WindowHandleAccess
+            + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+        id: &str,
+    ) -> Option<ElementHandle> {
+        find_all_by_id(component, id).into_iter().next()
+    }
+
+    /// Returns all the elements of the component that have the given `id` in the .60 source,
+    /// in drawing order. There can be several of them when the element is repeated.
+    pub fn find_all_by_id<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable>
+            + crate::re_exports::WindowHandleAccess
+            + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+        id: &str,
+    ) -> Vec<ElementHandle> {
+        let rc = component.clone_strong().into();
+        let dyn_rc = vtable::VRc::into_dyn(rc.clone());
+        ElementHandle::find_all_by_id(&dyn_rc, &rc.window_handle().clone(), id)
+    }
+
+    /// Returns the first element of the component, in drawing order, whose text or
+    /// `accessible-label` is equal to `text`.
+    pub fn find_by_text<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable>
+            + crate::re_exports::WindowHandleAccess
+            + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+        text: &str,
+    ) -> Option<ElementHandle> {
+        let rc = component.clone_strong().into();
+        let dyn_rc = vtable::VRc::into_dyn(rc.clone());
+        ElementHandle::find_all_by_text(&dyn_rc, &rc.window_handle().clone(), text)
+            .into_iter()
+            .next()
+    }
+
+    pub use sixtyfps_corelib::accessibility::{
+        AccessibilityNode, AccessibleAction, AccessibleStates,
+    };
+    pub use sixtyfps_corelib::tests::ElementHandle;
 }
 
 /// Include the code generated with the sixtyfps-build crate from the build script. After calling `sixtyfps_build::compile`
@@ -57,11 +57,11 @@ pub struct CompilerConfiguration {
 
 impl Default for CompilerConfiguration {
     fn default() -> Self {
-        Self {
-            config: sixtyfps_compilerlib::CompilerConfiguration::new(
-                sixtyfps_compilerlib::generator::OutputFormat::Rust,
-            ),
-        }
+        let mut config = sixtyfps_compilerlib::CompilerConfiguration::new(
+            sixtyfps_compilerlib::generator::OutputFormat::Rust,
+        );
+        config.translation_domain = env::var("CARGO_PKG_NAME").ok();
+        Self { config }
     }
 }
 
@@ -87,6 +87,15 @@ pub fn with_style(self, style: String) -> Self {
         config.style = Some(style);
         Self { config }
     }
+
+    /// Create a new configuration that sets the gettext domain used to look up the translations
+    /// of the strings marked with `@tr(...)`. The default is the name of the crate.
+    #[must_use]
+    pub fn with_translation_domain(self, domain: String) -> Self {
+        let mut config = self.config;
+        config.translation_domain = Some(domain);
+        Self { config }
+    }
 }
 
 /// Error returned by the `compile` function
@@ -180,24 +180,24 @@ fn fill_token_vec(stream: impl Iterator<Item = TokenTree>, vec: &mut Vec<parser:
                         SyntaxKind::AndAnd
                     }
                     '|' => {
-                        // Since the '|' alone does not exist or cannot be part of any other token that ||
-                        // just consider it as '||' and skip the joint ones.  FIXME. do that properly
                         if let Some(last) = vec.last_mut() {
-                            if last.kind == SyntaxKind::OrOr && prev_spacing == Spacing::Joint {
+                            if last.kind == SyntaxKind::Pipe && prev_spacing == Spacing::Joint {
+                                last.kind = SyntaxKind::OrOr;
+                                last.text = "||".into();
                                 continue;
                             }
                         }
-                        SyntaxKind::OrOr
+                        SyntaxKind::Pipe
                     }
                     '%' => {
-                        // % can only exist after number literal
+                        // % after a number literal is the percent unit
                         if let Some(last) = vec.last_mut() {
                             if last.kind == SyntaxKind::NumberLiteral {
                                 last.text = format!("{}%", last.text).into();
                                 continue;
                             }
                         }
-                        SyntaxKind::Error
+                        SyntaxKind::Percent
                     }
                     '$' => SyntaxKind::Dollar,
                     '@' => SyntaxKind::At,
@@ -338,6 +338,7 @@ pub fn sixtyfps(stream: TokenStream) -> TokenStream {
     //println!("{:#?}", syntax_node);
     let mut compiler_config =
         CompilerConfiguration::new(sixtyfps_compilerlib::generator::OutputFormat::Rust);
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
 
     if std::env::var_os("SIXTYFPS_STYLE").is_none() {
         // This file is written by the sixtyfps-rendering-backend-default's built script.
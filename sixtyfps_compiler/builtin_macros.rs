@@ -53,10 +53,81 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => {
+            match number_literal_arguments(n, sub_expr.collect(), 3, "spring", diag).as_slice() {
+                [mass, stiffness, damping] => {
+                    Expression::EasingCurve(EasingCurve::Spring(*mass, *stiffness, *damping))
+                }
+                _ => Expression::Invalid,
+            }
+        }
+        BuiltinMacroFunction::Steps => {
+            match number_literal_arguments(n.clone(), sub_expr.collect(), 1, "steps", diag)
+                .as_slice()
+            {
+                [steps] if *steps >= 1. && steps.fract() == 0. => {
+                    Expression::EasingCurve(EasingCurve::Steps(*steps as u32))
+                }
+                [_] => {
+                    diag.push_error("The number of steps must be a positive integer".into(), &n);
+                    Expression::Invalid
+                }
+                _ => Expression::Invalid,
+            }
+        }
+        BuiltinMacroFunction::CustomEasing => {
+            let args = sub_expr.collect::<Vec<_>>();
+            match args.as_slice() {
+                [(Expression::StringLiteral(name), _)] => {
+                    Expression::EasingCurve(EasingCurve::Custom(name.clone()))
+                }
+                [(_, arg_node)] => {
+                    diag.push_error(
+                        "The argument to custom easing must be a string literal".into(),
+                        arg_node,
+                    );
+                    Expression::Invalid
+                }
+                _ => {
+                    diag.push_error("custom easing expects one argument".into(), &n);
+                    Expression::Invalid
+                }
+            }
+        }
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
     }
 }
 
+/// Checks that there are `count` arguments that are number literals, and returns their values.
+/// Returns an empty vector in case of error.
+fn number_literal_arguments(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    count: usize,
+    name: &str,
+    diag: &mut BuildDiagnostics,
+) -> Vec<f32> {
+    if args.len() < count {
+        diag.push_error("Not enough arguments".into(), &node);
+        return vec![];
+    }
+    if let Some((_, arg_node)) = args.get(count) {
+        diag.push_error(format!("Too many argument for {}", name), arg_node);
+        return vec![];
+    }
+    let mut result = Vec::with_capacity(count);
+    for (expr, arg_node) in args {
+        match expr {
+            Expression::NumberLiteral(val, Unit::None) => result.push(val as f32),
+            _ => {
+                diag.push_error(format!("Arguments to {} must be number literal", name), &arg_node);
+                return vec![];
+            }
+        }
+    }
+    result
+}
+
 fn min_max_macro(
     node: Option<NodeOrToken>,
     op: char,
@@ -91,6 +91,13 @@ pub fn from_path_only(path: PathBuf) -> Rc<Self> {
         Rc::new(Self { path, ..Default::default() })
     }
 
+    /// Returns the line number (starting at 1) of the given offset
+    pub fn line_number(&self, offset: usize) -> usize {
+        match self.line_offsets().binary_search(&offset) {
+            Ok(line) | Err(line) => line + 1,
+        }
+    }
+
     fn line_offsets(&self) -> &[usize] {
         self.line_offsets.get_or_init(|| {
             self.source
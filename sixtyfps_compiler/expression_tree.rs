@@ -35,6 +35,8 @@ pub enum BuiltinFunction {
     Pow,
     SetFocusItem,
     ShowPopupWindow,
+    ShowChildWindow,
+    HideChildWindow,
     /// the "42".to_float()
     StringToFloat,
     /// the "42".is_float()
@@ -47,6 +49,11 @@ pub enum BuiltinFunction {
     ImplicitLayoutInfo(Orientation),
     RegisterCustomFontByPath,
     RegisterCustomFontByMemory,
+    /// `@tr(...)`, with the arguments: format string, context, domain, array of arguments,
+    /// count for the plural form, plural format string
+    Translate,
+    /// The text shown in menus for the key sequence of a `Shortcut`
+    KeySequenceDisplayText,
 }
 
 #[derive(Debug, Clone)]
@@ -55,6 +62,9 @@ pub enum BuiltinMacroFunction {
     Min,
     Max,
     CubicBezier,
+    Spring,
+    Steps,
+    CustomEasing,
     Rgb,
     Debug,
 }
@@ -97,6 +107,10 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
             },
+            BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow => Type::Function {
+                return_type: Box::new(Type::Void),
+                args: vec![Type::ElementReference],
+            },
             BuiltinFunction::StringToFloat => {
                 Type::Function { return_type: Box::new(Type::Float32), args: vec![Type::String] }
             }
@@ -140,6 +154,20 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::RegisterCustomFontByMemory => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Int32] }
             }
+            BuiltinFunction::Translate => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![
+                    Type::String,
+                    Type::String,
+                    Type::String,
+                    Type::Array(Box::new(Type::String)),
+                    Type::Int32,
+                    Type::String,
+                ],
+            },
+            BuiltinFunction::KeySequenceDisplayText => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::String] }
+            }
         }
     }
 
@@ -165,6 +193,7 @@ fn is_pure(&self) -> bool {
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
             BuiltinFunction::ShowPopupWindow => false,
+            BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
             BuiltinFunction::ColorBrighter | BuiltinFunction::ColorDarker => true,
             // ImageSize is pure, except when loading images via the network. Then the initial size will be 0/0 and
@@ -180,6 +209,9 @@ fn is_pure(&self) -> bool {
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory => false,
+            // The result depends on the current translation catalog
+            BuiltinFunction::Translate => false,
+            BuiltinFunction::KeySequenceDisplayText => true,
         }
     }
 }
@@ -313,6 +345,9 @@ pub enum Expression {
     /// Reference to the callback <name> in the <element>
     PropertyReference(NamedReference),
 
+    /// Reference to the function <name> declared with the `function` keyword in the <element>
+    FunctionReference(NamedReference),
+
     /// Reference to a function built into the run-time, implemented natively
     BuiltinFunctionReference(BuiltinFunction, Option<SourceLocation>),
 
@@ -448,6 +483,11 @@ pub enum Expression {
         stops: Vec<(Expression, Expression)>,
     },
 
+    RadialGradient {
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
+    },
+
     EnumerationValue(EnumerationValue),
 
     ReturnStatement(Option<Box<Expression>>),
@@ -482,6 +522,7 @@ pub fn ty(&self) -> Type {
             Expression::BoolLiteral(_) => Type::Bool,
             Expression::CallbackReference(nr) => nr.ty(),
             Expression::PropertyReference(nr) => nr.ty(),
+            Expression::FunctionReference(nr) => nr.ty(),
             Expression::BuiltinFunctionReference(funcref, _) => funcref.ty(),
             Expression::MemberFunction { member, .. } => member.ty(),
             Expression::BuiltinMacroReference { .. } => Type::Invalid, // We don't know the type
@@ -599,6 +640,7 @@ pub fn ty(&self) -> Type {
             Expression::ReadLocalVariable { ty, .. } => ty.clone(),
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
+            Expression::RadialGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -618,6 +660,7 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::BoolLiteral(_) => {}
             Expression::CallbackReference { .. } => {}
             Expression::PropertyReference { .. } => {}
+            Expression::FunctionReference { .. } => {}
             Expression::FunctionParameterReference { .. } => {}
             Expression::BuiltinFunctionReference { .. } => {}
             Expression::MemberFunction { base, member, .. } => {
@@ -687,6 +730,12 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                     visitor(s);
                 }
             }
+            Expression::RadialGradient { stops } => {
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref().map(visitor);
@@ -708,6 +757,7 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::BoolLiteral(_) => {}
             Expression::CallbackReference { .. } => {}
             Expression::PropertyReference { .. } => {}
+            Expression::FunctionReference { .. } => {}
             Expression::FunctionParameterReference { .. } => {}
             Expression::BuiltinFunctionReference { .. } => {}
             Expression::MemberFunction { base, member, .. } => {
@@ -780,6 +830,12 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                     visitor(s);
                 }
             }
+            Expression::RadialGradient { stops } => {
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
             Expression::EnumerationValue(_) => {}
             Expression::ReturnStatement(expr) => {
                 expr.as_deref_mut().map(visitor);
@@ -813,6 +869,7 @@ pub fn is_constant(&self) -> bool {
             Expression::BoolLiteral(_) => true,
             Expression::CallbackReference { .. } => false,
             Expression::PropertyReference(nr) => nr.is_constant(),
+            Expression::FunctionReference { .. } => false,
             Expression::BuiltinFunctionReference(func, _) => func.is_pure(),
             Expression::MemberFunction { .. } => false,
             Expression::ElementReference(_) => false,
@@ -851,6 +908,9 @@ pub fn is_constant(&self) -> bool {
             Expression::LinearGradient { angle, stops } => {
                 angle.is_constant() && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
+            Expression::RadialGradient { stops } => {
+                stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
@@ -1224,7 +1284,11 @@ pub enum EasingCurve {
     Linear,
     CubicBezier(f32, f32, f32, f32),
     // CubicBezierNonConst([Box<Expression>; 4]),
-    // Custom(Box<dyn Fn(f32)->f32>),
+    /// mass, stiffness, damping
+    Spring(f32, f32, f32),
+    Steps(u32),
+    /// The name of an easing curve registered at run-time
+    Custom(String),
 }
 
 impl Default for EasingCurve {
@@ -1253,6 +1317,7 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
         Expression::BoolLiteral(b) => write!(f, "{:?}", b),
         Expression::CallbackReference(a) => write!(f, "{:?}", a),
         Expression::PropertyReference(a) => write!(f, "{:?}", a),
+        Expression::FunctionReference(a) => write!(f, "{:?}", a),
         Expression::BuiltinFunctionReference(a, _) => write!(f, "{:?}", a),
         Expression::MemberFunction { base, base_node: _, member } => {
             pretty_print(f, base)?;
@@ -1365,6 +1430,16 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, ")")
         }
+        Expression::RadialGradient { stops } => {
+            write!(f, "@radial-gradient(circle")?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
+            write!(f, ")")
+        }
         Expression::EnumerationValue(e) => match e.enumeration.values.get(e.value as usize) {
             Some(val) => write!(f, "{}.{}", e.enumeration.name, val),
             None => write!(f, "{}.{}", e.enumeration.name, e.value),
@@ -21,6 +21,8 @@
 #[cfg(feature = "rust")]
 pub mod rust;
 
+mod pot;
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum OutputFormat {
     #[cfg(feature = "cpp")]
@@ -29,6 +31,8 @@ pub enum OutputFormat {
     Rust,
     Interpreter,
     Llr,
+    /// gettext message template with the strings marked with `@tr(...)`
+    Pot,
 }
 
 impl OutputFormat {
@@ -38,6 +42,7 @@ pub fn guess_from_extension(path: &std::path::Path) -> Option<Self> {
             Some("cpp") | Some("cxx") | Some("h") | Some("hpp") => Some(Self::Cpp),
             #[cfg(feature = "rust")]
             Some("rs") => Some(Self::Rust),
+            Some("pot") => Some(Self::Pot),
             _ => None,
         }
     }
@@ -52,6 +57,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             #[cfg(feature = "rust")]
             "rust" => Ok(Self::Rust),
             "llr" => Ok(Self::Llr),
+            "pot" => Ok(Self::Pot),
             _ => Err(format!("Unknown outpout format {}", s)),
         }
     }
@@ -94,6 +100,9 @@ pub fn generate(
                 crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component)
             )?;
         }
+        OutputFormat::Pot => {
+            write!(destination, "{}", pot::generate(doc))?;
+        }
     }
     Ok(())
 }
@@ -104,6 +104,7 @@ pub enum Declaration {
         Function(Function),
         Var(Var),
         TypeAlias(TypeAlias),
+        Enum(Enum),
     }
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -262,6 +263,26 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         }
     }
 
+    /// An `enum class` declaration
+    #[derive(Default, Debug)]
+    pub struct Enum {
+        pub name: String,
+        pub values: Vec<String>,
+    }
+
+    impl Display for Enum {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            indent(f)?;
+            writeln!(f, "enum class {} {{", self.name)?;
+            for value in &self.values {
+                indent(f)?;
+                writeln!(f, "    {},", value)?;
+            }
+            indent(f)?;
+            writeln!(f, "}};")
+        }
+    }
+
     pub trait CppType {
         fn cpp_type(&self) -> Option<String>;
     }
@@ -333,6 +354,9 @@ fn cpp_type(&self) -> Option<String> {
             Type::Array(i) => Some(format!("std::shared_ptr<sixtyfps::Model<{}>>", i.cpp_type()?)),
             Type::Image => Some("sixtyfps::Image".to_owned()),
             Type::Builtin(elem) => elem.native_class.cpp_type.clone(),
+            Type::Enumeration(enumeration) if enumeration.node.is_some() => {
+                Some(ident(&enumeration.name))
+            }
             Type::Enumeration(enumeration) => {
                 Some(format!("sixtyfps::cbindgen_private::{}", ident(&enumeration.name)))
             }
@@ -512,6 +536,13 @@ pub fn generate(doc: &Document) -> impl std::fmt::Display {
         },
     ));
 
+    for en in doc.root_component.used_types.borrow().enums.iter() {
+        file.declarations.push(Declaration::Enum(Enum {
+            name: ident(&en.name),
+            values: en.values.iter().map(|v| ident(v)).collect(),
+        }));
+    }
+
     for ty in doc.root_component.used_types.borrow().structs.iter() {
         if let Type::Struct { fields, name: Some(name), node: Some(_) } = ty {
             generate_struct(&mut file, name, fields);
@@ -779,6 +810,19 @@ fn generate_item_tree(
         item_tree_class_name
     ));
 
+    if sub_tree.is_child_window {
+        // The window need to be the first member so it is destroyed last
+        target_struct.members.push((
+            Access::Public,
+            Declaration::Var(Var {
+                ty: "sixtyfps::Window".into(),
+                name: "m_window".into(),
+                init: Some("sixtyfps::Window{sixtyfps::private_api::WindowRc()}".into()),
+                ..Default::default()
+            }),
+        ));
+    }
+
     generate_sub_component(
         target_struct,
         &sub_tree.root,
@@ -789,6 +833,9 @@ fn generate_item_tree(
     );
 
     let root_access = if parent_ctx.is_some() { "parent->root" } else { "self" };
+    // Child windows and the root have their own window, the other trees use the parent's window
+    let has_own_window = parent_ctx.is_none() || sub_tree.is_child_window;
+    let window = if has_own_window { "self->m_window" } else { "(*parent->window_ptr)" };
 
     let mut tree_array: Vec<String> = Default::default();
 
@@ -951,6 +998,88 @@ fn generate_item_tree(
         }),
     ));
 
+    let ctx = EvaluationContext {
+        public_component: root,
+        current_sub_component: Some(&sub_tree.root),
+        current_global: None,
+        generator_state: "self".into(),
+        parent: parent_ctx.clone(),
+        argument_types: &[],
+    };
+    let mut accessible_role_cases = vec![];
+    let mut accessible_string_statements = vec![];
+    for ((index, what), prop) in sub_tree.accessible_props() {
+        let prop = access_member(&prop, &ctx);
+        let (what, value) = match what.as_str() {
+            "accessible-role" => {
+                accessible_role_cases.push(format!("    case {}: return {}.get();", index, prop));
+                continue;
+            }
+            "accessible-checked" => ("Checked", format!("{}.get() ? \"true\" : \"false\"", prop)),
+            "accessible-label" => ("Label", format!("{}.get()", prop)),
+            "accessible-description" => ("Description", format!("{}.get()", prop)),
+            "accessible-value" => ("Value", format!("{}.get()", prop)),
+            _ => unreachable!("unknown accessible property {}", what),
+        };
+        accessible_string_statements.push(format!(
+            "if (index == {} && what == sixtyfps::cbindgen_private::AccessibleStringProperty::{}) {{ *result = {}; return; }}",
+            index, what, value
+        ));
+    }
+
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "accessible_role".into(),
+            signature: "([[maybe_unused]] sixtyfps::private_api::ComponentRef component, uintptr_t index) -> sixtyfps::cbindgen_private::AccessibleRole".into(),
+            is_static: true,
+            statements: Some(
+                std::iter::once(format!("[[maybe_unused]] auto self = reinterpret_cast<const {}*>(component.instance);", item_tree_class_name))
+                    .chain(std::iter::once("switch (index) {".into()))
+                    .chain(accessible_role_cases)
+                    .chain(["}".into(), "return sixtyfps::cbindgen_private::AccessibleRole::none;".into()])
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+    ));
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "accessible_string_property".into(),
+            signature: "([[maybe_unused]] sixtyfps::private_api::ComponentRef component, uintptr_t index, [[maybe_unused]] sixtyfps::cbindgen_private::AccessibleStringProperty what, [[maybe_unused]] sixtyfps::SharedString *result) -> void".into(),
+            is_static: true,
+            statements: Some(
+                std::iter::once(format!("[[maybe_unused]] auto self = reinterpret_cast<const {}*>(component.instance);", item_tree_class_name))
+                    .chain(accessible_string_statements)
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+    ));
+    let element_ids_cases = sub_tree.element_ids().into_iter().map(|(index, ids)| {
+        format!(
+            "    case {}: *result = sixtyfps::SharedVector<sixtyfps::SharedString>{{ {} }}; return;",
+            index,
+            ids.iter().map(|id| format!("sixtyfps::SharedString(u8\"{}\")", id)).join(", ")
+        )
+    });
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "element_ids".into(),
+            signature: "(sixtyfps::private_api::ComponentRef, uintptr_t index, [[maybe_unused]] sixtyfps::SharedVector<sixtyfps::SharedString> *result) -> void".into(),
+            is_static: true,
+            statements: Some(
+                std::iter::once("switch (index) {".into())
+                    .chain(element_ids_cases)
+                    .chain(std::iter::once("}".into()))
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Public,
         Declaration::Var(Var {
@@ -964,7 +1093,7 @@ fn generate_item_tree(
         ty: "const sixtyfps::private_api::ComponentVTable".to_owned(),
         name: format!("{}::static_vtable", item_tree_class_name),
         init: Some(format!(
-            "{{ visit_children, get_item_ref, parent_item,  layout_info, sixtyfps::private_api::drop_in_place<{}>, sixtyfps::private_api::dealloc }}",
+            "{{ visit_children, get_item_ref, parent_item,  layout_info, accessible_role, accessible_string_property, element_ids, sixtyfps::private_api::drop_in_place<{}>, sixtyfps::private_api::dealloc }}",
             item_tree_class_name)
         ),
         ..Default::default()
@@ -990,16 +1119,16 @@ fn generate_item_tree(
         "self->self_weak = vtable::VWeak(self_rc).into_dyn();".into(),
     ];
 
-    if parent_ctx.is_none() {
-        create_code.extend([format!(
-            "{}->m_window.window_handle().set_component(*self_rc);",
-            root_access
-        )]);
+    if has_own_window {
+        create_code.extend([format!("{}.window_handle().set_component(*self_rc);", window)]);
     }
 
     create_code.extend([
-        format!("{}->m_window.window_handle().init_items(self, item_tree());", root_access),
-        format!("self->init({}, self->self_weak, 0, 1 {});", root_access, init_parent_parameters),
+        format!("{}.window_handle().init_items(self, item_tree());", window),
+        format!(
+            "self->init({}, &{}, self->self_weak, 0, 1 {});",
+            root_access, window, init_parent_parameters
+        ),
         format!("return sixtyfps::ComponentHandle<{0}>{{ self_rc }};", target_struct.name),
     ]);
 
@@ -1020,10 +1149,9 @@ fn generate_item_tree(
 
     let mut destructor = vec!["auto self = this;".to_owned()];
 
-    destructor.push(format!(
-        "{}->m_window.window_handle().free_graphics_resources(self, item_tree());",
-        root_access
-    ));
+    destructor.push(
+        "self->window_ptr->window_handle().free_graphics_resources(self, item_tree());".into(),
+    );
 
     target_struct.members.push((
         Access::Public,
@@ -1049,6 +1177,7 @@ fn generate_sub_component(
 
     let mut init_parameters = vec![
         format!("{} root", root_ptr_type),
+        "sixtyfps::Window *window_ptr".into(),
         "sixtyfps::cbindgen_private::ComponentWeak enclosing_component".into(),
         "uintptr_t tree_index".into(),
         "uintptr_t tree_index_of_first_child".into(),
@@ -1076,6 +1205,16 @@ fn generate_sub_component(
     ));
     init.push("self->root = root;".into());
 
+    target_struct.members.push((
+        field_access,
+        Declaration::Var(Var {
+            ty: "sixtyfps::Window *".to_owned(),
+            name: "window_ptr".to_owned(),
+            ..Default::default()
+        }),
+    ));
+    init.push("self->window_ptr = window_ptr;".into());
+
     target_struct.members.push((
         field_access,
         Declaration::Var(Var {
@@ -1175,7 +1314,7 @@ fn generate_sub_component(
         };
 
         subcomponent_init_code.push(format!(
-            "this->{}.init(root, self_weak.into_dyn(), {}, {});",
+            "this->{}.init(root, window_ptr, self_weak.into_dyn(), {}, {});",
             field_name, global_index, global_children
         ));
 
@@ -1311,6 +1450,32 @@ fn generate_sub_component(
     init.extend(properties_init_code);
     init.extend(component.init_code.iter().map(|e| compile_expression(e, &ctx)));
 
+    for (idx, (prop, expression)) in component.change_callbacks.iter().enumerate() {
+        let change_tracker_id = format!("change_tracker_{}", idx);
+        init.push(format!(
+            "self->{id}.init(
+                [this]() {{
+                    [[maybe_unused]] auto self = this;
+                    return {prop}.get();
+                }},
+                [this]() {{
+                    [[maybe_unused]] auto self = this;
+                    {code};
+                }});",
+            id = change_tracker_id,
+            prop = access_member(prop, &ctx),
+            code = compile_expression_wrap_return(expression, &ctx)
+        ));
+        target_struct.members.push((
+            Access::Private,
+            Declaration::Var(Var {
+                ty: "sixtyfps::private_api::ChangeTracker".into(),
+                name: change_tracker_id,
+                ..Default::default()
+            }),
+        ));
+    }
+
     target_struct.members.push((
         field_access,
         Declaration::Function(Function {
@@ -1339,6 +1504,10 @@ fn generate_sub_component(
         }),
     ));
 
+    for function in &component.functions {
+        target_struct.members.push((field_access, generate_function(function, &ctx)));
+    }
+
     if !children_visitor_cases.is_empty() {
         target_struct.members.push((
             field_access,
@@ -1509,6 +1678,11 @@ fn generate_global(file: &mut File, global: &llr::GlobalComponent, root: &llr::P
         }),
     ));
 
+    for function in &global.functions {
+        // Public for the same reason as the properties above
+        global_struct.members.push((Access::Public, generate_function(function, &ctx)));
+    }
+
     let declarations = generate_public_api_for_properties(&global.public_properties, &ctx);
     global_struct.members.extend(declarations.into_iter().map(|decl| (Access::Public, decl)));
 
@@ -1516,6 +1690,36 @@ fn generate_global(file: &mut File, global: &llr::GlobalComponent, root: &llr::P
     file.declarations.push(Declaration::Struct(global_struct));
 }
 
+/// Generate the member function for a function declared with the `function` keyword
+fn generate_function(function: &llr::Function, ctx: &EvaluationContext) -> Declaration {
+    let mut ctx2 = ctx.clone();
+    ctx2.argument_types = &function.args;
+
+    let params = function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| format!("[[maybe_unused]] {} arg_{}", ty.cpp_type().unwrap_or_default(), i))
+        .collect::<Vec<_>>();
+    let code = compile_expression_wrap_return(&function.code, &ctx2);
+    let (return_type, body) = if function.ret_ty == Type::Void {
+        ("void".to_owned(), format!("{};", code))
+    } else {
+        (function.ret_ty.cpp_type().unwrap_or_default(), format!("return {};", code))
+    };
+
+    Declaration::Function(Function {
+        name: function_ident(&function.name),
+        signature: format!("({}) const -> {}", params.join(", "), return_type),
+        statements: Some(vec!["[[maybe_unused]] auto self = this;".into(), body]),
+        ..Default::default()
+    })
+}
+
+fn function_ident(name: &str) -> String {
+    format!("fn_{}", ident(name))
+}
+
 fn generate_public_api_for_properties(
     public_properties: &llr::PublicProperties,
     ctx: &EvaluationContext,
@@ -1603,9 +1807,8 @@ fn follow_sub_component_path<'a>(
     (compo_path, sub_component)
 }
 
-fn access_window_field(ctx: &EvaluationContext) -> String {
-    let root = &ctx.generator_state;
-    format!("{}->window().window_handle()", root)
+fn access_window_field(_ctx: &EvaluationContext) -> String {
+    "self->window_ptr->window_handle()".into()
 }
 
 /// Returns the code that can access the given property (but without the set or get)
@@ -1676,7 +1879,10 @@ fn in_native_item(
                     item_index,
                     prop_name,
                 } => in_native_item(ctx, sub_component_path, *item_index, prop_name, &path),
-                llr::PropertyReference::InParent { .. } | llr::PropertyReference::Global { .. } => {
+                llr::PropertyReference::InParent { .. }
+                | llr::PropertyReference::Global { .. }
+                | llr::PropertyReference::Function { .. }
+                | llr::PropertyReference::GlobalFunction { .. } => {
                     unreachable!()
                 }
             }
@@ -1690,6 +1896,55 @@ fn in_native_item(
             );
             format!("{}->{}->{}", root_access, global_id, property_name)
         }
+        llr::PropertyReference::Function { .. } | llr::PropertyReference::GlobalFunction { .. } => {
+            unreachable!("functions can only be called")
+        }
+    }
+}
+
+/// Returns the code to access the function referenced by `reference`, to be followed by the arguments
+fn access_function(reference: &llr::PropertyReference, ctx: &EvaluationContext) -> String {
+    match reference {
+        llr::PropertyReference::Function { sub_component_path, function_index } => {
+            if let Some(sub_component) = ctx.current_sub_component {
+                let (compo_path, sub_component) =
+                    follow_sub_component_path(sub_component, sub_component_path);
+                let function_name = function_ident(&sub_component.functions[*function_index].name);
+                format!("self->{}{}", compo_path, function_name)
+            } else if let Some(current_global) = ctx.current_global {
+                format!("this->{}", function_ident(&current_global.functions[*function_index].name))
+            } else {
+                unreachable!()
+            }
+        }
+        llr::PropertyReference::InParent { level, parent_reference } => {
+            let mut ctx = ctx;
+            let mut path = "self".to_string();
+            for _ in 0..level.get() {
+                write!(path, "->parent").unwrap();
+                ctx = ctx.parent.as_ref().unwrap().ctx;
+            }
+            match &**parent_reference {
+                llr::PropertyReference::Function { sub_component_path, function_index } => {
+                    let (compo_path, sub_component) = follow_sub_component_path(
+                        ctx.current_sub_component.unwrap(),
+                        sub_component_path,
+                    );
+                    let function_name =
+                        function_ident(&sub_component.functions[*function_index].name);
+                    format!("{}->{}{}", path, compo_path, function_name)
+                }
+                _ => unreachable!(),
+            }
+        }
+        llr::PropertyReference::GlobalFunction { global_index, function_index } => {
+            let root_access = &ctx.generator_state;
+            let global = &ctx.public_component.globals[*global_index];
+            let global_id = format!("global_{}", ident(&global.name));
+            let function_name = function_ident(&global.functions[*function_index].name);
+            format!("{}->{}->{}", root_access, global_id, function_name)
+        }
+        _ => unreachable!("not a function reference"),
     }
 }
 
@@ -1745,6 +2000,11 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             let mut a = arguments.iter().map(|a| compile_expression(a, ctx));
             format!("{}.call({})", f, a.join(","))
         }
+        Expression::FunctionCall{ function, arguments } => {
+            let f = access_function(function, ctx);
+            let mut a = arguments.iter().map(|a| compile_expression(a, ctx));
+            format!("{}({})", f, a.join(","))
+        }
         Expression::ExtraBuiltinFunctionCall { function, arguments, return_ty: _ } => {
             let mut a = arguments.iter().map(|a| compile_expression(a, ctx));
             format!("sixtyfps::private_api::{}({})", ident(&function), a.join(","))
@@ -1992,13 +2252,24 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                         .unwrap_or_else(|| "(Error: missing member in object)".to_owned())
                 });
                 format!("std::make_tuple({})", elem.join(", "))
-            } else if let Type::Struct{ name: Some(_), .. } = ty {
+            } else if let Type::Struct{ name: Some(name), .. } = ty {
                 format!(
                     "[&]({args}){{ {ty} o{{}}; {fields}return o; }}({vals})",
                     args = (0..values.len()).map(|i| format!("const auto &a_{}", i)).join(", "),
                     ty = ty.cpp_type().unwrap(),
                     fields = values.keys().enumerate().map(|(i, f)| format!("o.{} = a_{}; ", ident(f), i)).join(""),
-                    vals = values.values().map(|e| compile_expression(e, ctx)).join(", "),
+                    vals = values.values().map(|e| match e {
+                        // The keyframes of the animations are stored in a SharedVector
+                        Expression::Array { element_ty, values, as_model: false } if name == "PropertyAnimation" => {
+                            let ty = element_ty.cpp_type().unwrap();
+                            format!(
+                                "sixtyfps::SharedVector<{ty}>{{ {val} }}",
+                                ty = ty,
+                                val = values.iter().map(|e| format!("{ty} ( {expr} )", expr = compile_expression(e, ctx), ty = ty)).join(", ")
+                            )
+                        }
+                        _ => compile_expression(e, ctx),
+                    }).join(", "),
                 )
             } else {
                 panic!("Expression::Object is not a Type::Object")
@@ -2009,6 +2280,18 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             "sixtyfps::cbindgen_private::EasingCurve(sixtyfps::cbindgen_private::EasingCurve::Tag::CubicBezier, {}, {}, {}, {})",
             a, b, c, d
         ),
+        Expression::EasingCurve(EasingCurve::Spring(mass, stiffness, damping)) => format!(
+            "sixtyfps::cbindgen_private::EasingCurve(sixtyfps::cbindgen_private::EasingCurve::Spring_Body{{{{ {}, {}, {} }}}})",
+            mass, stiffness, damping
+        ),
+        Expression::EasingCurve(EasingCurve::Steps(steps)) => format!(
+            "sixtyfps::cbindgen_private::EasingCurve(sixtyfps::cbindgen_private::EasingCurve::Steps_Body{{ {} }})",
+            steps
+        ),
+        Expression::EasingCurve(EasingCurve::Custom(name)) => format!(
+            r#"sixtyfps::private_api::custom_easing_curve(u8"{}")"#,
+            escape_string(name)
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -2021,8 +2304,20 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 stops_it.join(", "), angle, stops.len()
             )
         }
+        Expression::RadialGradient{stops} => {
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("sixtyfps::private_api::GradientStop{{ {}, {}, }}", color, position)
+            });
+            format!(
+                "[&] {{ const sixtyfps::private_api::GradientStop stops[] = {{ {} }}; return sixtyfps::Brush(sixtyfps::private_api::RadialGradientBrush(stops, {})); }}()",
+                stops_it.join(", "), stops.len()
+            )
+        }
         Expression::EnumerationValue(value) => {
-            format!("sixtyfps::cbindgen_private::{}::{}", value.enumeration.name, ident(&value.to_string()))
+            let enum_type = Type::Enumeration(value.enumeration.clone()).cpp_type().unwrap();
+            format!("{}::{}", enum_type, ident(&value.to_string()))
         }
         Expression::ReturnStatement(Some(expr)) => format!(
             "throw sixtyfps::private_api::ReturnWrapper<{}>({})",
@@ -2143,6 +2438,20 @@ fn compile_builtin_function_call(
         BuiltinFunction::ImageSize => {
             format!("{}.size()", a.next().unwrap())
         }
+        BuiltinFunction::Translate => {
+            format!(
+                "sixtyfps::private_api::translate({}, {}, {}, {}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::KeySequenceDisplayText => {
+            format!("sixtyfps::private_api::key_sequence_display_text({})", a.next().unwrap())
+        }
         BuiltinFunction::ArrayLength => {
             format!("[](const auto &model){{ (*model).track_row_count_changes(); return (*model).row_count(); }}({})", a.next().unwrap())
         }
@@ -2173,6 +2482,34 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
+        BuiltinFunction::ShowChildWindow => {
+            if let [llr::Expression::NumberLiteral(window_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let window = access_window_field(ctx);
+                let current_sub_component = ctx.current_sub_component.unwrap();
+                let child_window_id =
+                    ident(&current_sub_component.popup_windows[*window_index as usize].root.name);
+                let parent_item = access_item_rc(parent_ref, ctx);
+                format!(
+                    "{}.show_child_window<{}>(self, {}, {{ {} }})",
+                    window, child_window_id, window_index, parent_item,
+                )
+            } else {
+                panic!("internal error: invalid args to ShowChildWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::HideChildWindow => {
+            if let [llr::Expression::NumberLiteral(window_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let window = access_window_field(ctx);
+                let parent_item = access_item_rc(parent_ref, ctx);
+                format!("{}.hide_child_window({}, {{ {} }})", window, window_index, parent_item)
+            } else {
+                panic!("internal error: invalid args to HideChildWindow {:?}", arguments)
+            }
+        }
         BuiltinFunction::RegisterCustomFontByPath => {
             if let [llr::Expression::StringLiteral(path)] = arguments {
                 format!(
@@ -0,0 +1,119 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*! module for the gettext message template (.pot) extraction of the `@tr(...)` strings
+*/
+
+use crate::expression_tree::{BuiltinFunction, Expression};
+use crate::object_tree::{visit_all_expressions, Document};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+#[derive(Default)]
+struct Message {
+    context: String,
+    msgid: String,
+    plural: String,
+    /// `file:line` of all the places where this message is used
+    locations: Vec<String>,
+}
+
+fn collect_message(
+    expr: &Expression,
+    messages: &mut Vec<Message>,
+    index: &mut HashMap<(String, String), usize>,
+) {
+    if let Expression::FunctionCall { function, arguments, source_location } = expr {
+        if let (
+            Expression::BuiltinFunctionReference(BuiltinFunction::Translate, _),
+            [Expression::StringLiteral(msgid), Expression::StringLiteral(context), _, _, _, Expression::StringLiteral(plural)],
+        ) = (&**function, arguments.as_slice())
+        {
+            let message = *index.entry((context.clone(), msgid.clone())).or_insert_with(|| {
+                messages.push(Message {
+                    context: context.clone(),
+                    msgid: msgid.clone(),
+                    ..Default::default()
+                });
+                messages.len() - 1
+            });
+            let message = &mut messages[message];
+            if message.plural.is_empty() {
+                message.plural = plural.clone();
+            }
+            if let Some(location) = source_location {
+                if let Some(sf) = &location.source_file {
+                    let location =
+                        format!("{}:{}", sf.path().display(), sf.line_number(location.span.offset));
+                    if !message.locations.contains(&location) {
+                        message.locations.push(location);
+                    }
+                }
+            }
+        }
+    }
+    expr.visit(|e| collect_message(e, messages, index));
+}
+
+fn escape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Returns the content of a gettext message template file with all the strings marked
+/// with `@tr(...)` in the document.
+pub fn generate(doc: &Document) -> String {
+    let mut messages = Vec::new();
+    let mut index = HashMap::new();
+    let root_component = &doc.root_component;
+    let used_types = root_component.used_types.borrow();
+    for component in used_types
+        .sub_components
+        .iter()
+        .chain(used_types.globals.iter())
+        .chain(std::iter::once(root_component))
+    {
+        visit_all_expressions(component, |expr, _| {
+            collect_message(expr, &mut messages, &mut index)
+        });
+    }
+
+    let mut output = String::new();
+    output.push_str(concat!(
+        "# SOME DESCRIPTIVE TITLE.\n",
+        "#, fuzzy\n",
+        "msgid \"\"\n",
+        "msgstr \"\"\n",
+        "\"Project-Id-Version: PACKAGE VERSION\\n\"\n",
+        "\"MIME-Version: 1.0\\n\"\n",
+        "\"Content-Type: text/plain; charset=UTF-8\\n\"\n",
+        "\"Content-Transfer-Encoding: 8bit\\n\"\n",
+        "\"Plural-Forms: nplurals=INTEGER; plural=EXPRESSION;\\n\"\n",
+    ));
+    for message in messages {
+        output.push('\n');
+        for location in &message.locations {
+            writeln!(output, "#: {}", location).unwrap();
+        }
+        if !message.context.is_empty() {
+            writeln!(output, "msgctxt \"{}\"", escape(&message.context)).unwrap();
+        }
+        writeln!(output, "msgid \"{}\"", escape(&message.msgid)).unwrap();
+        if message.plural.is_empty() {
+            output.push_str("msgstr \"\"\n");
+        } else {
+            writeln!(output, "msgid_plural \"{}\"", escape(&message.plural)).unwrap();
+            output.push_str("msgstr[0] \"\"\nmsgstr[1] \"\"\n");
+        }
+    }
+    output
+}
@@ -11,7 +11,7 @@
 */
 
 use crate::expression_tree::{BuiltinFunction, EasingCurve, OperatorClass};
-use crate::langtype::Type;
+use crate::langtype::{Enumeration, Type};
 use crate::layout::Orientation;
 use crate::llr::{
     self, EvaluationContext as llr_EvaluationContext, Expression, ParentCtx as llr_ParentCtx,
@@ -80,6 +80,10 @@ fn rust_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
             let inner = rust_type(o)?;
             Some(quote!(sixtyfps::re_exports::ModelHandle<#inner>))
         }
+        Type::Enumeration(e) if e.node.is_some() => {
+            let e = ident(&e.name);
+            Some(quote!(#e))
+        }
         Type::Enumeration(e) => {
             let e = ident(&e.name);
             Some(quote!(sixtyfps::re_exports::#e))
@@ -112,6 +116,15 @@ pub fn generate(doc: &Document) -> TokenStream {
         })
         .unzip();
 
+    let (enums_ids, enums): (Vec<_>, Vec<_>) = doc
+        .root_component
+        .used_types
+        .borrow()
+        .enums
+        .iter()
+        .map(|en| (ident(&en.name), generate_enum(en)))
+        .unzip();
+
     let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
 
     let sub_compos = llr
@@ -180,6 +193,7 @@ pub fn generate(doc: &Document) -> TokenStream {
         #[allow(unused_braces)]
         mod #compo_module {
             use sixtyfps::re_exports::*;
+            #(#enums)*
             #(#structs)*
             #(#globals)*
             #(#sub_compos)*
@@ -187,7 +201,7 @@ mod #compo_module {
             #(#resource_symbols)*
             const _THE_SAME_VERSION_MUST_BE_USED_FOR_THE_COMPILER_AND_THE_RUNTIME : sixtyfps::#version_check = sixtyfps::#version_check;
         }
-        pub use #compo_module::{#compo_id #(,#structs_ids)* #(,#globals_ids)* };
+        pub use #compo_module::{#compo_id #(,#enums_ids)* #(,#structs_ids)* #(,#globals_ids)* };
         pub use sixtyfps::{ComponentHandle, Global};
     }
 }
@@ -297,6 +311,24 @@ pub struct #component_id {
     }
 }
 
+fn generate_enum(en: &Enumeration) -> TokenStream {
+    let enum_id = ident(&en.name);
+    let values = en.values.iter().map(|v| ident(v)).collect::<Vec<_>>();
+    let default_value = &values[en.default_value];
+
+    quote! {
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub enum #enum_id {
+            #(#values),*
+        }
+        impl Default for #enum_id {
+            fn default() -> Self {
+                Self::#default_value
+            }
+        }
+    }
+}
+
 fn handle_property_init(
     prop: &llr::PropertyReference,
     binding_expression: &llr::BindingExpression,
@@ -455,6 +487,38 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
     quote!(#(#property_and_callback_accessors)*)
 }
 
+/// Generate a method for each function declared with the `function` keyword
+fn generate_functions(functions: &[llr::Function], ctx: &EvaluationContext) -> Vec<TokenStream> {
+    functions
+        .iter()
+        .map(|function| {
+            let mut ctx2 = ctx.clone();
+            ctx2.argument_types = &function.args;
+            let tokens_for_expression = compile_expression(&function.code, &ctx2);
+            let function_name = function_ident(&function.name);
+            let args_types = function.args.iter().map(|a| rust_type(a).unwrap());
+            let (return_type, body) = if function.ret_ty == Type::Void {
+                (quote!(()), quote!(#tokens_for_expression;))
+            } else {
+                (rust_type(&function.ret_ty).unwrap(), quote!((#tokens_for_expression) as _))
+            };
+            quote!(
+                #[allow(dead_code, unreachable_code)]
+                fn #function_name(self: ::core::pin::Pin<&Self>, args: (#(#args_types,)*)) -> #return_type {
+                    #![allow(unused)]
+                    use sixtyfps::re_exports::*;
+                    let _self = self;
+                    #body
+                }
+            )
+        })
+        .collect()
+}
+
+fn function_ident(name: &str) -> proc_macro2::Ident {
+    format_ident!("fn_{}", name.replace('-', "_"))
+}
+
 /// Generate the rust code for the given component.
 fn generate_sub_component(
     component: &llr::SubComponent,
@@ -601,6 +665,10 @@ fn generate_sub_component(
 
         let sub_compo_field = access_component_field_offset(&format_ident!("Self"), &field_name);
 
+        // The sub component is shown in the same window, which is not the root's for child windows
+        init.push(quote!(#sub_compo_field.apply_pin(_self).window.set(
+            _self.window.get().unwrap().window_handle().clone().into()
+        );));
         init.push(quote!(#sub_component_id::init(
             VRcMapped::map(self_rc.clone(), |x| #sub_compo_field.apply_pin(x)),
             &#root_ref_tokens,
@@ -653,9 +721,42 @@ fn generate_sub_component(
 
     init.extend(component.init_code.iter().map(|e| compile_expression(e, &ctx)));
 
+    let mut change_tracker_names = vec![];
+    for (idx, (prop, expression)) in component.change_callbacks.iter().enumerate() {
+        let change_tracker = format_ident!("change_tracker{}", idx);
+        let rust_property = access_member(prop, &ctx);
+        let tokens_for_expression = compile_expression(expression, &ctx);
+        init.push(quote! {
+            _self.#change_tracker.init(
+                {
+                    let self_weak = sixtyfps::re_exports::VRcMapped::downgrade(&self_rc);
+                    move || {
+                        self_weak.upgrade().map(|self_rc| {
+                            let _self = self_rc.as_pin_ref();
+                            #rust_property.get()
+                        })
+                    }
+                },
+                {
+                    let self_weak = sixtyfps::re_exports::VRcMapped::downgrade(&self_rc);
+                    #[allow(unreachable_code, unused)]
+                    move || {
+                        if let Some(self_rc) = self_weak.upgrade() {
+                            let _self = self_rc.as_pin_ref();
+                            #tokens_for_expression;
+                        }
+                    }
+                },
+            );
+        });
+        change_tracker_names.push(change_tracker);
+    }
+
     let layout_info_h = compile_expression(&component.layout_info_h, &ctx);
     let layout_info_v = compile_expression(&component.layout_info_v, &ctx);
 
+    let declared_functions = generate_functions(&component.functions, &ctx);
+
     // FIXME! this is only public because of the ComponentHandle::Inner. we should find another way
     let visibility =
         core::ptr::eq(&root.item_tree.root as *const _, component as *const _).then(|| quote!(pub));
@@ -672,6 +773,7 @@ struct #inner_component_id {
             #(#declared_property_vars : sixtyfps::re_exports::Property<#declared_property_types>,)*
             #(#declared_callbacks : sixtyfps::re_exports::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_names : sixtyfps::re_exports::Repeater<#repeated_element_components>,)*
+            #(#change_tracker_names : sixtyfps::re_exports::ChangeTracker,)*
             self_weak : sixtyfps::re_exports::OnceCell<sixtyfps::re_exports::VWeakMapped<sixtyfps::re_exports::ComponentVTable, #inner_component_id>>,
             #(parent : #parent_component_type,)*
             // FIXME: Do we really need a window all the time?
@@ -690,7 +792,6 @@ pub fn init(self_rc: sixtyfps::re_exports::VRcMapped<sixtyfps::re_exports::Compo
                 let _self = self_rc.as_pin_ref();
                 _self.self_weak.set(VRcMapped::downgrade(&self_rc));
                 _self.root.set(VRc::downgrade(root));
-                _self.window.set(root.window.get().unwrap().window_handle().clone().into());
                 _self.tree_index.set(tree_index);
                 _self.tree_index_of_first_child.set(tree_index_of_first_child);
                 #(#init)*
@@ -720,6 +821,8 @@ fn layout_info(self: ::core::pin::Pin<&Self>, orientation: sixtyfps::re_exports:
                     sixtyfps::re_exports::Orientation::Vertical => #layout_info_v,
                 }
             }
+
+            #(#declared_functions)*
         }
 
         #(#extra_components)*
@@ -781,6 +884,8 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::PublicComponent) -
 
     let inner_component_id = format_ident!("Inner{}", ident(&global.name));
 
+    let declared_functions = generate_functions(&global.functions, &ctx);
+
     let public_interface = global.exported.then(|| {
         let property_and_callback_accessors = public_api(&global.public_properties, quote!(self.0.as_ref()), &ctx);
         let public_component_id = ident(&global.name);
@@ -822,6 +927,8 @@ fn new() -> ::core::pin::Pin<sixtyfps::re_exports::Rc<Self>> {
                 #(#init)*
                 self_rc
             }
+
+            #(#declared_functions)*
         }
 
         #public_interface
@@ -845,14 +952,16 @@ fn generate_item_tree(
     } else {
         quote!(&self_rc)
     };
-    let maybe_create_window = parent_ctx.is_none().then(|| {
+    let init_window = if parent_ctx.is_none() || sub_tree.is_child_window {
         quote!(
             _self.window.set(sixtyfps::create_window().into());
             _self.window.get().unwrap().window_handle().set_component(&VRc::into_dyn(self_rc.clone()));
         )
-    });
+    } else {
+        quote!(_self.window.set(parent.upgrade().unwrap().window.get().unwrap().window_handle().clone().into());)
+    };
 
-    let parent_item_index = parent_ctx.and_then(|parent| {
+    let parent_item_index = parent_ctx.as_ref().and_then(|parent| {
         parent
             .repeater_index
             .map(|idx| parent.ctx.current_sub_component.unwrap().repeated[idx].index_in_tree)
@@ -902,6 +1011,42 @@ fn generate_item_tree(
 
     let item_tree_array_len = item_tree_array.len();
 
+    let ctx = EvaluationContext {
+        public_component: root,
+        current_sub_component: Some(&sub_tree.root),
+        current_global: None,
+        generator_state: quote!(_self),
+        parent: parent_ctx,
+        argument_types: &[],
+    };
+    let mut accessible_role_cases = vec![];
+    let mut accessible_string_cases = vec![];
+    for ((index, what), prop) in sub_tree.accessible_props() {
+        let prop = access_member(&prop, &ctx);
+        match what.as_str() {
+            "accessible-role" => accessible_role_cases.push(quote!(#index => #prop.get(),)),
+            "accessible-checked" => accessible_string_cases.push(quote!(
+                (#index, AccessibleStringProperty::Checked) => {
+                    *result = (if #prop.get() { "true" } else { "false" }).into()
+                }
+            )),
+            _ => {
+                let what = ident(match what.as_str() {
+                    "accessible-label" => "Label",
+                    "accessible-description" => "Description",
+                    "accessible-value" => "Value",
+                    _ => unreachable!("unknown accessible property {}", what),
+                });
+                accessible_string_cases.push(
+                    quote!((#index, AccessibleStringProperty::#what) => *result = #prop.get(),),
+                )
+            }
+        }
+    }
+    let element_ids_cases = sub_tree.element_ids().into_iter().map(|(index, ids)| {
+        quote!(#index => *result = SharedVector::from_slice(&[#(SharedString::from(#ids)),*]),)
+    });
+
     quote!(
         #sub_comp
 
@@ -915,8 +1060,8 @@ pub fn new(#(parent: #parent_component_type)*)
                 #(_self.parent = parent.clone() as #parent_component_type;)*
                 let self_rc = VRc::new(_self);
                 let _self = self_rc.as_pin_ref();
-                #maybe_create_window;
-                sixtyfps::re_exports::init_component_items(_self, Self::item_tree(), #root_token.window.get().unwrap().window_handle());
+                #init_window
+                sixtyfps::re_exports::init_component_items(_self, Self::item_tree(), _self.window.get().unwrap().window_handle());
                 Self::init(sixtyfps::re_exports::VRc::map(self_rc.clone(), |x| x), #root_token, 0, 1);
                 self_rc
             }
@@ -981,6 +1126,44 @@ fn parent_item(self: ::core::pin::Pin<&Self>, index: usize, result: &mut sixtyfp
             fn layout_info(self: ::core::pin::Pin<&Self>, orientation: sixtyfps::re_exports::Orientation) -> sixtyfps::re_exports::LayoutInfo {
                 self.layout_info(orientation)
             }
+
+            fn accessible_role(self: ::core::pin::Pin<&Self>, index: usize) -> sixtyfps::re_exports::AccessibleRole {
+                #![allow(unused)]
+                use sixtyfps::re_exports::*;
+                let _self = self;
+                match index {
+                    #(#accessible_role_cases)*
+                    _ => AccessibleRole::none,
+                }
+            }
+
+            fn accessible_string_property(
+                self: ::core::pin::Pin<&Self>,
+                index: usize,
+                what: sixtyfps::re_exports::AccessibleStringProperty,
+                result: &mut sixtyfps::re_exports::SharedString,
+            ) {
+                #![allow(unused)]
+                use sixtyfps::re_exports::*;
+                let _self = self;
+                match (index, what) {
+                    #(#accessible_string_cases)*
+                    _ => {}
+                }
+            }
+
+            fn element_ids(
+                self: ::core::pin::Pin<&Self>,
+                index: usize,
+                result: &mut sixtyfps::re_exports::SharedVector<sixtyfps::re_exports::SharedString>,
+            ) {
+                #![allow(unused)]
+                use sixtyfps::re_exports::*;
+                match index {
+                    #(#element_ids_cases)*
+                    _ => {}
+                }
+            }
         }
 
 
@@ -1180,7 +1363,10 @@ fn in_native_item(
                     item_index,
                     prop_name,
                 } => in_native_item(ctx, sub_component_path, *item_index, prop_name, path),
-                llr::PropertyReference::InParent { .. } | llr::PropertyReference::Global { .. } => {
+                llr::PropertyReference::InParent { .. }
+                | llr::PropertyReference::Global { .. }
+                | llr::PropertyReference::Function { .. }
+                | llr::PropertyReference::GlobalFunction { .. } => {
                     unreachable!()
                 }
             }
@@ -1195,6 +1381,72 @@ fn in_native_item(
             );
             quote!(#global_name::FIELD_OFFSETS.#property_name.apply_pin(#root_access.globals.#global_id.as_ref()))
         }
+        llr::PropertyReference::Function { .. } | llr::PropertyReference::GlobalFunction { .. } => {
+            unreachable!("functions can only be called")
+        }
+    }
+}
+
+/// Given a reference to a function, return the tokens to access the pinned component
+/// containing it, and the name of the method
+fn access_function(
+    reference: &llr::PropertyReference,
+    ctx: &EvaluationContext,
+) -> (TokenStream, proc_macro2::Ident) {
+    fn in_sub_component(
+        mut sub_component: &llr::SubComponent,
+        sub_component_path: &[usize],
+        function_index: usize,
+        mut path: TokenStream,
+    ) -> (TokenStream, proc_macro2::Ident) {
+        for i in sub_component_path {
+            let component_id = inner_component_id(sub_component);
+            let sub_component_name = ident(&sub_component.sub_components[*i].name);
+            path = quote!(#component_id::FIELD_OFFSETS.#sub_component_name.apply_pin(#path));
+            sub_component = &sub_component.sub_components[*i].ty;
+        }
+        (path, function_ident(&sub_component.functions[function_index].name))
+    }
+
+    match reference {
+        llr::PropertyReference::Function { sub_component_path, function_index } => {
+            if let Some(sub_component) = ctx.current_sub_component {
+                in_sub_component(sub_component, sub_component_path, *function_index, quote!(_self))
+            } else if let Some(current_global) = ctx.current_global {
+                (quote!(_self), function_ident(&current_global.functions[*function_index].name))
+            } else {
+                unreachable!()
+            }
+        }
+        llr::PropertyReference::InParent { level, parent_reference } => {
+            let mut ctx = ctx;
+            let mut path = quote!(_self);
+            for _ in 0..level.get() {
+                path = quote!(#path.parent.upgrade().unwrap().as_pin_ref());
+                ctx = ctx.parent.as_ref().unwrap().ctx;
+            }
+            match &**parent_reference {
+                llr::PropertyReference::Function { sub_component_path, function_index } => {
+                    in_sub_component(
+                        ctx.current_sub_component.unwrap(),
+                        sub_component_path,
+                        *function_index,
+                        path,
+                    )
+                }
+                _ => unreachable!(),
+            }
+        }
+        llr::PropertyReference::GlobalFunction { global_index, function_index } => {
+            let root_access = &ctx.generator_state;
+            let global = &ctx.public_component.globals[*global_index];
+            let global_id = format_ident!("global_{}", ident(&global.name));
+            (
+                quote!(#root_access.globals.#global_id.as_ref()),
+                function_ident(&global.functions[*function_index].name),
+            )
+        }
+        _ => unreachable!("not a function reference"),
     }
 }
 
@@ -1358,6 +1610,11 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             let a = arguments.iter().map(|a| compile_expression(a, ctx));
             quote! { #f.call(&(#(#a.clone() as _,)*).into())}
         }
+        Expression::FunctionCall { function, arguments } => {
+            let a = arguments.iter().map(|a| compile_expression(a, ctx));
+            let (component, function_name) = access_function(function, ctx);
+            quote! { #component.#function_name((#(#a.clone() as _,)*)) }
+        }
         Expression::ExtraBuiltinFunctionCall { function, arguments, return_ty: _ } => {
             let f = ident(&function);
             let a = arguments.iter().map(|a| {
@@ -1544,7 +1801,18 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         }
         Expression::Struct { ty, values } => {
             if let Type::Struct { fields, name, .. } = ty {
-                let elem = fields.keys().map(|k| values.get(k).map(|e| compile_expression(e, ctx)));
+                let elem = fields.keys().map(|k| {
+                    values.get(k).map(|e| match e {
+                        // The keyframes of the animations are stored in a SharedVector
+                        Expression::Array { values, as_model: false, .. }
+                            if name.as_deref() == Some("PropertyAnimation") =>
+                        {
+                            let val = values.iter().map(|e| compile_expression(e, ctx));
+                            quote!(sixtyfps::re_exports::SharedVector::<_>::from_slice(&[#(#val),*]))
+                        }
+                        _ => compile_expression(e, ctx),
+                    })
+                });
                 if let Some(name) = name {
                     let name_tokens: TokenStream = struct_name_to_tokens(name.as_str());
                     let keys = fields.keys().map(|k| ident(k));
@@ -1587,6 +1855,15 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => {
             quote!(sixtyfps::re_exports::EasingCurve::CubicBezier([#a, #b, #c, #d]))
         }
+        Expression::EasingCurve(EasingCurve::Spring(mass, stiffness, damping)) => {
+            quote!(sixtyfps::re_exports::EasingCurve::Spring([#mass, #stiffness, #damping]))
+        }
+        Expression::EasingCurve(EasingCurve::Steps(steps)) => {
+            quote!(sixtyfps::re_exports::EasingCurve::Steps(#steps))
+        }
+        Expression::EasingCurve(EasingCurve::Custom(name)) => {
+            quote!(sixtyfps::re_exports::EasingCurve::custom(#name))
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -1598,10 +1875,24 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 sixtyfps::re_exports::LinearGradientBrush::new(#angle as _, [#(#stops),*].iter().cloned())
             ))
         }
+        Expression::RadialGradient { stops } => {
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sixtyfps::re_exports::GradientStop{ color: #color, position: #position as _ })
+            });
+            quote!(sixtyfps::Brush::RadialGradient(
+                sixtyfps::re_exports::RadialGradientBrush::new_circle([#(#stops),*].iter().cloned())
+            ))
+        }
         Expression::EnumerationValue(value) => {
             let base_ident = ident(&value.enumeration.name);
             let value_ident = ident(&value.to_string());
-            quote!(sixtyfps::re_exports::#base_ident::#value_ident)
+            if value.enumeration.node.is_some() {
+                quote!(#base_ident::#value_ident)
+            } else {
+                quote!(sixtyfps::re_exports::#base_ident::#value_ident)
+            }
         }
         Expression::ReturnStatement(expr) => {
             let return_expr = expr.as_ref().map(|expr| compile_expression(expr, ctx));
@@ -1692,6 +1983,45 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
+        BuiltinFunction::ShowChildWindow => {
+            if let [Expression::NumberLiteral(window_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let current_sub_component = ctx.current_sub_component.unwrap();
+                let child_window_id = inner_component_id(
+                    &current_sub_component.popup_windows[*window_index as usize].root,
+                );
+                let window_index = *window_index as usize;
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let window_tokens = access_window_field(ctx);
+                quote!(
+                    if !#window_tokens.clone().is_child_window_shown(#window_index, #parent_item) {
+                        let child_window = #child_window_id::new(_self.self_weak.get().unwrap().clone());
+                        #window_tokens.clone().show_child_window(
+                            #window_index,
+                            #parent_item,
+                            VRc::as_pin_ref(&child_window).window.get().unwrap().window_handle(),
+                        );
+                    }
+                )
+            } else {
+                panic!("internal error: invalid args to ShowChildWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::HideChildWindow => {
+            if let [Expression::NumberLiteral(window_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let window_index = *window_index as usize;
+                let parent_item = access_item_rc(parent_ref, ctx);
+                let window_tokens = access_window_field(ctx);
+                quote!(
+                    #window_tokens.clone().hide_child_window(#window_index, #parent_item);
+                )
+            } else {
+                panic!("internal error: invalid args to HideChildWindow {:?}", arguments)
+            }
+        }
         BuiltinFunction::ImplicitLayoutInfo(orient) => {
             if let [Expression::PropertyReference(pr)] = arguments {
                 let item = access_member(pr, ctx);
@@ -1719,6 +2049,28 @@ fn compile_builtin_function_call(
                 panic!("internal error: invalid args to RegisterCustomFontByMemory {:?}", arguments)
             }
         }
+        BuiltinFunction::Translate => {
+            let (original, context, domain, arguments, n, plural) = (
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+            );
+            quote!(sixtyfps::re_exports::translate(
+                &#original,
+                &#context,
+                &#domain,
+                #arguments.as_slice(),
+                #n as _,
+                &#plural,
+            ))
+        }
+        BuiltinFunction::KeySequenceDisplayText => {
+            let keys = a.next().unwrap();
+            quote!(sixtyfps::re_exports::key_sequence_display_text(&#keys))
+        }
         BuiltinFunction::GetWindowScaleFactor => {
             let window_tokens = access_window_field(ctx);
             quote!(#window_tokens.scale_factor())
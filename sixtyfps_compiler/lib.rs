@@ -70,6 +70,9 @@ pub struct CompilerConfiguration {
     /// This may help optimization to optimize the runtime resources usages,
     /// but at the cost of much more generated code and binary size.
     pub inline_all_elements: bool,
+
+    /// The domain used to look up the translations of the `@tr(...)` strings.
+    pub translation_domain: Option<String>,
 }
 
 impl CompilerConfiguration {
@@ -105,6 +108,7 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             style: Default::default(),
             open_import_fallback: Default::default(),
             inline_all_elements,
+            translation_domain: Default::default(),
         }
     }
 }
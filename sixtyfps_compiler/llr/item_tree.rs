@@ -29,6 +29,7 @@ pub struct BindingExpression {
 pub struct GlobalComponent {
     pub name: String,
     pub properties: Vec<Property>,
+    pub functions: Vec<Function>,
     /// One entry per property
     pub init_values: Vec<Option<BindingExpression>>,
     pub const_properties: Vec<bool>,
@@ -53,6 +54,10 @@ pub enum PropertyReference {
     InParent { level: NonZeroUsize, parent_reference: Box<PropertyReference> },
     /// The property within a GlobalComponent
     Global { global_index: usize, property_index: usize },
+    /// A function declared in this SubComponent (or in one of its sub components)
+    Function { sub_component_path: Vec<usize>, function_index: usize },
+    /// A function declared within a GlobalComponent
+    GlobalFunction { global_index: usize, function_index: usize },
 }
 
 #[derive(Debug)]
@@ -61,6 +66,15 @@ pub struct Property {
     pub ty: Type,
 }
 
+/// A function declared with the `function` keyword
+#[derive(Debug)]
+pub struct Function {
+    pub name: String,
+    pub ret_ty: Type,
+    pub args: Vec<Type>,
+    pub code: Expression,
+}
+
 #[derive(Debug, Clone)]
 /// The property references might be either in the parent context, or in the
 /// repeated's component context
@@ -173,6 +187,7 @@ fn visit_in_array_recursive(
 pub struct SubComponent {
     pub name: String,
     pub properties: Vec<Property>,
+    pub functions: Vec<Function>,
     pub items: Vec<Item>,
     pub repeated: Vec<RepeatedElement>,
     pub popup_windows: Vec<ItemTree>,
@@ -186,6 +201,14 @@ pub struct SubComponent {
     pub const_properties: Vec<PropertyReference>,
     // Code that is run in the sub component constructor, after property initializations
     pub init_code: Vec<Expression>,
+    /// The `changed` handlers: the code is run after the value of the property changed
+    pub change_callbacks: Vec<(PropertyReference, Expression)>,
+    /// The accessibility properties, indexed by the local index of the item in the tree
+    /// and the name of the property (e.g. `accessible-role`).
+    pub accessible_props: BTreeMap<(usize, String), PropertyReference>,
+    /// The ids of the elements, as written in the .60 source, indexed by the local index of the
+    /// item in the tree.
+    pub element_ids: BTreeMap<usize, Vec<String>>,
 
     pub layout_info_h: Expression,
     pub layout_info_v: Expression,
@@ -231,6 +254,125 @@ pub struct ItemTree {
     /// the parent ItemTree.
     /// The String is the type of the parent ItemTree
     pub parent_context: Option<String>,
+    /// This tree comes from a `Window` element within another component, and is shown in its
+    /// own top-level window, created when the tree is instantiated.
+    pub is_child_window: bool,
+}
+
+impl ItemTree {
+    /// The accessibility properties of the items in this tree, including the ones from the
+    /// sub components, indexed by the index of the item in the item tree array.
+    /// The property references are relative to the root sub component.
+    pub fn accessible_props(&self) -> BTreeMap<(usize, String), PropertyReference> {
+        let mut result = BTreeMap::new();
+        self.visit_item_levels(|tree_index, levels| {
+            // The bindings set by the outer components take precedence
+            for (path, local_index) in levels.iter().rev() {
+                let sub_component = self.sub_component(path);
+                for ((_, name), prop) in sub_component
+                    .accessible_props
+                    .range((*local_index, String::new())..)
+                    .take_while(|((index, _), _)| index == local_index)
+                {
+                    result
+                        .entry((tree_index, name.clone()))
+                        .or_insert_with(|| prefix_sub_component_path(prop, path));
+                }
+            }
+        });
+        result
+    }
+
+    /// The ids of the items in this tree, including the ones from the sub components, indexed by
+    /// the index of the item in the item tree array. The ids set in the outer components come first.
+    pub fn element_ids(&self) -> BTreeMap<usize, Vec<String>> {
+        let mut result = BTreeMap::new();
+        self.visit_item_levels(|tree_index, levels| {
+            let ids = levels
+                .iter()
+                .rev()
+                .filter_map(|(path, local_index)| {
+                    self.sub_component(path).element_ids.get(local_index)
+                })
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>();
+            if !ids.is_empty() {
+                result.insert(tree_index, ids);
+            }
+        });
+        result
+    }
+
+    /// Calls the visitor for each non-repeated item of the tree, with the index of the item in
+    /// the item tree array, and the sub component path and local index for each level.
+    /// An item which is the root of a sub component is also the item of the element that
+    /// instantiates it in the parent component, so there is one level for each of these
+    /// components, starting from the innermost one.
+    fn visit_item_levels(&self, mut visitor: impl FnMut(usize, &[(Vec<usize>, usize)])) {
+        let mut tree_index = 0;
+        self.tree.visit_in_array(&mut |node, _, _| {
+            if !node.repeated {
+                let mut levels = vec![];
+                let mut path = node.sub_component_path.clone();
+                let mut local_index =
+                    self.sub_component(&path).items[node.item_index].index_in_tree;
+                loop {
+                    levels.push((path.clone(), local_index));
+                    if local_index != 0 {
+                        break;
+                    }
+                    let instance = match path.pop() {
+                        Some(instance) => instance,
+                        None => break,
+                    };
+                    local_index = self.sub_component(&path).sub_components[instance].index_in_tree;
+                }
+                visitor(tree_index, &levels);
+            }
+            tree_index += 1;
+        });
+    }
+
+    fn sub_component(&self, sub_component_path: &[usize]) -> &SubComponent {
+        let mut sub_component = &self.root;
+        for i in sub_component_path {
+            sub_component = &sub_component.sub_components[*i].ty;
+        }
+        sub_component
+    }
+}
+
+/// Make a property reference relative to a sub component relative to the component
+/// which contains it at the given path.
+fn prefix_sub_component_path(prop: &PropertyReference, path: &[usize]) -> PropertyReference {
+    let prefixed = |sub_component_path: &Vec<usize>| {
+        path.iter().chain(sub_component_path.iter()).copied().collect::<Vec<_>>()
+    };
+    match prop {
+        PropertyReference::Local { sub_component_path, property_index } => {
+            PropertyReference::Local {
+                sub_component_path: prefixed(sub_component_path),
+                property_index: *property_index,
+            }
+        }
+        PropertyReference::InNativeItem { sub_component_path, item_index, prop_name } => {
+            PropertyReference::InNativeItem {
+                sub_component_path: prefixed(sub_component_path),
+                item_index: *item_index,
+                prop_name: prop_name.clone(),
+            }
+        }
+        PropertyReference::Function { sub_component_path, function_index } => {
+            PropertyReference::Function {
+                sub_component_path: prefixed(sub_component_path),
+                function_index: *function_index,
+            }
+        }
+        PropertyReference::InParent { .. }
+        | PropertyReference::Global { .. }
+        | PropertyReference::GlobalFunction { .. } => prop.clone(),
+    }
 }
 
 #[derive(Debug)]
@@ -15,7 +15,7 @@
 use crate::layout::Orientation;
 use crate::llr::Expression as llr_Expression;
 use crate::namedreference::NamedReference;
-use crate::object_tree::{Element, ElementRc, PropertyAnimation};
+use crate::object_tree::{Component, Element, ElementRc, PopupWindow, PropertyAnimation};
 
 pub struct ExpressionContext<'a> {
     pub component: &'a Rc<crate::object_tree::Component>,
@@ -74,6 +74,7 @@ pub fn lower_expression(
         tree_Expression::PropertyReference(nr) => {
             llr_Expression::PropertyReference(ctx.map_property_reference(nr))
         }
+        tree_Expression::FunctionReference(_) => panic!(),
         tree_Expression::BuiltinFunctionReference(_, _) => panic!(),
         tree_Expression::MemberFunction { .. } => panic!(),
         tree_Expression::BuiltinMacroReference(_, _) => panic!(),
@@ -117,6 +118,22 @@ pub fn lower_expression(
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ShowPopupWindow, _) => {
                 lower_show_popup(arguments, ctx)
             }
+            tree_Expression::BuiltinFunctionReference(
+                f @ (BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow),
+                _,
+            ) => lower_child_window_call(*f, arguments, ctx),
+            tree_Expression::BuiltinFunctionReference(BuiltinFunction::Translate, _) => {
+                let mut arguments =
+                    arguments.iter().map(|e| lower_expression(e, ctx)).collect::<Vec<_>>();
+                // The arguments of the format string are passed as a slice
+                if let Some(llr_Expression::Array { as_model, .. }) = arguments.get_mut(3) {
+                    *as_model = false;
+                }
+                llr_Expression::BuiltinFunctionCall {
+                    function: BuiltinFunction::Translate,
+                    arguments,
+                }
+            }
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let arguments = arguments.iter().map(|e| lower_expression(e, ctx)).collect::<_>();
                 llr_Expression::BuiltinFunctionCall { function: *f, arguments }
@@ -125,6 +142,10 @@ pub fn lower_expression(
                 let arguments = arguments.iter().map(|e| lower_expression(e, ctx)).collect::<_>();
                 llr_Expression::CallBackCall { callback: ctx.map_property_reference(nr), arguments }
             }
+            tree_Expression::FunctionReference(nr) => {
+                let arguments = arguments.iter().map(|e| lower_expression(e, ctx)).collect::<_>();
+                llr_Expression::FunctionCall { function: ctx.map_property_reference(nr), arguments }
+            }
             _ => panic!("not calling a function"),
         },
         tree_Expression::SelfAssignment { lhs, rhs, op } => lower_assignment(lhs, rhs, *op, ctx),
@@ -167,6 +188,12 @@ pub fn lower_expression(
                 .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
                 .collect::<_>(),
         },
+        tree_Expression::RadialGradient { stops } => llr_Expression::RadialGradient {
+            stops: stops
+                .iter()
+                .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                .collect::<_>(),
+        },
         tree_Expression::EnumerationValue(e) => llr_Expression::EnumerationValue(e.clone()),
         tree_Expression::ReturnStatement(x) => {
             llr_Expression::ReturnStatement(x.as_ref().map(|e| lower_expression(e, ctx).into()))
@@ -320,22 +347,9 @@ fn repeater_special_property(
 
 fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
     if let [tree_Expression::ElementReference(e)] = args {
-        let popup_window = e.upgrade().unwrap();
-        let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-        let parent_component = pop_comp
-            .parent_element
-            .upgrade()
-            .unwrap()
-            .borrow()
-            .enclosing_component
-            .upgrade()
-            .unwrap();
+        let parent_component = popup_parent_component(&e.upgrade().unwrap());
         let popup_list = parent_component.popup_windows.borrow();
-        let (popup_index, popup) = popup_list
-            .iter()
-            .enumerate()
-            .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
-            .unwrap();
+        let (popup_index, popup) = find_popup(&popup_list, &e.upgrade().unwrap());
         let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
         let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
         let item_ref = lower_expression(
@@ -351,15 +365,52 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
     }
 }
 
+fn lower_child_window_call(
+    function: BuiltinFunction,
+    args: &[tree_Expression],
+    ctx: &ExpressionContext,
+) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        let parent_component = popup_parent_component(&e.upgrade().unwrap());
+        let popup_list = parent_component.popup_windows.borrow();
+        let (window_index, window) = find_popup(&popup_list, &e.upgrade().unwrap());
+        let item_ref = lower_expression(
+            &tree_Expression::ElementReference(Rc::downgrade(&window.parent_element)),
+            ctx,
+        );
+        llr_Expression::BuiltinFunctionCall {
+            function,
+            arguments: vec![llr_Expression::NumberLiteral(window_index as _), item_ref],
+        }
+    } else {
+        panic!("invalid arguments to {:?}", function);
+    }
+}
+
+/// Returns the component that contains the PopupWindow or child Window whose root is `root`
+fn popup_parent_component(root: &ElementRc) -> Rc<Component> {
+    let pop_comp = root.borrow().enclosing_component.upgrade().unwrap();
+    let parent_element = pop_comp.parent_element.upgrade().unwrap();
+    let parent_component = parent_element.borrow().enclosing_component.upgrade().unwrap();
+    parent_component
+}
+
+/// Returns the index in `popup_list` of the PopupWindow or child Window whose root is `root`
+fn find_popup<'a>(popup_list: &'a [PopupWindow], root: &ElementRc) -> (usize, &'a PopupWindow) {
+    let pop_comp = root.borrow().enclosing_component.upgrade().unwrap();
+    popup_list.iter().enumerate().find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp)).unwrap()
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
             values: animation_fields()
                 .map(|(k, ty)| {
-                    let e = a.borrow().bindings.get(&k).map_or_else(
-                        || llr_Expression::default_value_for_type(&ty).unwrap(),
-                        |v| lower_expression(&v.borrow().expression, ctx),
-                    );
+                    let e = match a.borrow().bindings.get(&k) {
+                        Some(v) if k == "keyframes" => lower_keyframes(&v.borrow().expression, ctx),
+                        Some(v) => lower_expression(&v.borrow().expression, ctx),
+                        None => default_animation_field(&k, &ty),
+                    };
                     (k, e)
                 })
                 .collect::<_>(),
@@ -367,15 +418,66 @@ fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Ex
         }
     }
 
+    /// The keyframes are stored in a SharedVector of KeyFrame
+    fn lower_keyframes(e: &tree_Expression, ctx: &ExpressionContext<'_>) -> llr_Expression {
+        let values = match e {
+            tree_Expression::Array { values, .. } => values
+                .iter()
+                .map(|keyframe| match keyframe {
+                    tree_Expression::Struct { values, .. } => llr_Expression::Struct {
+                        values: values
+                            .iter()
+                            .map(|(k, v)| (k.clone(), lower_expression(v, ctx)))
+                            .collect(),
+                        ty: keyframe_ty(),
+                    },
+                    _ => panic!("invalid keyframe expression"),
+                })
+                .collect(),
+            _ => panic!("invalid keyframes expression"),
+        };
+        llr_Expression::Array { element_ty: keyframe_ty(), values, as_model: false }
+    }
+
+    fn default_animation_field(name: &str, ty: &Type) -> llr_Expression {
+        match name {
+            "iteration-count" => llr_Expression::NumberLiteral(1.),
+            "keyframes" => {
+                llr_Expression::Array { element_ty: keyframe_ty(), values: vec![], as_model: false }
+            }
+            _ => llr_Expression::default_value_for_type(ty).unwrap(),
+        }
+    }
+
     fn animation_fields() -> impl Iterator<Item = (String, Type)> {
         IntoIterator::into_iter([
             ("duration".to_string(), Type::Int32),
             ("loop-count".to_string(), Type::Int32),
             ("easing".to_string(), Type::Easing),
             ("delay".to_string(), Type::Int32),
+            ("iteration-count".to_string(), Type::Float32),
+            (
+                "direction".to_string(),
+                Type::Enumeration(
+                    crate::typeregister::ANIMATION_DIRECTION_ENUM.with(|e| e.clone()),
+                ),
+            ),
+            ("keyframes".to_string(), Type::Array(Box::new(keyframe_ty()))),
         ])
     }
 
+    fn keyframe_ty() -> Type {
+        Type::Struct {
+            fields: IntoIterator::into_iter([
+                ("position".to_string(), Type::Float32),
+                ("value".to_string(), Type::Float32),
+            ])
+            .collect(),
+            name: Some("KeyFrame".into()),
+            node: None,
+        }
+    }
+
     fn animation_ty() -> Type {
         Type::Struct {
             fields: animation_fields().collect(),
@@ -392,7 +494,15 @@ fn animation_ty() -> Type {
                 value: Box::new(lower_expression(state_ref, ctx)),
             };
             let animation_ty = animation_ty();
-            let mut get_anim = llr_Expression::default_value_for_type(&animation_ty).unwrap();
+            let mut get_anim = llr_Expression::Struct {
+                values: animation_fields()
+                    .map(|(k, ty)| {
+                        let e = default_animation_field(&k, &ty);
+                        (k, e)
+                    })
+                    .collect(),
+                ty: animation_ty.clone(),
+            };
             for tr in animations.iter().rev() {
                 let condition = lower_expression(
                     &tr.condition(tree_Expression::ReadLocalVariable {
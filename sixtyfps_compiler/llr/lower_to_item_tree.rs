@@ -7,7 +7,7 @@
 use crate::langtype::Type;
 use crate::llr::item_tree::*;
 use crate::namedreference::NamedReference;
-use crate::object_tree::{Component, ElementRc};
+use crate::object_tree::{Component, ElementRc, PopupWindow};
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -32,6 +32,7 @@ pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
         tree: make_tree(&state, &component.root_element, &sc, &[]),
         root: Rc::try_unwrap(sc.sub_component).unwrap(),
         parent_context: None,
+        is_child_window: false,
     };
     PublicComponent {
         item_tree,
@@ -142,11 +143,12 @@ fn property_reference_within_sub_component(
 ) -> PropertyReference {
     match &mut prop_ref {
         PropertyReference::Local { sub_component_path, .. }
-        | PropertyReference::InNativeItem { sub_component_path, .. } => {
+        | PropertyReference::InNativeItem { sub_component_path, .. }
+        | PropertyReference::Function { sub_component_path, .. } => {
             sub_component_path.insert(0, sub_component);
         }
         PropertyReference::InParent { .. } => panic!("the sub-component had no parents"),
-        PropertyReference::Global { .. } => (),
+        PropertyReference::Global { .. } | PropertyReference::GlobalFunction { .. } => (),
     }
     prop_ref
 }
@@ -177,6 +179,7 @@ fn lower_sub_component(
     let mut sub_component = SubComponent {
         name: component_id(component),
         properties: Default::default(),
+        functions: Default::default(),
         items: Default::default(),
         repeated: Default::default(),
         popup_windows: Default::default(),
@@ -186,6 +189,9 @@ fn lower_sub_component(
         two_way_bindings: Default::default(),
         const_properties: Default::default(),
         init_code: Default::default(),
+        change_callbacks: Default::default(),
+        accessible_props: Default::default(),
+        element_ids: Default::default(),
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false),
         layout_info_v: super::Expression::BoolLiteral(false),
@@ -209,6 +215,7 @@ fn lower_sub_component(
 
     let s: Option<ElementRc> = None;
     let mut repeater_offset = 0;
+    let mut function_elements = vec![];
     crate::object_tree::recurse_elem(&component.root_element, &s, &mut |element, parent| {
         let elem = element.borrow();
         for (p, x) in &elem.property_declarations {
@@ -224,6 +231,21 @@ fn lower_sub_component(
                 .properties
                 .push(Property { name: format!("{}_{}", elem.id, p), ty: x.property_type.clone() });
         }
+        for (f, function) in &elem.functions {
+            let function_index = sub_component.functions.len();
+            mapping.property_mapping.insert(
+                NamedReference::new(element, f),
+                PropertyReference::Function { sub_component_path: vec![], function_index },
+            );
+            sub_component.functions.push(Function {
+                name: format!("{}_{}", elem.id, f),
+                ret_ty: (*function.return_type).clone(),
+                args: function.args.clone(),
+                // The body is lowered once all the properties are mapped
+                code: super::Expression::CodeBlock(vec![]),
+            });
+            function_elements.push((element.clone(), f.clone()));
+        }
         if elem.repeated.is_some() {
             mapping.element_mapping.insert(
                 element.clone().into(),
@@ -273,6 +295,10 @@ fn lower_sub_component(
         Some(element.clone())
     });
     let ctx = ExpressionContext { mapping: &mapping, state, parent: parent_context, component };
+    for (function, (element, f)) in sub_component.functions.iter_mut().zip(function_elements) {
+        let code = &element.borrow().functions[&f].code;
+        function.code = super::lower_expression::lower_expression(&code.borrow(), &ctx);
+    }
     crate::generator::handle_property_bindings_init(component, |e, p, binding| {
         let prop = ctx.map_property_reference(&NamedReference::new(e, p));
         for tw in &binding.two_way_bindings {
@@ -319,7 +345,7 @@ fn lower_sub_component(
         .popup_windows
         .borrow()
         .iter()
-        .map(|popup| lower_popup_component(&popup.component, &ctx))
+        .map(|popup| lower_popup_component(popup, &ctx))
         .collect();
 
     crate::generator::for_each_const_properties(component, |elem, n| {
@@ -334,6 +360,29 @@ fn lower_sub_component(
         .map(|e| super::lower_expression::lower_expression(e, &ctx))
         .collect();
 
+    crate::object_tree::recurse_elem(&component.root_element, &(), &mut |element, _| {
+        for (p, callbacks) in &element.borrow().change_callbacks {
+            let prop = ctx.map_property_reference(&NamedReference::new(element, p));
+            for expr in callbacks.borrow().iter() {
+                sub_component
+                    .change_callbacks
+                    .push((prop.clone(), super::lower_expression::lower_expression(expr, &ctx)));
+            }
+        }
+        if element.borrow().repeated.is_some() {
+            return;
+        }
+        let item_index = *element.borrow().item_index.get().unwrap();
+        for (p, nr) in &element.borrow().accessibility_props {
+            sub_component
+                .accessible_props
+                .insert((item_index, p.clone()), ctx.map_property_reference(nr));
+        }
+        if !element.borrow().element_ids.is_empty() {
+            sub_component.element_ids.insert(item_index, element.borrow().element_ids.clone());
+        }
+    });
+
     sub_component.layout_info_h = super::lower_expression::get_layout_info(
         &component.root_element,
         &ctx,
@@ -380,6 +429,7 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
             tree: make_tree(ctx.state, &component.root_element, &sc, &[]),
             root: Rc::try_unwrap(sc.sub_component).unwrap(),
             parent_context: Some(e.enclosing_component.upgrade().unwrap().id.clone()),
+            is_child_window: false,
         },
         index_prop: (!repeated.is_conditional_element).then(|| 1),
         data_prop: (!repeated.is_conditional_element).then(|| 0),
@@ -388,7 +438,8 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
     }
 }
 
-fn lower_popup_component(component: &Rc<Component>, ctx: &ExpressionContext) -> ItemTree {
+fn lower_popup_component(popup: &PopupWindow, ctx: &ExpressionContext) -> ItemTree {
+    let component = &popup.component;
     let sc = lower_sub_component(component, &ctx.state, Some(ctx));
     ItemTree {
         tree: make_tree(ctx.state, &component.root_element, &sc, &[]),
@@ -405,6 +456,7 @@ fn lower_popup_component(component: &Rc<Component>, ctx: &ExpressionContext) ->
                 .id
                 .clone(),
         ),
+        is_child_window: popup.is_child_window,
     }
 }
 
@@ -415,6 +467,7 @@ fn lower_global(
 ) -> GlobalComponent {
     let mut mapping = LoweredSubComponentMapping::default();
     let mut properties = vec![];
+    let mut functions = vec![];
     let mut const_properties = vec![];
 
     for (p, x) in &global.root_element.borrow().property_declarations {
@@ -434,9 +487,31 @@ fn lower_global(
             .insert(nr.clone(), PropertyReference::Global { global_index, property_index });
     }
 
+    for (f, function) in &global.root_element.borrow().functions {
+        let function_index = functions.len();
+        let nr = NamedReference::new(&global.root_element, f);
+        mapping.property_mapping.insert(
+            nr.clone(),
+            PropertyReference::Function { sub_component_path: vec![], function_index },
+        );
+        functions.push(Function {
+            name: f.clone(),
+            ret_ty: (*function.return_type).clone(),
+            args: function.args.clone(),
+            // The body is lowered once all the properties are mapped
+            code: super::Expression::CodeBlock(vec![]),
+        });
+        state
+            .global_properties
+            .insert(nr, PropertyReference::GlobalFunction { global_index, function_index });
+    }
+
     let mut init_values = vec![None; properties.len()];
 
     let ctx = ExpressionContext { mapping: &mapping, state, parent: None, component: global };
+    for (function, f) in functions.iter_mut().zip(global.root_element.borrow().functions.values()) {
+        function.code = super::lower_expression::lower_expression(&f.code.borrow(), &ctx);
+    }
     for (prop, binding) in &global.root_element.borrow().bindings {
         assert!(binding.borrow().two_way_bindings.is_empty());
         assert!(binding.borrow().animation.is_none());
@@ -472,6 +547,7 @@ fn lower_global(
     GlobalComponent {
         name: global.root_element.borrow().id.clone(),
         properties,
+        functions,
         init_values,
         const_properties,
         public_properties,
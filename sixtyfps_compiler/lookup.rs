@@ -60,10 +60,12 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
     }
 
     pub fn return_type(&self) -> &Type {
-        if let Type::Callback { return_type, .. } = &self.property_type {
-            return_type.as_ref().map_or(&Type::Void, |b| &(**b))
-        } else {
-            &self.property_type
+        match &self.property_type {
+            Type::Callback { return_type, .. } => {
+                return_type.as_ref().map_or(&Type::Void, |b| &(**b))
+            }
+            Type::Function { return_type, .. } => return_type,
+            _ => &self.property_type,
         }
     }
 }
@@ -343,6 +345,10 @@ fn lookup(&self, _ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
 fn expression_from_reference(n: NamedReference, ty: &Type) -> Expression {
     if matches!(ty, Type::Callback { .. }) {
         Expression::CallbackReference(n)
+    } else if matches!(ty, Type::Function { .. })
+        && n.element().borrow().is_declared_function(n.name())
+    {
+        Expression::FunctionReference(n)
     } else {
         Expression::PropertyReference(n)
     }
@@ -392,6 +398,9 @@ fn for_each_entry<R>(
             Type::Color => ColorSpecific.for_each_entry(ctx, f),
             Type::Brush => ColorSpecific.for_each_entry(ctx, f),
             Type::Easing => EasingSpecific.for_each_entry(ctx, f),
+            Type::Float32 if ctx.property_name == Some("iteration-count") => {
+                IterationCountSpecific.for_each_entry(ctx, f)
+            }
             Type::Enumeration(enumeration) => enumeration.clone().for_each_entry(ctx, f),
             _ => None,
         }
@@ -402,6 +411,9 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
             Type::Color => ColorSpecific.lookup(ctx, name),
             Type::Brush => ColorSpecific.lookup(ctx, name),
             Type::Easing => EasingSpecific.lookup(ctx, name),
+            Type::Float32 if ctx.property_name == Some("iteration-count") => {
+                IterationCountSpecific.lookup(ctx, name)
+            }
             Type::Enumeration(enumeration) => enumeration.clone().lookup(ctx, name),
             _ => None,
         }
@@ -459,6 +471,18 @@ fn for_each_entry<R>(
 
 sixtyfps_common::for_each_special_keys!(special_keys_lookup);
 
+/// `infinite` for the `iteration-count` of an animation
+struct IterationCountSpecific;
+impl LookupObject for IterationCountSpecific {
+    fn for_each_entry<R>(
+        &self,
+        _ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        f("infinite", Expression::NumberLiteral(-1., Unit::None).into())
+    }
+}
+
 struct EasingSpecific;
 impl LookupObject for EasingSpecific {
     fn for_each_entry<R>(
@@ -490,6 +514,36 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "steps",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Steps,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "custom",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::CustomEasing,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
     }
 }
 
@@ -10,7 +10,7 @@
 use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
 use crate::langtype::PropertyLookupResult;
-use crate::langtype::{BuiltinElement, NativeClass, Type};
+use crate::langtype::{BuiltinElement, Enumeration, NativeClass, Type};
 use crate::layout::{LayoutConstraints, Orientation};
 use crate::namedreference::NamedReference;
 use crate::parser;
@@ -19,7 +19,7 @@
 use crate::typeregister::TypeRegister;
 use std::cell::{Cell, RefCell};
 use std::collections::btree_map::Entry;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::rc::{Rc, Weak};
 
 macro_rules! unwrap_or_continue {
@@ -40,6 +40,7 @@ pub struct Document {
     pub node: Option<syntax_nodes::Document>,
     pub inner_components: Vec<Rc<Component>>,
     pub inner_structs: Vec<Type>,
+    pub inner_enums: Vec<Rc<Enumeration>>,
     pub root_component: Rc<Component>,
     pub local_registry: TypeRegister,
     /// A list of paths to .ttf/.ttc files that are supposed to be registered on
@@ -60,6 +61,7 @@ pub fn from_node(
         let mut local_registry = TypeRegister::new(parent_registry);
         let mut inner_components = vec![];
         let mut inner_structs = vec![];
+        let mut inner_enums = vec![];
 
         let mut process_component =
             |n: syntax_nodes::Component,
@@ -83,6 +85,44 @@ pub fn from_node(
                 local_registry.insert_type(ty.clone());
                 inner_structs.push(ty);
             };
+        let mut process_enum = |n: syntax_nodes::EnumDeclaration,
+                                diag: &mut BuildDiagnostics,
+                                local_registry: &mut TypeRegister| {
+            let name = match parser::identifier_text(&n.DeclaredIdentifier()) {
+                Some(name) => name,
+                None => {
+                    assert!(diag.has_error());
+                    return;
+                }
+            };
+            let mut values: Vec<String> = vec![];
+            for v in n.EnumValue() {
+                let value = match parser::identifier_text(&v) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if values.contains(&value) {
+                    diag.push_error(format!("Duplicated enum value '{}'", value), &v);
+                    continue;
+                }
+                values.push(value);
+            }
+            if values.is_empty() {
+                diag.push_error(
+                    format!("Enum '{}' must have at least one value", name),
+                    &n.DeclaredIdentifier(),
+                );
+                return;
+            }
+            let e = Rc::new(Enumeration {
+                name: name.clone(),
+                values,
+                default_value: 0,
+                node: Some(n),
+            });
+            local_registry.insert_type_with_name(Type::Enumeration(e.clone()), name);
+            inner_enums.push(e);
+        };
 
         for n in node.children() {
             match n.kind() {
@@ -90,6 +130,7 @@ pub fn from_node(
                 SyntaxKind::StructDeclaration => {
                     process_struct(n.into(), diag, &mut local_registry)
                 }
+                SyntaxKind::EnumDeclaration => process_enum(n.into(), diag, &mut local_registry),
                 SyntaxKind::ExportsList => {
                     for n in n.children() {
                         match n.kind() {
@@ -99,6 +140,9 @@ pub fn from_node(
                             SyntaxKind::StructDeclaration => {
                                 process_struct(n.into(), diag, &mut local_registry)
                             }
+                            SyntaxKind::EnumDeclaration => {
+                                process_enum(n.into(), diag, &mut local_registry)
+                            }
                             _ => {}
                         }
                     }
@@ -162,6 +206,7 @@ pub fn from_node(
             root_component,
             inner_components,
             inner_structs,
+            inner_enums,
             local_registry,
             custom_fonts,
             exports,
@@ -179,6 +224,9 @@ pub struct PopupWindow {
     pub x: NamedReference,
     pub y: NamedReference,
     pub parent_element: ElementRc,
+    /// True if the component comes from a `Window` element that is not the root of its component.
+    /// It is shown in its own top-level window with `show()`, instead of as a popup.
+    pub is_child_window: bool,
 }
 
 type ChildrenInsertionPoint = (ElementRc, syntax_nodes::ChildrenPlaceholder);
@@ -190,6 +238,8 @@ pub struct UsedSubTypes {
     pub globals: Vec<Rc<Component>>,
     /// All the structs used by the component and its children.
     pub structs: Vec<Type>,
+    /// All the enums declared in .60 files that are used by the component and its children.
+    pub enums: Vec<Rc<Enumeration>>,
     /// All the sub components use by this components and its children,
     /// and the amount of time it is used
     pub sub_components: Vec<Rc<Component>>,
@@ -344,6 +394,22 @@ pub fn type_node(&self) -> Option<SyntaxNode> {
     }
 }
 
+/// A function declared with the `function` keyword
+#[derive(Clone, Debug)]
+pub struct Function {
+    pub return_type: Box<Type>,
+    pub args: Vec<Type>,
+    /// The body of the function. It is an Expression::Uncompiled until the resolving pass
+    pub code: RefCell<Expression>,
+    pub node: Option<syntax_nodes::Function>,
+}
+
+impl Function {
+    pub fn ty(&self) -> Type {
+        Type::Function { return_type: self.return_type.clone(), args: self.args.clone() }
+    }
+}
+
 impl From<Type> for PropertyDeclaration {
     fn from(ty: Type) -> Self {
         PropertyDeclaration { property_type: ty, ..Self::default() }
@@ -433,6 +499,10 @@ pub struct Element {
     pub base_type: crate::langtype::Type,
     /// Currently contains also the callbacks. FIXME: should that be changed?
     pub bindings: BindingsMap,
+    /// The `changed prop => { ... }` handlers, indexed by property name.
+    pub change_callbacks: BTreeMap<String, RefCell<Vec<Expression>>>,
+    /// The functions declared with the `function` keyword, indexed by name.
+    pub functions: BTreeMap<String, Function>,
     pub property_analysis: RefCell<HashMap<String, PropertyAnalysis>>,
 
     pub children: Vec<ElementRc>,
@@ -455,6 +525,15 @@ pub struct Element {
     /// The property pointing to the layout info. `(horizontal, vertical)`
     pub layout_info_prop: Option<(NamedReference, NamedReference)>,
 
+    /// The accessibility properties (`accessible-role`, `accessible-label`, ...) that are set
+    /// on this element, indexed by name. Filled by the lower_accessibility pass.
+    pub accessibility_props: BTreeMap<String, NamedReference>,
+
+    /// The ids given to this element in the .60 sources. Unlike `id`, these are not renamed by
+    /// the unique_id pass. An element can have several ids when a component is inlined into it.
+    /// They are used to look up elements in the testing API.
+    pub element_ids: Vec<String>,
+
     /// true if this Element is the fake Flickable viewport
     pub is_flickable_viewport: bool,
 
@@ -530,6 +609,20 @@ macro_rules! indent {
             writeln!(f, "{} <=> {:?};", name, nr)?;
         }
     }
+    for (name, callbacks) in &e.change_callbacks {
+        for expr in callbacks.borrow().iter() {
+            indent!();
+            write!(f, "changed {} => ", name)?;
+            expression_tree::pretty_print(f, expr)?;
+            writeln!(f)?;
+        }
+    }
+    for (name, function) in &e.functions {
+        indent!();
+        write!(f, "function {}({:?}) -> {} ", name, function.args, function.return_type)?;
+        expression_tree::pretty_print(f, &function.code.borrow())?;
+        writeln!(f)?;
+    }
     if !e.states.is_empty() {
         indent!();
         writeln!(f, "states {:?}", e.states)?;
@@ -670,6 +763,7 @@ pub fn from_node(
             node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
             node.States().for_each(|n| error_on(&n, "states"));
             node.Transitions().for_each(|n| error_on(&n, "transitions"));
+            node.PropertyChangedCallback().for_each(|n| error_on(&n, "change callbacks"));
             Type::Void
         };
         let mut r = Element { id, base_type, node: Some(node.clone()), ..Default::default() };
@@ -794,6 +888,56 @@ pub fn from_node(
             );
         }
 
+        for func in node.Function() {
+            let name =
+                unwrap_or_continue!(parser::identifier_text(&func.DeclaredIdentifier()); diag);
+            if r.lookup_property(&name).property_type != Type::Invalid {
+                diag.push_error(
+                    format!("Cannot override '{}'", name),
+                    &func.DeclaredIdentifier().child_token(SyntaxKind::Identifier).unwrap(),
+                );
+                continue;
+            }
+
+            let mut arg_names = HashSet::new();
+            let args = func
+                .ArgumentDeclaration()
+                .map(|arg| {
+                    if let Some(arg_name) = parser::identifier_text(&arg.DeclaredIdentifier()) {
+                        if !arg_names.insert(arg_name.clone()) {
+                            diag.push_error(
+                                format!("Duplicated argument name '{}'", arg_name),
+                                &arg.DeclaredIdentifier()
+                                    .child_token(SyntaxKind::Identifier)
+                                    .unwrap(),
+                            );
+                        }
+                    }
+                    let ty = type_from_node(arg.Type(), diag, tr);
+                    if ty != Type::Invalid && !ty.is_property_type() {
+                        diag.push_error(
+                            format!("'{}' is not a valid argument type", ty),
+                            &arg.Type(),
+                        );
+                    }
+                    ty
+                })
+                .collect();
+            let return_type = Box::new(
+                func.ReturnType()
+                    .map_or(Type::Void, |ret_ty| type_from_node(ret_ty.Type(), diag, tr)),
+            );
+            r.functions.insert(
+                name,
+                Function {
+                    return_type,
+                    args,
+                    code: RefCell::new(Expression::Uncompiled(func.clone().into())),
+                    node: Some(func),
+                },
+            );
+        }
+
         for con_node in node.CallbackConnection() {
             let unresolved_name = unwrap_or_continue!(parser::identifier_text(&con_node); diag);
             let PropertyLookupResult { resolved_name, property_type } =
@@ -834,6 +978,37 @@ pub fn from_node(
             }
         }
 
+        for change_node in node.PropertyChangedCallback() {
+            let name_node = change_node.DeclaredIdentifier();
+            let unresolved_name = unwrap_or_continue!(parser::identifier_text(&name_node); diag);
+            let PropertyLookupResult { resolved_name, property_type } =
+                r.lookup_property(&unresolved_name);
+            if property_type == Type::Invalid {
+                diag.push_error(
+                    format!("Unknown property {} in {}", unresolved_name, r.base_type),
+                    &name_node,
+                );
+                continue;
+            } else if !property_type.is_property_type() {
+                diag.push_error(
+                    format!("'{}' is not a property in {}", unresolved_name, r.base_type),
+                    &name_node,
+                );
+                continue;
+            }
+            match r.change_callbacks.entry(resolved_name.into_owned()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(RefCell::new(vec![Expression::Uncompiled(
+                        change_node.clone().into(),
+                    )]));
+                }
+                Entry::Occupied(_) => diag.push_error(
+                    format!("Duplicated change callback on '{}'", unresolved_name),
+                    &name_node,
+                ),
+            }
+        }
+
         for anim in node.PropertyAnimation() {
             if let Some(star) = anim.child_token(SyntaxKind::Star) {
                 diag.push_error(
@@ -997,14 +1172,18 @@ fn from_sub_element_node(
                 &node.child_token(SyntaxKind::Identifier).unwrap(),
             )
         }
-        Element::from_node(
+        let e = Element::from_node(
             node.Element(),
-            id,
+            id.clone(),
             parent_type,
             component_child_insertion_point,
             diag,
             tr,
-        )
+        );
+        if !id.is_empty() {
+            e.borrow_mut().element_ids.push(id);
+        }
+        e
     }
 
     fn from_repeated_node(
@@ -1078,10 +1257,24 @@ fn from_conditional_node(
     /// the provided name points towards a property alias. Type::Invalid is returned if the property does
     /// not exist.
     pub fn lookup_property<'a>(&self, name: &'a str) -> PropertyLookupResult<'a> {
-        self.property_declarations.get(name).cloned().map(|decl| decl.property_type).map_or_else(
-            || self.base_type.lookup_property(name),
-            |property_type| PropertyLookupResult { resolved_name: name.into(), property_type },
-        )
+        self.property_declarations
+            .get(name)
+            .map(|decl| decl.property_type.clone())
+            .or_else(|| self.functions.get(name).map(Function::ty))
+            .map_or_else(
+                || self.base_type.lookup_property(name),
+                |property_type| PropertyLookupResult { resolved_name: name.into(), property_type },
+            )
+    }
+
+    /// Return true if `name` is a function declared with the `function` keyword in this element
+    /// or in one of its base components (as opposed to a builtin member function such as `focus`)
+    pub fn is_declared_function(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+            || match &self.base_type {
+                Type::Component(c) => c.root_element.borrow().is_declared_function(name),
+                _ => false,
+            }
     }
 
     /// Return the Span of this element in the AST for error reporting
@@ -1286,7 +1479,7 @@ fn animation_element_from_node(
     diag: &mut BuildDiagnostics,
     tr: &TypeRegister,
 ) -> Option<ElementRc> {
-    let anim_type = tr.property_animation_type_for_property(prop_type);
+    let anim_type = tr.property_animation_type_for_property(prop_type.clone());
     if !matches!(anim_type, Type::Builtin(..)) {
         diag.push_error(
             format!(
@@ -1305,10 +1498,43 @@ fn animation_element_from_node(
             }),
             diag,
         );
+        if let Some(keyframes) = anim.Keyframes() {
+            if matches!(prop_type, Type::Color | Type::Brush) {
+                diag.push_error(
+                    "Keyframes are only supported for the animation of numeric properties".into(),
+                    &keyframes,
+                );
+            } else {
+                anim_element.property_declarations.insert(
+                    "keyframes".into(),
+                    PropertyDeclaration {
+                        property_type: keyframes_type(prop_type),
+                        ..Default::default()
+                    },
+                );
+                anim_element.bindings.insert(
+                    "keyframes".into(),
+                    BindingExpression::new_uncompiled(keyframes.into()).into(),
+                );
+            }
+        }
         Some(Rc::new(RefCell::new(anim_element)))
     }
 }
 
+/// The type of the `keyframes` of the animation of a property of the given type
+pub fn keyframes_type(property_type: Type) -> Type {
+    Type::Array(Box::new(Type::Struct {
+        fields: IntoIterator::into_iter([
+            ("position".to_string(), Type::Float32),
+            ("value".to_string(), property_type),
+        ])
+        .collect(),
+        name: None,
+        node: None,
+    }))
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct QualifiedTypeName {
     pub members: Vec<String>,
@@ -1536,6 +1762,14 @@ fn visit_element_expressions_simple(
         elem.borrow_mut().repeated = Some(r)
     }
     visit_element_expressions_simple(elem, &mut vis);
+    for (name, callbacks) in &elem.borrow().change_callbacks {
+        for expr in callbacks.borrow_mut().iter_mut() {
+            vis(expr, Some(name.as_str()), &|| Type::Callback { return_type: None, args: vec![] });
+        }
+    }
+    for (name, function) in &elem.borrow().functions {
+        vis(&mut function.code.borrow_mut(), Some(name.as_str()), &|| function.ty());
+    }
     let mut states = std::mem::take(&mut elem.borrow_mut().states);
     for s in &mut states {
         if let Some(cond) = s.condition.as_mut() {
@@ -1567,7 +1801,9 @@ pub fn visit_all_named_references_in_element(
     fn recurse_expression(expr: &mut Expression, vis: &mut impl FnMut(&mut NamedReference)) {
         expr.visit_mut(|sub| recurse_expression(sub, vis));
         match expr {
-            Expression::PropertyReference(r) | Expression::CallbackReference(r) => vis(r),
+            Expression::PropertyReference(r)
+            | Expression::CallbackReference(r)
+            | Expression::FunctionReference(r) => vis(r),
             Expression::LayoutCacheAccess { layout_cache_prop, .. } => vis(layout_cache_prop),
             Expression::SolveLayout(l, _) => l.visit_named_references(vis),
             Expression::ComputeLayoutInfo(l, _) => l.visit_named_references(vis),
@@ -1613,6 +1849,11 @@ fn recurse_expression(expr: &mut Expression, vis: &mut impl FnMut(&mut NamedRefe
     let mut layout_info_prop = std::mem::take(&mut elem.borrow_mut().layout_info_prop);
     layout_info_prop.as_mut().map(|(h, b)| (vis(h), vis(b)));
     elem.borrow_mut().layout_info_prop = layout_info_prop;
+    let mut accessibility_props = std::mem::take(&mut elem.borrow_mut().accessibility_props);
+    for nr in accessibility_props.values_mut() {
+        vis(nr);
+    }
+    elem.borrow_mut().accessibility_props = accessibility_props;
 
     // visit two way bindings
     for expr in elem.borrow().bindings.values() {
@@ -1758,19 +1999,26 @@ struct NamedExport {
             }),
         );
         let exports_it = exports_it.chain(
-            doc.ExportsList().flat_map(|exports| exports.StructDeclaration()).map(|st| {
-                let name_location: SyntaxNode = st.DeclaredIdentifier().into();
-                let name = parser::identifier_text(&st.DeclaredIdentifier()).unwrap_or_else(|| {
-                    debug_assert!(diag.has_error());
-                    String::new()
-                });
-                NamedExport {
-                    internal_name_ident: name_location.clone(),
-                    internal_name: name.clone(),
-                    external_name_ident: name_location,
-                    exported_name: name,
-                }
-            }),
+            doc.ExportsList()
+                .flat_map(|exports| {
+                    exports
+                        .StructDeclaration()
+                        .map(|st| st.DeclaredIdentifier())
+                        .chain(exports.EnumDeclaration().map(|en| en.DeclaredIdentifier()))
+                })
+                .map(|declared_identifier| {
+                    let name_location: SyntaxNode = declared_identifier.clone().into();
+                    let name = parser::identifier_text(&declared_identifier).unwrap_or_else(|| {
+                        debug_assert!(diag.has_error());
+                        String::new()
+                    });
+                    NamedExport {
+                        internal_name_ident: name_location.clone(),
+                        internal_name: name.clone(),
+                        external_name_ident: name_location,
+                        exported_name: name,
+                    }
+                }),
         );
 
         struct SeenExport {
@@ -1821,7 +2069,9 @@ struct SeenExport {
 
         let mut resolve_export_to_inner_component_or_import =
             |export: &NamedExport| match type_registry.lookup(export.internal_name.as_str()) {
-                ty @ Type::Component(_) | ty @ Type::Struct { .. } => Some(ty),
+                ty @ Type::Component(_) | ty @ Type::Struct { .. } | ty @ Type::Enumeration(_) => {
+                    Some(ty)
+                }
                 Type::Invalid => {
                     diag.push_error(
                         format!("'{}' not found", export.internal_name),
@@ -321,17 +321,20 @@ fn source_file(&self) -> Option<&SourceFile> {
         Question -> "?",
         Dollar -> "$",
         At -> "@",
+        Pipe -> "|",
+        Percent -> "%",
     }
     // syntax kind
     {
-        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration ],
+        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         /// `DeclaredIdentifier := Element { ... }`
         Component -> [ DeclaredIdentifier, Element ],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
-                     *CallbackDeclaration, *SubElement, *RepeatedElement, *PropertyAnimation,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *CallbackDeclaration, *Function, *SubElement, *RepeatedElement,
+                     *PropertyAnimation, *TwoWayBinding, *States, *Transitions,
+                     *PropertyChangedCallback, ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -339,10 +342,20 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier,  CodeBlock ],
+        /// `changed prop => { ... }`
+        PropertyChangedCallback -> [ DeclaredIdentifier, CodeBlock ],
+        /// `function foo(arg: type) -> type { ... }`
+        Function -> [ DeclaredIdentifier, *ArgumentDeclaration, ?ReturnType, CodeBlock ],
+        /// `arg: type` within a function declaration
+        ArgumentDeclaration -> [ DeclaredIdentifier, Type ],
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, ?Keyframes ],
+        /// `keyframes: [0%: 0px, 50%: 30px, 100%: 0px];` within an animation
+        Keyframes -> [ *Keyframe ],
+        /// `50%: 30px` in a Keyframes. The first expression is the position
+        Keyframe -> [ 2 Expression ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
         QualifiedName-> [],
         /// Wraps single identifier (to disambiguate when there are other identifier in the production)
@@ -360,13 +373,21 @@ fn source_file(&self) -> Option<&SourceFile> {
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
                        ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtLinearGradient,
-                       ?MemberAccess ],
+                       ?AtRadialGradient, ?AtTr, ?MemberAccess ],
         /// Concatenate the Expressions to make a string (usually expended from a template string)
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
         AtImageUrl -> [],
         /// `@linear-gradient(...)`
         AtLinearGradient -> [*Expression],
+        /// `@radial-gradient(...)`
+        AtRadialGradient -> [*Expression],
+        /// `@tr("context" => "format {}" | "plural format {}" % n, args...)`
+        AtTr -> [?TrContext, ?TrPlural, *Expression],
+        /// `"context" =>` in a `@tr(...)`
+        TrContext -> [],
+        /// `| "plural" % n` in a `@tr(...)`
+        TrPlural -> [Expression],
         /// expression()
         FunctionCallExpression -> [*Expression],
         /// expression[index]
@@ -398,7 +419,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// There is an identifier "in" or "out", the DeclaredIdentifier is the state name
         Transition -> [DeclaredIdentifier, *PropertyAnimation],
         /// Export a set of declared components by name
-        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration ],
+        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, *EnumDeclaration ],
         /// Declare the first identifier to be exported, either under its name or instead
         /// under the name of the second identifier.
         ExportSpecifier -> [ ExportIdentifier, ?ExportName ],
@@ -421,6 +442,10 @@ fn source_file(&self) -> Option<&SourceFile> {
         ArrayType -> [ Type ],
         /// `struct Foo := { ... }
         StructDeclaration -> [DeclaredIdentifier, ObjectType],
+        /// `enum Foo { bar, baz }`
+        EnumDeclaration -> [DeclaredIdentifier, *EnumValue],
+        /// The identifier of one value within an EnumDeclaration
+        EnumValue -> [],
 
     }
 }
@@ -3,7 +3,7 @@
 
 use super::element::{parse_element, parse_element_content};
 use super::prelude::*;
-use super::r#type::parse_struct_declaration;
+use super::r#type::{parse_enum_declaration, parse_struct_declaration};
 
 #[cfg_attr(test, parser_test)]
 /// ```test,Document
@@ -13,6 +13,7 @@
 /// Type := Base {} export { Type }
 /// import { Base } from "somewhere"; Type := Base {}
 /// struct Foo := { foo: foo }
+/// enum Foo { bar, baz }
 /// /* empty */
 /// ```
 pub fn parse_document(p: &mut impl Parser) -> bool {
@@ -39,6 +40,11 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
                     return false;
                 }
             }
+            "enum" => {
+                if !parse_enum_declaration(&mut *p) {
+                    return false;
+                }
+            }
             _ => {
                 if !parse_component(&mut *p) {
                     return false;
@@ -110,6 +116,7 @@ pub fn parse_qualified_name(p: &mut impl Parser) -> bool {
 /// export { Type as Foo, AnotherType }
 /// export Foo := Item { }
 /// export struct Foo := { foo: bar }
+/// export enum Foo { bar, baz }
 /// ```
 fn parse_export(p: &mut impl Parser) -> bool {
     debug_assert_eq!(p.peek().as_str(), "export");
@@ -138,6 +145,8 @@ fn parse_export(p: &mut impl Parser) -> bool {
         }
     } else if p.peek().as_str() == "struct" {
         parse_struct_declaration(&mut *p)
+    } else if p.peek().as_str() == "enum" {
+        parse_enum_declaration(&mut *p)
     } else {
         parse_component(&mut *p)
     }
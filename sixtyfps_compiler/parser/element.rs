@@ -32,7 +32,9 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// for xx in model: Sub {}
 /// if condition : Sub {}
 /// clicked => {}
+/// changed width => {}
 /// callback foobar;
+/// function foo() {}
 /// property<int> width;
 /// animate someProp { }
 /// animate * { }
@@ -52,12 +54,21 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     parse_callback_connection(&mut *p)
                 }
                 SyntaxKind::DoubleArrow => parse_two_way_binding(&mut *p),
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "changed"
+                        && p.nth(2).kind() == SyntaxKind::FatArrow =>
+                {
+                    parse_property_changed_callback(&mut *p);
+                }
                 SyntaxKind::Identifier if p.peek().as_str() == "for" => {
                     parse_repeated_element(&mut *p);
                 }
                 SyntaxKind::Identifier if p.peek().as_str() == "callback" => {
                     parse_callback_declaration(&mut *p);
                 }
+                SyntaxKind::Identifier if p.peek().as_str() == "function" => {
+                    parse_function(&mut *p);
+                }
                 SyntaxKind::Identifier | SyntaxKind::Star if p.peek().as_str() == "animate" => {
                     parse_property_animation(&mut *p);
                 }
@@ -256,6 +267,23 @@ fn parse_callback_connection(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,PropertyChangedCallback
+/// changed foo => {}
+/// changed text => { foo; bar = 42; }
+/// ```
+fn parse_property_changed_callback(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "changed");
+    let mut p = p.start_node(SyntaxKind::PropertyChangedCallback);
+    p.consume(); // "changed"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,TwoWayBinding
 /// foo <=> bar;
@@ -320,6 +348,46 @@ fn parse_callback_declaration(p: &mut impl Parser) {
     p.expect(SyntaxKind::Semicolon);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Function
+/// function foo() {}
+/// function foo(a: int) -> int { return a; }
+/// function clamp(v: length, lo: length, hi: length,) -> length { max(lo, min(hi, v)) }
+/// function with_struct(a: { x: string, y: int }, b: [int]) -> string { a.x }
+/// ```
+/// Must consume at least one token
+fn parse_function(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "function");
+    let mut p = p.start_node(SyntaxKind::Function);
+    p.consume(); // "function"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.expect(SyntaxKind::LParent) {
+        while p.peek().kind() != SyntaxKind::RParent {
+            {
+                let mut p = p.start_node(SyntaxKind::ArgumentDeclaration);
+                {
+                    let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+                    p.expect(SyntaxKind::Identifier);
+                }
+                p.expect(SyntaxKind::Colon);
+                parse_type(&mut *p);
+            }
+            if !p.test(SyntaxKind::Comma) {
+                break;
+            }
+        }
+        p.expect(SyntaxKind::RParent);
+    }
+    if p.test(SyntaxKind::Arrow) {
+        let mut p = p.start_node(SyntaxKind::ReturnType);
+        parse_type(&mut *p);
+    }
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,PropertyDeclaration
 /// property<int> foobar;
@@ -368,6 +436,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1s; keyframes: [0%: 0px, 50%: 30px, 100%: 0px]; }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -392,6 +461,7 @@ fn parse_property_animation(p: &mut impl Parser) {
             }
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
+                SyntaxKind::Colon if p.peek().as_str() == "keyframes" => parse_keyframes(&mut *p),
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
                 _ => {
                     p.consume();
@@ -406,6 +476,34 @@ fn parse_property_animation(p: &mut impl Parser) {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Keyframes
+/// keyframes: [];
+/// keyframes: [0%: 0px, 50%: 30px, 100%: 0px];
+/// keyframes: [25%: foo.bar * 2, ];
+/// ```
+fn parse_keyframes(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "keyframes");
+    let mut p = p.start_node(SyntaxKind::Keyframes);
+    p.consume(); // "keyframes"
+    p.expect(SyntaxKind::Colon);
+    if !p.expect(SyntaxKind::LBracket) {
+        p.until(SyntaxKind::Semicolon);
+        return;
+    }
+    while !matches!(p.nth(0).kind(), SyntaxKind::RBracket | SyntaxKind::Eof) {
+        let mut p = p.start_node(SyntaxKind::Keyframe);
+        parse_expression(&mut *p);
+        p.expect(SyntaxKind::Colon);
+        parse_expression(&mut *p);
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RBracket);
+    p.expect(SyntaxKind::Semicolon);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,States
 /// states []
@@ -211,6 +211,8 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
 /// ```test
 /// @image-url("/foo/bar.png")
 /// @linear-gradient(0deg, blue, red)
+/// @radial-gradient(circle, blue, red)
+/// @tr("foo")
 /// ```
 fn parse_at_keyword(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().kind(), SyntaxKind::At);
@@ -226,9 +228,15 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "linear-gradient" | "linear_gradient" => {
             parse_at_linear_gradient(p);
         }
+        "radial-gradient" | "radial_gradient" => {
+            parse_at_radial_gradient(p);
+        }
+        "tr" => {
+            parse_tr(p);
+        }
         _ => {
             p.consume();
-            p.error("Expected 'image-url' or 'linear-gradient' after '@'");
+            p.error("Expected 'image-url', 'linear-gradient', 'radial-gradient' or 'tr' after '@'");
         }
     }
 }
@@ -345,3 +353,82 @@ fn parse_at_linear_gradient(p: &mut impl Parser) {
         p.test(SyntaxKind::Comma);
     }
 }
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AtRadialGradient
+/// @radial-gradient(circle, #e66465, #9198e5)
+/// @radial-gradient(circle, #333, #333 50%, #eee 75%, #333 75%)
+/// @radial_gradient(circle, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
+/// ```
+fn parse_at_radial_gradient(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtRadialGradient);
+    p.expect(SyntaxKind::At);
+    debug_assert!(p.peek().as_str() == "radial-gradient" || p.peek().as_str() == "radial_gradient");
+    p.consume(); //"radial-gradient"
+
+    p.expect(SyntaxKind::LParent);
+
+    while !p.test(SyntaxKind::RParent) {
+        if !parse_expression(&mut *p) {
+            return;
+        }
+        p.test(SyntaxKind::Comma);
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AtTr
+/// @tr("foo")
+/// @tr("foo{0}", bar(42))
+/// @tr("context" => "ccc{}", 0)
+/// @tr("xxx" => "ccc{n}" | "ddd{}" % 42, 45)
+/// @tr("{n} file" | "{n} files" % count)
+/// ```
+fn parse_tr(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtTr);
+    p.expect(SyntaxKind::At);
+    debug_assert_eq!(p.peek().as_str(), "tr");
+    p.consume(); // "tr"
+    p.expect(SyntaxKind::LParent);
+
+    fn consume_literal(p: &mut impl Parser) -> bool {
+        let peek = p.peek();
+        if peek.kind() != SyntaxKind::StringLiteral
+            || !peek.as_str().starts_with('"')
+            || !peek.as_str().ends_with('"')
+        {
+            p.error("Expected plain string literal");
+            return false;
+        }
+        p.expect(SyntaxKind::StringLiteral)
+    }
+
+    let checkpoint = p.checkpoint();
+    if !consume_literal(&mut *p) {
+        return;
+    }
+
+    if p.test(SyntaxKind::FatArrow) {
+        drop(p.start_node_at(checkpoint, SyntaxKind::TrContext));
+        if !consume_literal(&mut *p) {
+            return;
+        }
+    }
+
+    if p.peek().kind() == SyntaxKind::Pipe {
+        let mut p = p.start_node(SyntaxKind::TrPlural);
+        p.consume(); // "|"
+        if !consume_literal(&mut *p) {
+            return;
+        }
+        p.expect(SyntaxKind::Percent);
+        parse_expression_helper(&mut *p, OperatorPrecedence::Unary);
+    }
+
+    while p.test(SyntaxKind::Comma) {
+        if !parse_expression(&mut *p) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RParent);
+}
@@ -21,6 +21,8 @@
 mod generate_item_indices;
 mod infer_aliases_types;
 mod inlining;
+mod lower_accessibility;
+mod lower_drag_and_drop;
 mod lower_layout;
 mod lower_popups;
 mod lower_shadows;
@@ -34,6 +36,7 @@
 mod repeater_component;
 mod resolve_native_classes;
 mod resolving;
+mod shortcuts;
 mod transform_and_opacity;
 mod unique_id;
 mod visible;
@@ -76,6 +79,7 @@ pub async fn run_passes(
     check_public_api::check_public_api(doc, diag);
 
     collect_subcomponents::collect_subcomponents(root_component);
+    lower_drag_and_drop::lower_drag_and_drop(root_component, &doc.local_registry, diag);
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
     {
@@ -87,7 +91,9 @@ pub async fn run_passes(
             diag,
         );
         lower_states::lower_states(component, &doc.local_registry, diag);
+        shortcuts::handle_shortcuts(component, diag);
     }
+    shortcuts::check_shortcut_conflicts(root_component, diag);
 
     inlining::inline(doc, inlining::InlineSelection::InlineOnlyRequiredComponents);
     collect_subcomponents::collect_subcomponents(root_component);
@@ -127,6 +133,7 @@ pub async fn run_passes(
         // rely on the default geometry pass to size them correctly relative to the new root.
         default_geometry::default_geometry(component, diag);
         visible::handle_visible(component, &global_type_registry.borrow());
+        lower_accessibility::lower_accessibility_properties(component);
         materialize_fake_properties::materialize_fake_properties(component);
     }
     collect_globals::collect_globals(doc, diag);
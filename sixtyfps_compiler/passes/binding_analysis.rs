@@ -34,6 +34,7 @@ pub fn binding_analysis(doc: &Document, diag: &mut BuildDiagnostics) {
     mark_used_base_properties(component);
     propagate_is_set_on_aliases(component, &mut reverse_aliases);
     perform_binding_analysis(component, &reverse_aliases, diag);
+    check_change_callback_loops(component, diag);
 }
 
 /// A reference to a property which might be deep in a component path.
@@ -110,6 +111,8 @@ fn from(prop: NamedReference) -> Self {
 struct AnalysisContext {
     visited: HashSet<PropertyPath>,
     currently_analyzing: linked_hash_set::LinkedHashSet<PropertyPath>,
+    /// The functions whose body is being visited, to stop on recursive calls
+    currently_analyzing_functions: HashSet<PropertyPath>,
 }
 
 fn perform_binding_analysis(
@@ -146,6 +149,27 @@ fn analyze_element(
             diag,
         );
     }
+    for (name, callbacks) in &elem.borrow().change_callbacks {
+        process_property(
+            &PropertyPath::from(NamedReference::new(elem, name)),
+            context,
+            reverse_aliases,
+            diag,
+        );
+        for expr in callbacks.borrow().iter() {
+            recurse_expression(expr, &mut |prop| {
+                process_property(&PropertyPath::from(prop.clone()), context, reverse_aliases, diag);
+            });
+        }
+    }
+    for name in elem.borrow().functions.keys() {
+        analyse_function(
+            &PropertyPath::from(NamedReference::new(elem, name)),
+            context,
+            reverse_aliases,
+            diag,
+        );
+    }
 }
 
 #[derive(Copy, Clone, dm::BitAnd, dm::BitOr, dm::BitAndAssign, dm::BitOrAssign)]
@@ -252,6 +276,30 @@ fn analyse_binding(
     return depends_on_external;
 }
 
+/// Visit the properties used in the body of the function `current`, so that the bindings
+/// calling this function depend on them
+fn analyse_function(
+    current: &PropertyPath,
+    context: &mut AnalysisContext,
+    reverse_aliases: &ReverseAliases,
+    diag: &mut BuildDiagnostics,
+) -> DependsOnExternal {
+    let mut depends_on_external = DependsOnExternal(false);
+    if !context.currently_analyzing_functions.insert(current.clone()) {
+        // recursive call
+        return depends_on_external;
+    }
+    let element = current.prop.element();
+    let element = element.borrow();
+    let code = element.functions[current.prop.name()].code.borrow();
+    recurse_expression(&code, &mut |prop| {
+        depends_on_external |=
+            process_property(&current.relative(prop), context, reverse_aliases, diag);
+    });
+    context.currently_analyzing_functions.remove(current);
+    depends_on_external
+}
+
 /// Process the property `prop`
 ///
 /// This will visit all the bindings from that property
@@ -282,6 +330,8 @@ fn process_property(
         let element = prop.prop.element();
         if element.borrow().bindings.contains_key(prop.prop.name()) {
             analyse_binding(&prop, context, reverse_aliases, diag);
+        } else if element.borrow().functions.contains_key(prop.prop.name()) {
+            analyse_function(&prop, context, reverse_aliases, diag);
         }
         let next = if let Type::Component(base) = &element.borrow().base_type {
             if element.borrow().property_declarations.contains_key(prop.prop.name()) {
@@ -307,7 +357,9 @@ fn process_property(
 fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&NamedReference)) {
     expr.visit(|sub| recurse_expression(sub, vis));
     match expr {
-        Expression::PropertyReference(r) | Expression::CallbackReference(r) => vis(r),
+        Expression::PropertyReference(r)
+        | Expression::CallbackReference(r)
+        | Expression::FunctionReference(r) => vis(r),
         Expression::LayoutCacheAccess { layout_cache_prop, .. } => vis(layout_cache_prop),
         Expression::SolveLayout(l, o) | Expression::ComputeLayoutInfo(l, o) => {
             // we should only visit the layout geometry for the orientation
@@ -514,3 +566,150 @@ fn mark_used_base_properties(component: &Rc<Component>) {
         mark_used_base_properties(c);
     }
 }
+
+/// A property with a change callback, identified by the element that has the `changed` handler
+type ChangeCallbackKey = (ByAddress<ElementRc>, String);
+
+/// Report the change callbacks which, by assigning properties that have themselves a change
+/// callback, end up calling themselves again.
+fn check_change_callback_loops(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    let mut all_callbacks = vec![];
+    let mut collect = |component: &Rc<Component>| {
+        crate::object_tree::recurse_elem_including_sub_components_no_borrow(
+            component,
+            &(),
+            &mut |e, _| {
+                for name in e.borrow().change_callbacks.keys() {
+                    all_callbacks.push((ByAddress(e.clone()), name.clone()));
+                }
+            },
+        )
+    };
+    for c in &component.used_types.borrow().sub_components {
+        collect(c);
+    }
+    collect(component);
+
+    let mut done = HashSet::new();
+    let mut reported = HashSet::new();
+    for key in &all_callbacks {
+        visit_change_callback(key, &mut vec![], &mut done, &mut reported, diag);
+    }
+}
+
+fn visit_change_callback(
+    key: &ChangeCallbackKey,
+    stack: &mut Vec<ChangeCallbackKey>,
+    done: &mut HashSet<ChangeCallbackKey>,
+    reported: &mut HashSet<ChangeCallbackKey>,
+    diag: &mut BuildDiagnostics,
+) {
+    if let Some(pos) = stack.iter().position(|k| k == key) {
+        for (elem, name) in &stack[pos..] {
+            if !reported.insert((elem.clone(), name.clone())) {
+                continue;
+            }
+            let elem = elem.borrow();
+            let span = elem.node.as_ref().map(|n| {
+                n.PropertyChangedCallback()
+                    .map(|c| c.DeclaredIdentifier())
+                    .find(|id| crate::parser::identifier_text(id).as_ref() == Some(name))
+                    .map_or_else(|| n.to_source_location(), |id| id.to_source_location())
+            });
+            diag.push_error(
+                format!("The change callback on '{}' is part of a change callback loop", name),
+                &span,
+            );
+        }
+        return;
+    }
+    if done.contains(key) {
+        return;
+    }
+
+    let mut assigned = vec![];
+    let mut visited_calls = HashSet::new();
+    for expr in key.0.borrow().change_callbacks[&key.1].borrow().iter() {
+        collect_assigned_properties(expr, &mut assigned, &mut visited_calls);
+    }
+    stack.push(key.clone());
+    for nr in assigned {
+        if let Some(next) = change_callback_for_property(&nr) {
+            visit_change_callback(&next, stack, done, reported, diag);
+        }
+    }
+    stack.pop();
+    done.insert(key.clone());
+}
+
+/// Collect the properties assigned by `expr`, including the ones assigned by the functions
+/// and the callbacks that it calls
+fn collect_assigned_properties(
+    expr: &Expression,
+    assigned: &mut Vec<NamedReference>,
+    visited_calls: &mut HashSet<NamedReference>,
+) {
+    expr.visit(|sub| collect_assigned_properties(sub, assigned, visited_calls));
+    match expr {
+        Expression::SelfAssignment { lhs, .. } => {
+            if let Expression::PropertyReference(nr) = &**lhs {
+                assigned.push(nr.clone());
+            }
+        }
+        Expression::FunctionCall { function, .. } => {
+            if let Expression::FunctionReference(nr) | Expression::CallbackReference(nr) =
+                &**function
+            {
+                if visited_calls.insert(nr.clone()) {
+                    if let Some(code) = called_code(nr) {
+                        collect_assigned_properties(&code, assigned, visited_calls);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The code run when calling `nr`: the body of the function, or the handler of the callback
+/// if it is set in the component
+fn called_code(nr: &NamedReference) -> Option<Expression> {
+    let mut element = nr.element();
+    loop {
+        let next = {
+            let e = element.borrow();
+            if let Some(function) = e.functions.get(nr.name()) {
+                return Some(function.code.borrow().clone());
+            }
+            if let Some(binding) = e.bindings.get(nr.name()) {
+                return Some(binding.borrow().expression.clone());
+            }
+            match &e.base_type {
+                Type::Component(base) if !e.property_declarations.contains_key(nr.name()) => {
+                    base.root_element.clone()
+                }
+                _ => return None,
+            }
+        };
+        element = next;
+    }
+}
+
+/// Find the element holding the change callback for this property, looking into the base components
+fn change_callback_for_property(nr: &NamedReference) -> Option<ChangeCallbackKey> {
+    let mut element = nr.element();
+    loop {
+        if element.borrow().change_callbacks.contains_key(nr.name()) {
+            return Some((ByAddress(element), nr.name().into()));
+        }
+        let next = match &element.borrow().base_type {
+            Type::Component(base)
+                if !element.borrow().property_declarations.contains_key(nr.name()) =>
+            {
+                base.root_element.clone()
+            }
+            _ => return None,
+        };
+        element = next;
+    }
+}
@@ -13,7 +13,9 @@
 
 /// Fill the root_component's used_types.globals
 pub fn collect_globals(doc: &Document, _diag: &mut BuildDiagnostics) {
-    doc.root_component.used_types.borrow_mut().globals.clear();
+    // Keep the previous globals alive while visiting, as the globals created by earlier passes are
+    // only owned by this list
+    let _previous_globals = std::mem::take(&mut doc.root_component.used_types.borrow_mut().globals);
     let mut set = HashSet::new();
     for (_, ty) in doc.exports() {
         if let Type::Component(c) = ty {
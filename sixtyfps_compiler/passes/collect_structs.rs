@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@sixtyfps.io>
 // SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
 
-//! Passes that fills the root component used_types.structs
+//! Passes that fills the root component used_types.structs and used_types.enums
 
 use crate::expression_tree::Expression;
 use crate::langtype::Type;
@@ -9,7 +9,7 @@
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
-/// Fill the root_component's used_types.structs
+/// Fill the root_component's used_types.structs and used_types.enums
 pub fn collect_structs(doc: &Document) {
     let mut hash = BTreeMap::new();
 
@@ -27,6 +27,18 @@ pub fn collect_structs(doc: &Document) {
         let key = next.0.clone();
         sort_struct(&mut hash, used_struct, &key);
     }
+
+    // The enums declared in .60 files were collected along with the structs, but they have
+    // no dependencies so they are simply moved to their own list
+    let mut enums = vec![];
+    used_struct.retain(|ty| match ty {
+        Type::Enumeration(e) => {
+            enums.push(e.clone());
+            false
+        }
+        _ => true,
+    });
+    used_types.enums = enums;
 }
 
 fn collect_structs_in_component(root_component: &Rc<Component>, hash: &mut BTreeMap<String, Type>) {
@@ -40,13 +52,18 @@ fn collect_structs_in_component(root_component: &Rc<Component>, hash: &mut BTree
         for x in elem.borrow().property_declarations.values() {
             maybe_collect_object(&x.property_type);
         }
+        for f in elem.borrow().functions.values() {
+            maybe_collect_object(&f.ty());
+        }
     });
 
     visit_all_expressions(root_component, |expr, _| {
-        expr.visit_recursive(&mut |expr| {
-            if let Expression::Struct { ty, .. } = expr {
-                maybe_collect_object(ty)
+        expr.visit_recursive(&mut |expr| match expr {
+            Expression::Struct { ty, .. } => maybe_collect_object(ty),
+            Expression::EnumerationValue(value) => {
+                maybe_collect_object(&Type::Enumeration(value.enumeration.clone()))
             }
+            _ => {}
         })
     });
 }
@@ -79,6 +96,7 @@ fn visit_named_object(ty: &Type, visitor: &mut impl FnMut(&String, &Type)) {
                 visit_named_object(sub_ty, visitor);
             }
         }
+        Type::Enumeration(e) if e.node.is_some() => visitor(&e.name, ty),
         Type::Array(x) => visit_named_object(x, visitor),
         Type::Callback { return_type, args } => {
             if let Some(rt) = return_type {
@@ -88,6 +106,12 @@ fn visit_named_object(ty: &Type, visitor: &mut impl FnMut(&String, &Type)) {
                 visit_named_object(a, visitor);
             }
         }
+        Type::Function { return_type, args } => {
+            visit_named_object(return_type, visitor);
+            for a in args {
+                visit_named_object(a, visitor);
+            }
+        }
         _ => {}
     }
 }
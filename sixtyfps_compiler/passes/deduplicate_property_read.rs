@@ -11,8 +11,8 @@
 
 pub fn deduplicate_property_read(component: &Component) {
     visit_all_expressions(component, |expr, ty| {
-        if matches!(ty(), Type::Callback { .. }) {
-            // Callback handler can't be optimizes because they can have side effect.
+        if matches!(ty(), Type::Callback { .. } | Type::Function { .. }) {
+            // Callback handler and functions can't be optimizes because they can have side effect.
             // But that's fine as they also do not register dependencies
             return;
         }
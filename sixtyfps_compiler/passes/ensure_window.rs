@@ -5,7 +5,7 @@
 
 use crate::expression_tree::{BindingExpression, Expression};
 use crate::namedreference::NamedReference;
-use crate::object_tree::{Component, Element};
+use crate::object_tree::{Component, Element, ElementRc};
 use crate::typeregister::TypeRegister;
 use std::cell::RefCell;
 use std::collections::HashSet;
@@ -19,6 +19,7 @@ pub fn ensure_window(
     if component.root_element.borrow().builtin_type().map_or(true, |b| {
         matches!(b.name.as_str(), "Window" | "Dialog" | "WindowItem" | "PopupWindow")
     }) {
+        mark_title_set_externally(&component.root_element);
         return; // already a window, nothing to do
     }
 
@@ -32,6 +33,8 @@ pub fn ensure_window(
         id: std::mem::replace(&mut win_elem_mut.id, "root_window".into()),
         base_type: std::mem::replace(&mut win_elem_mut.base_type, window_type),
         bindings: Default::default(),
+        change_callbacks: Default::default(),
+        functions: Default::default(),
         property_analysis: Default::default(),
         children: std::mem::take(&mut win_elem_mut.children),
         enclosing_component: win_elem_mut.enclosing_component.clone(),
@@ -42,6 +45,8 @@ pub fn ensure_window(
         transitions: Default::default(),
         child_of_layout: false,
         layout_info_prop: Default::default(),
+        accessibility_props: Default::default(),
+        element_ids: Default::default(),
         is_flickable_viewport: false,
         item_index: Default::default(),
         item_index_of_first_children: Default::default(),
@@ -65,6 +70,7 @@ pub fn ensure_window(
     let mut base_props: HashSet<String> =
         new_root.borrow().base_type.property_list().into_iter().map(|x| x.0).collect();
     base_props.extend(win_elem.borrow().bindings.keys().cloned());
+    base_props.extend(win_elem.borrow().change_callbacks.keys().cloned());
     for prop in base_props {
         if prop == "width" || prop == "height" {
             continue;
@@ -79,6 +85,9 @@ pub fn ensure_window(
         if let Some(b) = win_elem.borrow_mut().bindings.remove(&prop) {
             new_root.borrow_mut().bindings.insert(prop.clone(), b);
         }
+        if let Some(c) = win_elem.borrow_mut().change_callbacks.remove(&prop) {
+            new_root.borrow_mut().change_callbacks.insert(prop.clone(), c);
+        }
         if let Some(a) = win_elem.borrow().property_analysis.borrow_mut().remove(&prop) {
             new_root.borrow().property_analysis.borrow_mut().insert(prop.clone(), a);
         }
@@ -100,4 +109,17 @@ pub fn ensure_window(
             .into(),
         ),
     );
+    mark_title_set_externally(&component.root_element);
+}
+
+/// The title of the window can be changed at runtime with `Window::set_title`
+fn mark_title_set_externally(window: &ElementRc) {
+    window
+        .borrow()
+        .property_analysis
+        .borrow_mut()
+        .entry("title".into())
+        .or_default()
+        .is_set_externally = true;
+    crate::namedreference::mark_property_set_derived_in_base(window.clone(), "title");
 }
@@ -158,6 +158,19 @@ fn inline_element(
         }
     }
 
+    for (k, val) in inlined_component.root_element.borrow().change_callbacks.iter() {
+        elem_mut
+            .change_callbacks
+            .entry(k.clone())
+            .or_default()
+            .get_mut()
+            .extend(val.borrow().iter().cloned());
+    }
+
+    elem_mut
+        .functions
+        .extend(inlined_component.root_element.borrow().functions.iter().map(clone_tuple));
+
     if let Some(orig) = &inlined_component.root_element.borrow().layout_info_prop {
         if let Some(_new) = &mut elem_mut.layout_info_prop {
             todo!("Merge layout infos");
@@ -166,6 +179,13 @@ fn inline_element(
         }
     }
 
+    for (k, nr) in inlined_component.root_element.borrow().accessibility_props.iter() {
+        elem_mut.accessibility_props.entry(k.clone()).or_insert_with(|| nr.clone());
+    }
+    elem_mut
+        .element_ids
+        .extend(inlined_component.root_element.borrow().element_ids.iter().cloned());
+
     core::mem::drop(elem_mut);
 
     // Now fixup all binding and reference
@@ -196,6 +216,8 @@ fn duplicate_element_with_mapping(
             .iter()
             .map(|b| duplicate_binding(b, mapping, root_component))
             .collect(),
+        change_callbacks: elem.change_callbacks.clone(),
+        functions: elem.functions.clone(),
         property_analysis: elem.property_analysis.clone(),
         children: elem
             .children
@@ -213,6 +235,8 @@ fn duplicate_element_with_mapping(
             .collect(),
         child_of_layout: elem.child_of_layout,
         layout_info_prop: elem.layout_info_prop.clone(),
+        accessibility_props: elem.accessibility_props.clone(),
+        element_ids: elem.element_ids.clone(),
         named_references: Default::default(),
         item_index: Default::default(), // Not determined yet
         item_index_of_first_children: Default::default(),
@@ -300,6 +324,7 @@ fn duplicate_popup(
             .get(&element_key(p.parent_element.clone()))
             .expect("Parent element must be in the mapping")
             .clone(),
+        is_child_window: p.is_child_window,
     }
 }
 
@@ -428,6 +453,10 @@ fn component_requires_inlining(component: &Rc<Component>) -> bool {
     }
 
     let root_element = &component.root_element;
+    if super::lower_popups::is_window_element(root_element) {
+        // So that the lower_popups pass turns the element into a child window
+        return true;
+    }
     if super::flickable::is_flickable_element(root_element)
         || super::focus_item::get_explicit_forward_focus(root_element).is_some()
         || super::lower_layout::is_layout_element(root_element)
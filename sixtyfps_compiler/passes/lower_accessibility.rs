@@ -0,0 +1,47 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+//! Pass that collects the `accessible-*` properties of the elements so that the
+//! generators can expose them in the accessibility tree.
+//!
+//! Must be run before materialize_fake_properties
+
+use crate::expression_tree::{Expression, NamedReference};
+use crate::langtype::Type;
+use crate::object_tree::*;
+use crate::typeregister::{ACCESSIBLE_ROLE_ENUM, RESERVED_ACCESSIBILITY_PROPERTIES};
+use std::rc::Rc;
+
+pub fn lower_accessibility_properties(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        if elem.borrow().repeated.is_some() {
+            // The properties are on the root element of the repeated component
+            return;
+        }
+        apply_builtin_defaults(elem);
+
+        let accessibility_props = std::iter::once("accessible-role")
+            .chain(RESERVED_ACCESSIBILITY_PROPERTIES.iter().map(|(name, _)| *name))
+            .filter(|prop| elem.borrow().bindings.contains_key(*prop))
+            .map(|prop| (prop.to_owned(), NamedReference::new(elem, prop)))
+            .collect::<Vec<_>>();
+        elem.borrow_mut().accessibility_props.extend(accessibility_props);
+    })
+}
+
+/// Builtin elements that have a meaning for assistive technologies get a default role and label
+fn apply_builtin_defaults(elem: &ElementRc) {
+    let (role, label_prop) = match &elem.borrow().base_type {
+        Type::Builtin(b) => match b.name.as_str() {
+            "Text" => ("text", "text"),
+            "Window" | "Dialog" => ("window", "title"),
+            _ => return,
+        },
+        _ => return,
+    };
+    let role = ACCESSIBLE_ROLE_ENUM.with(|e| e.clone().try_value_from_string(role).unwrap());
+    let label = NamedReference::new(elem, label_prop);
+    let mut elem = elem.borrow_mut();
+    elem.set_binding_if_not_set("accessible-role".into(), || Expression::EnumerationValue(role));
+    elem.set_binding_if_not_set("accessible-label".into(), || Expression::PropertyReference(label));
+}
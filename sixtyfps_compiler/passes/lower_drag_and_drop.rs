@@ -0,0 +1,197 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+//! Pass that lowers the `image` of the `DragArea` elements to an `Image` child, and the typed
+//! payloads of the `DragArea` and `DropArea` elements to a global.
+//!
+//! A typed payload is a `payload` property declared on a `DragArea` and on a `DropArea`. When a
+//! drag starts, the `payload` of the `DragArea` is copied to the global, together with an id for its
+//! type. A `DropArea` only accepts the drags of a payload of the same type as its own `payload`,
+//! which is bound to the payload in the global.
+//!
+//! Must be run before inlining, on the root component once the sub components are collected.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression, NamedReference, Unit};
+use crate::langtype::Type;
+use crate::object_tree::*;
+use crate::typeregister::TypeRegister;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub fn lower_drag_and_drop(
+    root_component: &Rc<Component>,
+    type_register: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) {
+    let mut drag_areas = Vec::new();
+    let mut drop_areas = Vec::new();
+    for component in (root_component.used_types.borrow().sub_components.iter())
+        .chain(std::iter::once(root_component))
+    {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            let builtin_name = match &elem.borrow().base_type {
+                Type::Builtin(b) => b.name.clone(),
+                _ => return,
+            };
+            if builtin_name == "DragArea" {
+                lower_drag_image(elem, type_register);
+                drag_areas.push(elem.clone());
+            } else if builtin_name == "DropArea" {
+                drop_areas.push(elem.clone());
+            }
+        });
+    }
+
+    let payload_type = |elem: &ElementRc| {
+        elem.borrow().property_declarations.get("payload").map(|decl| decl.property_type.clone())
+    };
+    if !drag_areas.iter().chain(drop_areas.iter()).any(|elem| payload_type(elem).is_some()) {
+        return;
+    }
+
+    let global = create_payload_global();
+    root_component.used_types.borrow_mut().globals.push(global.clone());
+    // The id of a payload type is its index in this list, plus one: 0 is a drag without payload.
+    let mut payload_types: Vec<Type> = Vec::new();
+    let mut payload_id = |ty: Type| {
+        let index = payload_types.iter().position(|t| *t == ty).unwrap_or_else(|| {
+            global.root_element.borrow_mut().property_declarations.insert(
+                format!("payload-{}", payload_types.len() + 1),
+                PropertyDeclaration { property_type: ty.clone(), ..PropertyDeclaration::default() },
+            );
+            payload_types.push(ty);
+            payload_types.len() - 1
+        });
+        index + 1
+    };
+    let global_property = |name: String| {
+        Expression::PropertyReference(NamedReference::new(&global.root_element, &name))
+    };
+    let assign_global_property = |name: String, rhs: Expression| {
+        let nr = NamedReference::new(&global.root_element, &name);
+        nr.mark_as_set();
+        Expression::SelfAssignment {
+            lhs: Box::new(Expression::PropertyReference(nr)),
+            rhs: Box::new(rhs),
+            op: '=',
+        }
+    };
+    let type_id_literal = |id: usize| Expression::Cast {
+        from: Box::new(Expression::NumberLiteral(id as _, Unit::None)),
+        to: Type::Int32,
+    };
+
+    for drag_area in drag_areas {
+        let id = payload_type(&drag_area).map_or(0, &mut payload_id);
+        let mut code = vec![assign_global_property("payload-type".into(), type_id_literal(id))];
+        if id > 0 {
+            code.push(assign_global_property(
+                format!("payload-{}", id),
+                Expression::PropertyReference(NamedReference::new(&drag_area, "payload")),
+            ));
+        }
+        let mut drag_area = drag_area.borrow_mut();
+        match drag_area.bindings.get("started") {
+            Some(binding) => {
+                let mut binding = binding.borrow_mut();
+                code.push(std::mem::replace(&mut binding.expression, Expression::Invalid));
+                binding.expression = Expression::CodeBlock(code);
+            }
+            None => {
+                drag_area
+                    .bindings
+                    .insert("started".into(), RefCell::new(Expression::CodeBlock(code).into()));
+            }
+        }
+    }
+
+    for drop_area in drop_areas {
+        let id = match payload_type(&drop_area) {
+            Some(ty) => payload_id(ty),
+            None => continue,
+        };
+        if let Some(binding) = drop_area.borrow().bindings.get("payload") {
+            diag.push_error(
+                "The payload of a DropArea is set by the dragged DragArea and cannot have a binding"
+                    .into(),
+                &*binding.borrow(),
+            );
+            continue;
+        }
+        let same_type = Expression::BinaryExpression {
+            lhs: Box::new(global_property("payload-type".into())),
+            rhs: Box::new(type_id_literal(id)),
+            op: '=',
+        };
+        let mut drop_area = drop_area.borrow_mut();
+        drop_area.bindings.insert(
+            "payload".into(),
+            RefCell::new(global_property(format!("payload-{}", id)).into()),
+        );
+        match drop_area.bindings.get("can-drop") {
+            Some(binding) => {
+                let mut binding = binding.borrow_mut();
+                let can_drop = std::mem::replace(&mut binding.expression, Expression::Invalid);
+                binding.expression = Expression::Condition {
+                    condition: Box::new(same_type),
+                    true_expr: Box::new(can_drop),
+                    false_expr: Box::new(Expression::BoolLiteral(false)),
+                };
+            }
+            None => {
+                drop_area.bindings.insert("can-drop".into(), RefCell::new(same_type.into()));
+            }
+        }
+    }
+}
+
+/// Creates the global that holds the payload of the current drag, in a property for each payload
+/// type, and the id of that type in its `payload-type` property.
+fn create_payload_global() -> Rc<Component> {
+    let root_element = Element {
+        id: "DragPayload".into(),
+        base_type: Type::Void,
+        property_declarations: [(
+            "payload-type".to_owned(),
+            PropertyDeclaration { property_type: Type::Int32, ..PropertyDeclaration::default() },
+        )]
+        .into_iter()
+        .collect(),
+        ..Element::default()
+    };
+    let global = Rc::new(Component {
+        id: "DragPayload".into(),
+        root_element: ElementRc::new(RefCell::new(root_element)),
+        ..Component::default()
+    });
+    global.root_element.borrow_mut().enclosing_component = Rc::downgrade(&global);
+    global
+}
+
+/// Adds an `Image` child showing the `image` of the `DragArea`, while dragging. Like the other
+/// children of the `DragArea`, it follows the mouse.
+fn lower_drag_image(drag_area: &ElementRc, type_register: &TypeRegister) {
+    if !drag_area.borrow().bindings.contains_key("image") {
+        return;
+    }
+    let image = Element {
+        id: format!("{}-drag-image", drag_area.borrow().id),
+        base_type: type_register.lookup_element("Image").unwrap(),
+        enclosing_component: drag_area.borrow().enclosing_component.clone(),
+        bindings: [
+            ("source", NamedReference::new(drag_area, "image")),
+            ("visible", NamedReference::new(drag_area, "dragging")),
+        ]
+        .into_iter()
+        .map(|(name, nr)| {
+            (
+                name.to_owned(),
+                RefCell::new(BindingExpression::from(Expression::PropertyReference(nr))),
+            )
+        })
+        .collect(),
+        ..Element::default()
+    };
+    drag_area.borrow_mut().children.push(ElementRc::new(RefCell::new(image)));
+}
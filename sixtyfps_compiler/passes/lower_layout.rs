@@ -669,6 +669,13 @@ fn create_layout_item(
     item_element: &ElementRc,
     diag: &mut BuildDiagnostics,
 ) -> Option<CreateLayoutItemResult> {
+    let has_no_geometry = matches!(&item_element.borrow().base_type,
+        Type::Builtin(b) if b.name == "Shortcut" || b.name == "TimerItem");
+    if has_no_geometry {
+        // Shortcuts and timers have no geometry and don't take space in the layout
+        return None;
+    }
+
     let fix_explicit_percent = |prop: &str, item: &ElementRc| {
         if !item.borrow().bindings.get(prop).map_or(false, |b| b.borrow().ty() == Type::Percent) {
             return;
@@ -1,10 +1,11 @@
 // Copyright © SixtyFPS GmbH <info@sixtyfps.io>
 // SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
 
-//! Passe that transform the PopupWindow element into a component
+//! Passe that transform the PopupWindow element, and the Window elements that are not the root
+//! of their component, into a component
 
 use crate::diagnostics::BuildDiagnostics;
-use crate::expression_tree::{Expression, NamedReference};
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference};
 use crate::langtype::Type;
 use crate::object_tree::*;
 use crate::typeregister::TypeRegister;
@@ -24,17 +25,32 @@ pub fn lower_popups(
         &mut |elem, parent_element: &Option<ElementRc>| {
             let is_popup = elem.borrow().base_type.to_string() == "PopupWindow";
             if is_popup {
-                lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
+                lower_popup_window(elem, parent_element.as_ref(), Some(&window_type), diag);
+            } else if parent_element.is_some() && is_window_element(elem) && !is_root(elem) {
+                lower_popup_window(elem, parent_element.as_ref(), None, diag);
             }
             Some(elem.clone())
         },
-    )
+    );
+    check_child_window_calls(component, diag);
 }
 
+/// Returns true if the element is a `Window` or a `Dialog`
+pub fn is_window_element(elem: &ElementRc) -> bool {
+    matches!(&elem.borrow().base_type, Type::Builtin(b) if matches!(b.name.as_str(), "Window" | "Dialog"))
+}
+
+fn is_root(elem: &ElementRc) -> bool {
+    let component = elem.borrow().enclosing_component.upgrade().unwrap();
+    Rc::ptr_eq(&component.root_element, elem)
+}
+
+/// Moves the element into its own component. `window_type` is the type given to a PopupWindow,
+/// and is None for a child Window, which keeps its type.
 fn lower_popup_window(
     popup_window_element: &ElementRc,
     parent_element: Option<&ElementRc>,
-    window_type: &Type,
+    window_type: Option<&Type>,
     diag: &mut BuildDiagnostics,
 ) {
     let parent_element = match parent_element {
@@ -53,7 +69,9 @@ fn lower_popup_window(
     // Remove the popup_window_element from its parent
     parent_element.borrow_mut().children.retain(|child| !Rc::ptr_eq(child, popup_window_element));
 
-    popup_window_element.borrow_mut().base_type = window_type.clone();
+    if let Some(window_type) = window_type {
+        popup_window_element.borrow_mut().base_type = window_type.clone();
+    }
 
     let popup_comp = Rc::new(Component {
         root_element: popup_window_element.clone(),
@@ -79,7 +97,10 @@ fn lower_popup_window(
     visit_all_named_references(&parent_component, &mut |nr| {
         if std::rc::Weak::ptr_eq(&nr.element().borrow().enclosing_component, &weak) {
             diag.push_error(
-                "Cannot access the inside of a PopupWindow from enclosing component".into(),
+                format!(
+                    "Cannot access the inside of a {} from enclosing component",
+                    if window_type.is_some() { "PopupWindow" } else { "Window" }
+                ),
                 &*popup_window_element.borrow(),
             );
             // just set it to whatever is a valid NamedReference, otherwise we'll panic later
@@ -92,9 +113,69 @@ fn lower_popup_window(
         x: coord_x,
         y: coord_y,
         parent_element: parent_element.clone(),
+        is_child_window: window_type.is_none(),
     });
 }
 
+/// Reports the calls to `show()` and `hide()` on a Window that is not a child window, and the
+/// calls to `show()` from another component than the one that contains the Window.
+fn check_child_window_calls(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let calling_component = elem.borrow().enclosing_component.upgrade().unwrap();
+        visit_element_expressions(elem, |expr, _, _| {
+            expr.visit_recursive(&mut |expr| {
+                let (function, window, source_location) = match expr {
+                    Expression::FunctionCall { function, arguments, source_location } => {
+                        match (&**function, arguments.as_slice()) {
+                            (
+                                Expression::BuiltinFunctionReference(
+                                    f @ (BuiltinFunction::ShowChildWindow
+                                    | BuiltinFunction::HideChildWindow),
+                                    _,
+                                ),
+                                [Expression::ElementReference(window)],
+                            ) => (f, window.upgrade().unwrap(), source_location),
+                            _ => return,
+                        }
+                    }
+                    _ => return,
+                };
+                let window_component = window.borrow().enclosing_component.upgrade().unwrap();
+                let parent_component = window_component
+                    .parent_element
+                    .upgrade()
+                    .and_then(|p| p.borrow().enclosing_component.upgrade());
+                let parent_component = match parent_component {
+                    Some(parent_component)
+                        if is_root(&window)
+                            && parent_component.popup_windows.borrow().iter().any(|p| {
+                                p.is_child_window && Rc::ptr_eq(&p.component, &window_component)
+                            }) =>
+                    {
+                        parent_component
+                    }
+                    _ => {
+                        diag.push_error(
+                            "Only a Window declared inside of another element can be shown or hidden"
+                                .into(),
+                            source_location,
+                        );
+                        return;
+                    }
+                };
+                if matches!(function, BuiltinFunction::ShowChildWindow)
+                    && !Rc::ptr_eq(&parent_component, &calling_component)
+                {
+                    diag.push_error(
+                        "A Window can only be shown from the component that contains it".into(),
+                        source_location,
+                    );
+                }
+            })
+        });
+    })
+}
+
 fn create_coordinate(
     popup_comp: &Rc<Component>,
     parent_element: &ElementRc,
@@ -28,6 +28,7 @@ pub fn move_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics)
     component.popup_windows.borrow().iter().for_each(|f| move_declarations(&f.component, diag));
 
     let mut new_root_bindings = HashMap::new();
+    let mut new_root_change_callbacks = HashMap::new();
     let mut new_root_property_analysis = HashMap::new();
 
     let move_bindings_and_animations = &mut |elem: &ElementRc| {
@@ -59,6 +60,18 @@ pub fn move_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics)
         }
         elem.borrow_mut().bindings = new_bindings;
 
+        let change_callbacks = core::mem::take(&mut elem.borrow_mut().change_callbacks);
+        let mut new_change_callbacks = BTreeMap::new();
+        for (k, e) in change_callbacks {
+            let will_be_moved = elem.borrow().property_declarations.contains_key(&k);
+            if will_be_moved {
+                new_root_change_callbacks.insert(map_name(elem, k.as_str()), e);
+            } else {
+                new_change_callbacks.insert(k, e);
+            }
+        }
+        elem.borrow_mut().change_callbacks = new_change_callbacks;
+
         let property_analysis = elem.borrow().property_analysis.take();
         let mut new_property_analysis = HashMap::with_capacity(property_analysis.len());
         for (prop, a) in property_analysis {
@@ -100,6 +113,7 @@ pub fn move_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics)
         let mut r = component.root_element.borrow_mut();
         r.property_declarations = decl.property_declarations;
         r.bindings.extend(new_root_bindings.into_iter());
+        r.change_callbacks.extend(new_root_change_callbacks.into_iter());
         r.property_analysis.borrow_mut().extend(new_root_property_analysis.into_iter());
     }
 
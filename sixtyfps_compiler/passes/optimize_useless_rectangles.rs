@@ -13,22 +13,23 @@ pub fn optimize_useless_rectangles(root_component: &Rc<Component>) {
     recurse_elem_including_sub_components(root_component, &(), &mut |parent, _| {
         let mut parent = parent.borrow_mut();
         let children = std::mem::take(&mut parent.children);
+        let component = parent.enclosing_component.upgrade().unwrap();
 
         for elem in children {
-            if !can_optimize(&elem) {
+            // The popups and child windows keep a reference to their parent element
+            let is_popup_parent = component
+                .popup_windows
+                .borrow()
+                .iter()
+                .any(|p| Rc::ptr_eq(&p.parent_element, &elem));
+            if is_popup_parent || !can_optimize(&elem) {
                 parent.children.push(elem);
                 continue;
             }
 
             parent.children.extend(std::mem::take(&mut elem.borrow_mut().children));
 
-            parent
-                .enclosing_component
-                .upgrade()
-                .unwrap()
-                .optimized_elements
-                .borrow_mut()
-                .push(elem);
+            component.optimized_elements.borrow_mut().push(elem);
         }
     });
 }
@@ -36,7 +37,11 @@ pub fn optimize_useless_rectangles(root_component: &Rc<Component>) {
 /// Check that this is a element we can optimize
 fn can_optimize(elem: &ElementRc) -> bool {
     let e = elem.borrow();
-    if e.is_flickable_viewport || e.child_of_layout {
+    if e.is_flickable_viewport
+        || e.child_of_layout
+        || !e.accessibility_props.is_empty()
+        || !e.element_ids.is_empty()
+    {
         return false;
     };
 
@@ -161,8 +161,10 @@ pub fn remove_aliases(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
                 .borrow()
                 .get(remove.name())
                 .map_or(false, |v| v.is_read_externally || v.is_set_externally);
+            // The change callbacks are looked up by name, so the declaration must stay
+            let has_change_callback = elem.change_callbacks.contains_key(remove.name());
             if let Some(d) = elem.property_declarations.get_mut(remove.name()) {
-                if d.expose_in_public_api || used_externally {
+                if d.expose_in_public_api || used_externally || has_change_callback {
                     d.is_alias = Some(to.clone());
                     drop(elem);
                     // one must mark the aliased property as settable from outside
@@ -30,6 +30,8 @@ fn create_repeater_components(component: &Rc<Component>) {
                 id: elem.id.clone(),
                 base_type: std::mem::take(&mut elem.base_type),
                 bindings: std::mem::take(&mut elem.bindings),
+                change_callbacks: std::mem::take(&mut elem.change_callbacks),
+                functions: std::mem::take(&mut elem.functions),
                 property_analysis: std::mem::take(&mut elem.property_analysis),
                 children: std::mem::take(&mut elem.children),
                 property_declarations: std::mem::take(&mut elem.property_declarations),
@@ -41,6 +43,8 @@ fn create_repeater_components(component: &Rc<Component>) {
                 transitions: std::mem::take(&mut elem.transitions),
                 child_of_layout: elem.child_of_layout || is_listview.is_some(),
                 layout_info_prop: elem.layout_info_prop.take(),
+                accessibility_props: std::mem::take(&mut elem.accessibility_props),
+                element_ids: std::mem::take(&mut elem.element_ids),
                 is_flickable_viewport: elem.is_flickable_viewport,
                 item_index: Default::default(), // Not determined yet
                 item_index_of_first_children: Default::default(),
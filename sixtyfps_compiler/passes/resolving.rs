@@ -50,6 +50,10 @@ fn resolve_expression(
                 //FIXME: proper callback support (node is a codeblock)
                 Expression::from_callback_connection(node.clone().into(), &mut lookup_ctx)
             }
+            SyntaxKind::Function => Expression::from_function(node.clone().into(), &mut lookup_ctx),
+            SyntaxKind::PropertyChangedCallback => {
+                Expression::from_property_changed_callback(node.clone().into(), &mut lookup_ctx)
+            }
             SyntaxKind::Expression => {
                 //FIXME again: this happen for non-binding expression (i.e: model)
                 Expression::from_expression_node(node.clone().into(), &mut lookup_ctx)
@@ -58,6 +62,9 @@ fn resolve_expression(
             SyntaxKind::BindingExpression => {
                 Expression::from_binding_expression_node(node.clone(), &mut lookup_ctx)
             }
+            SyntaxKind::Keyframes => {
+                Expression::from_keyframes_node(node.clone().into(), &mut lookup_ctx)
+            }
             SyntaxKind::TwoWayBinding => {
                 if lookup_ctx.property_type == Type::Invalid {
                     // An attempt to resolve this already failed when trying to resolve the property type
@@ -166,6 +173,57 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
         e.maybe_convert_to(ctx.property_type.clone(), &node, ctx.diag)
     }
 
+    /// The `keyframes: [...]` of an animation, which is an array of struct with a `position`
+    /// between 0 and 1, and a `value` of the type of the animated property
+    fn from_keyframes_node(node: syntax_nodes::Keyframes, ctx: &mut LookupCtx) -> Expression {
+        let element_ty = match &ctx.property_type {
+            Type::Array(element_ty) => (**element_ty).clone(),
+            _ => unreachable!("keyframes must have an array type"),
+        };
+        let value_ty = match &element_ty {
+            Type::Struct { fields, .. } => fields["value"].clone(),
+            _ => unreachable!("keyframes must be an array of struct"),
+        };
+        let mut last_position = 0.;
+        let values = node
+            .Keyframe()
+            .filter_map(|keyframe| {
+                let (position_n, value_n) = keyframe.Expression();
+                let position = match Self::from_expression_node(position_n.clone(), ctx) {
+                    Expression::NumberLiteral(p, Unit::Percent) if (0. ..=100.).contains(&p) => {
+                        p / 100.
+                    }
+                    _ => {
+                        ctx.diag.push_error(
+                            "The position of a keyframe must be a percentage between 0% and 100%"
+                                .into(),
+                            &position_n,
+                        );
+                        return None;
+                    }
+                };
+                if position < last_position {
+                    ctx.diag.push_error("Keyframes must be sorted by position".into(), &position_n);
+                }
+                last_position = position;
+                let value = Self::from_expression_node(value_n.clone(), ctx).maybe_convert_to(
+                    value_ty.clone(),
+                    &value_n,
+                    ctx.diag,
+                );
+                Some(Expression::Struct {
+                    ty: element_ty.clone(),
+                    values: IntoIterator::into_iter([
+                        ("position".to_string(), Expression::NumberLiteral(position, Unit::None)),
+                        ("value".to_string(), value),
+                    ])
+                    .collect(),
+                })
+            })
+            .collect();
+        Expression::Array { element_ty, values }
+    }
+
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
@@ -232,11 +290,36 @@ fn from_callback_connection(
         )
     }
 
+    fn from_property_changed_callback(
+        node: syntax_nodes::PropertyChangedCallback,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        Self::from_codeblock_node(node.CodeBlock(), ctx).maybe_convert_to(
+            Type::Void,
+            &node,
+            ctx.diag,
+        )
+    }
+
+    fn from_function(node: syntax_nodes::Function, ctx: &mut LookupCtx) -> Expression {
+        ctx.arguments = node
+            .ArgumentDeclaration()
+            .map(|x| identifier_text(&x.DeclaredIdentifier()).unwrap_or_default())
+            .collect();
+        Self::from_codeblock_node(node.CodeBlock(), ctx).maybe_convert_to(
+            ctx.return_type().clone(),
+            &node,
+            ctx.diag,
+        )
+    }
+
     fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) -> Self {
         node.Expression()
             .map(|n| Self::from_expression_node(n, ctx))
             .or_else(|| node.AtImageUrl().map(|n| Self::from_at_image_url_node(n, ctx)))
             .or_else(|| node.AtLinearGradient().map(|n| Self::from_at_linear_gradient(n, ctx)))
+            .or_else(|| node.AtRadialGradient().map(|n| Self::from_at_radial_gradient(n, ctx)))
+            .or_else(|| node.AtTr().map(|n| Self::from_at_tr(n, ctx)))
             .or_else(|| {
                 node.QualifiedName().map(|n| {
                     let exp = Self::from_qualified_name_node(n.clone(), ctx);
@@ -371,6 +454,43 @@ fn from_at_linear_gradient(node: syntax_nodes::AtLinearGradient, ctx: &mut Looku
                 ctx.diag,
             ));
 
+        let stops = Self::from_gradient_stops(subs, ctx);
+        Expression::LinearGradient { angle, stops }
+    }
+
+    fn from_at_radial_gradient(node: syntax_nodes::AtRadialGradient, ctx: &mut LookupCtx) -> Self {
+        let mut subs = node
+            .children_with_tokens()
+            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+        let is_circle = match subs.next() {
+            Some(e) if e.kind() == SyntaxKind::Expression => {
+                e.as_node().unwrap().text().to_string().trim() == "circle"
+            }
+            _ => false,
+        };
+        if !is_circle {
+            ctx.diag.push_error(
+                "Expected 'circle': currently, only @radial-gradient(circle, ...) are supported"
+                    .into(),
+                &node,
+            );
+            return Expression::Invalid;
+        }
+        if subs.next().map_or(false, |s| s.kind() != SyntaxKind::Comma) {
+            ctx.diag.push_error("'circle' must be followed by a comma".into(), &node);
+            return Expression::Invalid;
+        }
+
+        let stops = Self::from_gradient_stops(subs, ctx);
+        Expression::RadialGradient { stops }
+    }
+
+    /// Resolves the color stops of a gradient, given the remaining comma and expression
+    /// children of the node
+    fn from_gradient_stops(
+        subs: impl Iterator<Item = NodeOrToken>,
+        ctx: &mut LookupCtx,
+    ) -> Vec<(Expression, Expression)> {
         let mut stops = vec![];
         enum Stop {
             Empty,
@@ -466,8 +586,100 @@ enum Stop {
             }
             start += pos + 1;
         }
+        stops
+    }
 
-        Expression::LinearGradient { angle, stops }
+    fn from_at_tr(node: syntax_nodes::AtTr, ctx: &mut LookupCtx) -> Self {
+        let string = match node
+            .child_text(SyntaxKind::StringLiteral)
+            .and_then(|s| crate::literals::unescape_string(&s))
+        {
+            Some(s) => s,
+            None => {
+                ctx.diag.push_error("Cannot parse string literal".into(), &node);
+                return Self::Invalid;
+            }
+        };
+        let context = node.TrContext().map(|n| {
+            n.child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| crate::literals::unescape_string(&s))
+                .unwrap_or_else(|| {
+                    ctx.diag.push_error("Cannot parse string literal".into(), &n);
+                    Default::default()
+                })
+        });
+        let plural = node.TrPlural().map(|pl| {
+            let s = pl
+                .child_text(SyntaxKind::StringLiteral)
+                .and_then(|s| crate::literals::unescape_string(&s))
+                .unwrap_or_else(|| {
+                    ctx.diag.push_error("Cannot parse string literal".into(), &pl);
+                    Default::default()
+                });
+            let n = Self::from_expression_node(pl.Expression(), ctx).maybe_convert_to(
+                Type::Int32,
+                &pl.Expression(),
+                ctx.diag,
+            );
+            (s, n)
+        });
+
+        let domain = ctx
+            .type_loader
+            .and_then(|tl| tl.compiler_config.translation_domain.clone())
+            .unwrap_or_default();
+
+        let subs = node
+            .Expression()
+            .map(|n| {
+                Self::from_expression_node(n.clone(), ctx).maybe_convert_to(
+                    Type::String,
+                    &n,
+                    ctx.diag,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut number_of_placeholders = 0;
+        for format_string in std::iter::once(&string).chain(plural.as_ref().map(|(s, _)| s)) {
+            match count_tr_placeholders(format_string, plural.is_some()) {
+                Ok(count) => number_of_placeholders = number_of_placeholders.max(count),
+                Err(e) => {
+                    ctx.diag.push_error(e, &node);
+                    return Self::Invalid;
+                }
+            }
+        }
+        if number_of_placeholders > subs.len() {
+            ctx.diag.push_error(
+                format!(
+                    "Format string contains {} placeholders, but only {} extra arguments were given",
+                    number_of_placeholders,
+                    subs.len()
+                ),
+                &node,
+            );
+            return Self::Invalid;
+        }
+
+        let (plural, n) = plural
+            .map_or((String::new(), Expression::NumberLiteral(1., Unit::None)), |(s, n)| (s, n));
+
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::Translate,
+                Some(node.to_source_location()),
+            )),
+            arguments: vec![
+                Expression::StringLiteral(string),
+                Expression::StringLiteral(context.unwrap_or_default()),
+                Expression::StringLiteral(domain),
+                Expression::Array { element_ty: Type::String, values: subs },
+                n,
+                Expression::StringLiteral(plural),
+            ],
+            source_location: Some(node.to_source_location()),
+        }
     }
 
     /// Perform the lookup
@@ -531,6 +743,14 @@ fn from_qualified_name_node(node: syntax_nodes::QualifiedName, ctx: &mut LookupC
                 }
                 r
             }
+            LookupResult::Expression {
+                expression: r @ Expression::FunctionReference(..), ..
+            } => {
+                if let Some(x) = it.next() {
+                    ctx.diag.push_error("Cannot access fields of a function".into(), &x)
+                }
+                r
+            }
             LookupResult::Enumeration(enumeration) => {
                 if let Some(next_identifier) = it.next() {
                     match enumeration
@@ -1023,6 +1243,13 @@ fn continue_lookup_within_element(
             ctx.diag.push_error("Cannot access fields of callback".into(), &x)
         }
         Expression::CallbackReference(NamedReference::new(elem, &resolved_name))
+    } else if matches!(property_type, Type::Function { .. })
+        && elem.borrow().is_declared_function(&resolved_name)
+    {
+        if let Some(x) = it.next() {
+            ctx.diag.push_error("Cannot access fields of a function".into(), &x)
+        }
+        Expression::FunctionReference(NamedReference::new(elem, &resolved_name))
     } else if matches!(property_type, Type::Function { .. }) {
         let member = elem.borrow().base_type.lookup_member_function(&resolved_name);
         Expression::MemberFunction {
@@ -1137,3 +1364,55 @@ pub fn resolve_two_way_binding(
         }
     }
 }
+
+/// Returns the number of arguments needed by a `@tr` format string, or an error message if the
+/// format string is invalid.
+///
+/// `{}` refers to the next argument, `{0}` to the argument with that index, and `{n}` (only
+/// valid with a plural form) to the count. `{{` and `}}` are escaped braces.
+fn count_tr_placeholders(format_string: &str, has_plural: bool) -> Result<usize, String> {
+    let mut positional = 0;
+    let mut max_index = 0;
+    let mut chars = format_string.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '}' => return Err("Unescaped '}' in format string. Use '}}' instead".into()),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err("Unescaped '{' in format string. Use '{{' instead".into())
+                        }
+                    }
+                }
+                if placeholder.is_empty() {
+                    positional += 1;
+                } else if placeholder == "n" {
+                    if !has_plural {
+                        return Err(
+                            "'{n}' can only be used in a format string with a plural form".into()
+                        );
+                    }
+                } else if let Ok(index) = placeholder.parse::<usize>() {
+                    max_index = max_index.max(index + 1);
+                } else {
+                    return Err(format!(
+                        "Invalid '{{{}}}' placeholder in format string",
+                        placeholder
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(positional.max(max_index))
+}
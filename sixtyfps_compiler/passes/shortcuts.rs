@@ -0,0 +1,133 @@
+//! Pass that checks the key sequences of the `Shortcut` elements, reports the shortcuts of a
+//! window that conflict with each other, and sets the binding of their `display-text`.
+//!
+//! Must be run before inlining, so that the invalid key sequences are reported once per component.
+
+use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
+use crate::expression_tree::{BuiltinFunction, Expression, NamedReference};
+use crate::langtype::Type;
+use crate::object_tree::*;
+use sixtyfps_common::key_sequence::KeySequence;
+use std::rc::Rc;
+
+pub fn handle_shortcuts(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem_no_borrow(&component.root_element, &(), &mut |elem, _| {
+        if !is_builtin(elem, "Shortcut") {
+            return;
+        }
+        let display_text = match constant_keys(elem) {
+            Some((keys, location)) => match KeySequence::parse(&keys) {
+                Ok(sequence) => Expression::StringLiteral(sequence.to_string()),
+                Err(err) => {
+                    diag.push_error(format!("Invalid key sequence '{}': {}", keys, err), &location);
+                    return;
+                }
+            },
+            None => Expression::FunctionCall {
+                function: Box::new(Expression::BuiltinFunctionReference(
+                    BuiltinFunction::KeySequenceDisplayText,
+                    None,
+                )),
+                arguments: vec![Expression::PropertyReference(NamedReference::new(elem, "keys"))],
+                source_location: None,
+            },
+        };
+        elem.borrow_mut().set_binding_if_not_set("display-text".into(), || display_text);
+    })
+}
+
+/// Reports the shortcuts which are always enabled in the window of the root component and use
+/// the same key sequence, including the ones of the sub-components. The conflicts within a
+/// sub-component are reported at the element instantiating it in the root component.
+pub fn check_shortcut_conflicts(root_component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    let mut shortcuts = Vec::new();
+    collect_always_enabled_shortcuts(
+        &root_component.root_element,
+        std::ptr::null(),
+        None,
+        &mut shortcuts,
+    );
+
+    let mut seen: Vec<(*const Element, KeySequence)> = Vec::new();
+    let mut reported: Vec<*const Element> = Vec::new();
+    for (scope, sequence, reported_element, location) in shortcuts {
+        if !seen.contains(&(scope, sequence)) {
+            seen.push((scope, sequence));
+        } else if !reported.contains(&reported_element) {
+            reported.push(reported_element);
+            diag.push_error(
+                format!("The shortcut '{}' is already used by another Shortcut", sequence),
+                &location,
+            );
+        }
+    }
+}
+
+/// The key sequence of an always enabled shortcut, with the PopupWindow or repeated element
+/// containing it (the shortcuts of a popup take precedence when it is shown, and the ones of a
+/// repeated or conditional element are not always there), and the element and location at
+/// which a conflict is reported.
+type AlwaysEnabledShortcut = (*const Element, KeySequence, *const Element, SourceLocation);
+
+fn collect_always_enabled_shortcuts(
+    elem: &ElementRc,
+    scope: *const Element,
+    instance: Option<&ElementRc>,
+    shortcuts: &mut Vec<AlwaysEnabledShortcut>,
+) {
+    let scope = if elem.borrow().repeated.is_some() || is_builtin(elem, "PopupWindow") {
+        elem.as_ptr() as *const Element
+    } else {
+        scope
+    };
+
+    let base_type = elem.borrow().base_type.clone();
+    if let Type::Component(base) = base_type {
+        collect_always_enabled_shortcuts(
+            &base.root_element,
+            scope,
+            Some(instance.unwrap_or(elem)),
+            shortcuts,
+        );
+    } else if is_builtin(elem, "Shortcut") {
+        // `enabled` defaults to true, so only a binding to something else may disable it
+        let always_enabled = elem
+            .borrow()
+            .bindings
+            .get("enabled")
+            .map_or(true, |b| matches!(b.borrow().expression, Expression::BoolLiteral(true)));
+        let sequence = constant_keys(elem)
+            .and_then(|(keys, location)| Some((KeySequence::parse(&keys).ok()?, location)));
+        if let (true, Some((sequence, location))) = (always_enabled, sequence) {
+            shortcuts.push(match instance {
+                Some(instance) => (
+                    scope,
+                    sequence,
+                    instance.as_ptr() as *const Element,
+                    instance.borrow().to_source_location(),
+                ),
+                None => (scope, sequence, elem.as_ptr() as *const Element, location),
+            });
+        }
+    }
+
+    let children = elem.borrow().children.clone();
+    for child in &children {
+        collect_always_enabled_shortcuts(child, scope, instance, shortcuts);
+    }
+}
+
+fn is_builtin(elem: &ElementRc, name: &str) -> bool {
+    matches!(&elem.borrow().base_type, Type::Builtin(b) if b.name == name)
+}
+
+/// The value of the `keys` binding of a Shortcut, with its location, if it is a string literal.
+fn constant_keys(elem: &ElementRc) -> Option<(String, SourceLocation)> {
+    elem.borrow().bindings.get("keys").and_then(|b| {
+        let b = b.borrow();
+        match &b.expression {
+            Expression::StringLiteral(keys) => Some((keys.clone(), b.to_source_location())),
+            _ => None,
+        }
+    })
+}
@@ -52,6 +52,7 @@
             ])
             .collect(),
             default_value: 0,
+            node: None,
         });
 
     pub static LAYOUT_ALIGNMENT_ENUM: Rc<Enumeration> =
@@ -61,6 +62,28 @@
                 ["stretch", "center", "start", "end", "space-between", "space-around"]
             ).map(String::from).collect(),
             default_value: 0,
+            node: None,
+        });
+
+    pub static ANIMATION_DIRECTION_ENUM: Rc<Enumeration> =
+        Rc::new(Enumeration {
+            name: "AnimationDirection".into(),
+            values: IntoIterator::into_iter(
+                ["normal", "reverse", "alternate", "alternate-reverse"]
+            ).map(String::from).collect(),
+            default_value: 0,
+            node: None,
+        });
+
+    pub static ACCESSIBLE_ROLE_ENUM: Rc<Enumeration> =
+        Rc::new(Enumeration {
+            name: "AccessibleRole".into(),
+            values: IntoIterator::into_iter([
+                "none", "button", "checkbox", "combobox", "groupbox", "list", "slider", "spinbox",
+                "tab", "text", "text-input", "window",
+            ]).map(String::from).collect(),
+            default_value: 0,
+            node: None,
         });
 
     pub static PATH_EVENT_ENUM: Rc<Enumeration> =
@@ -70,6 +93,7 @@
             ["begin", "line", "quadratic", "cubic", "end_open", "end_closed"]
         ).map(String::from).collect(),
         default_value: 0,
+        node: None,
     });
 }
 
@@ -86,6 +110,15 @@
     ("drop-shadow-color", Type::Color),
 ];
 
+/// The accessibility properties which are exposed to assistive technologies,
+/// except `accessible-role` whose type is an enumeration.
+pub(crate) const RESERVED_ACCESSIBILITY_PROPERTIES: &[(&str, Type)] = &[
+    ("accessible-label", Type::String),
+    ("accessible-description", Type::String),
+    ("accessible-value", Type::String),
+    ("accessible-checked", Type::Bool),
+];
+
 /// list of reserved property injected in every item
 pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type)> {
     RESERVED_GEOMETRY_PROPERTIES
@@ -93,11 +126,13 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type)> {
         .chain(RESERVED_LAYOUT_PROPERTIES.iter())
         .chain(RESERVED_OTHER_PROPERTIES.iter())
         .chain(RESERVED_DROP_SHADOW_PROPERTIES.iter())
+        .chain(RESERVED_ACCESSIBILITY_PROPERTIES.iter())
         .map(|(k, v)| (*k, v.clone()))
         .chain(IntoIterator::into_iter([
             ("forward-focus", Type::ElementReference),
             ("focus", BuiltinFunction::SetFocusItem.ty()),
             ("dialog-button-role", Type::Enumeration(DIALOG_BUTTON_ROLE_ENUM.with(|e| e.clone()))),
+            ("accessible-role", Type::Enumeration(ACCESSIBLE_ROLE_ENUM.with(|e| e.clone()))),
         ]))
 }
 
@@ -188,6 +223,7 @@ pub fn builtin() -> Rc<RefCell<Self>> {
                     name: name.to_owned(),
                     values: values.iter().cloned().map(String::from).collect(),
                     default_value: 0,
+                    node: None,
                 })),
                 name.to_owned(),
             );
@@ -200,6 +236,7 @@ pub fn builtin() -> Rc<RefCell<Self>> {
         declare_enum("ImageFit", &["fill", "contain", "cover"]);
         declare_enum("ImageRendering", &["smooth", "pixelated"]);
         declare_enum("EventResult", &["reject", "accept"]);
+        declare_enum("CloseRequestResponse", &["hide-window", "keep-window-shown"]);
         declare_enum("FillRule", &["nonzero", "evenodd"]);
         declare_enum(
             "MouseCursor",
@@ -243,10 +280,15 @@ pub fn builtin() -> Rc<RefCell<Self>> {
         );
         declare_enum("PointerEventKind", &["cancel", "down", "up"]);
         declare_enum("PointerEventButton", &["none", "left", "right", "middle"]);
+        declare_enum("SwipeDirection", &["left", "right", "up", "down"]);
         DIALOG_BUTTON_ROLE_ENUM
             .with(|e| register.insert_type_with_name(Type::Enumeration(e.clone()), e.name.clone()));
         LAYOUT_ALIGNMENT_ENUM
             .with(|e| register.insert_type_with_name(Type::Enumeration(e.clone()), e.name.clone()));
+        ANIMATION_DIRECTION_ENUM
+            .with(|e| register.insert_type_with_name(Type::Enumeration(e.clone()), e.name.clone()));
+        ACCESSIBLE_ROLE_ENUM
+            .with(|e| register.insert_type_with_name(Type::Enumeration(e.clone()), e.name.clone()));
 
         register.supported_property_animation_types.insert(Type::Float32.to_string());
         register.supported_property_animation_types.insert(Type::Int32.to_string());
@@ -279,6 +321,25 @@ pub fn builtin() -> Rc<RefCell<Self>> {
             _ => unreachable!(),
         };
 
+        for window_type in ["Window", "Dialog"] {
+            match &mut register.types.get_mut(window_type).unwrap() {
+                Type::Builtin(ref mut b) => {
+                    let b = Rc::get_mut(b).unwrap();
+                    for (name, function) in [
+                        ("show", BuiltinFunction::ShowChildWindow),
+                        ("hide", BuiltinFunction::HideChildWindow),
+                    ] {
+                        b.properties.insert(name.into(), BuiltinPropertyInfo::new(function.ty()));
+                        b.member_functions.insert(
+                            name.into(),
+                            Expression::BuiltinFunctionReference(function, None),
+                        );
+                    }
+                }
+                _ => unreachable!(),
+            };
+        }
+
         Rc::new(RefCell::new(register))
     }
 
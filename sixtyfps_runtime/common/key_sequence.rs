@@ -0,0 +1,253 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*! Parsing of the key sequences of keyboard shortcuts, such as `"Ctrl+Shift+S"` or `"F5"`.
+
+The compiler uses it to diagnose invalid or conflicting shortcuts, and the runtime to match the
+key events against the shortcuts and to produce the text displayed in menus.
+*/
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+macro_rules! declare_special_key_lookup {
+    ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident)|* ;)*) => {
+        fn special_key_from_name(name: &str) -> Option<char> {
+            $(if name.eq_ignore_ascii_case(stringify!($name)) {
+                return Some($char);
+            })*
+            None
+        }
+
+        fn special_key_name(key: char) -> Option<&'static str> {
+            match key {
+                $($char => Some(stringify!($name)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+crate::for_each_special_keys!(declare_special_key_lookup);
+
+/// Alternative names accepted for some keys, in addition to the names of the special keys
+const KEY_ALIASES: [(&str, char); 8] = [
+    ("Space", ' '),
+    ("Plus", '+'),
+    ("Enter", '\u{000a}'),
+    ("Esc", '\u{001b}'),
+    ("Up", '\u{F700}'),
+    ("Down", '\u{F701}'),
+    ("Left", '\u{F702}'),
+    ("Right", '\u{F703}'),
+];
+
+/// A key combined with the modifiers that must be held when it is pressed.
+///
+/// Letters are stored in lower case, so that the sequence matches regardless of the case of the
+/// text of the key event.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeySequence {
+    pub control: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// The character of the key, or one of the special key codes for named keys
+    pub key: char,
+}
+
+/// The error returned by [`KeySequence::parse`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeySequenceParseError<'a> {
+    /// The string does not contain a key
+    MissingKey,
+    /// The modifier is not one of `Ctrl`, `Alt`, `Shift` or `Meta`
+    UnknownModifier(&'a str),
+    /// The same modifier appears more than once
+    DuplicatedModifier(&'a str),
+    /// The key is neither a single character nor the name of a key
+    UnknownKey(&'a str),
+}
+
+impl<'a> Display for KeySequenceParseError<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::MissingKey => write!(f, "the key sequence has no key"),
+            Self::UnknownModifier(m) => write!(
+                f,
+                "unknown modifier '{}', expected one of 'Ctrl', 'Alt', 'Shift' or 'Meta'",
+                m
+            ),
+            Self::DuplicatedModifier(m) => write!(f, "the modifier '{}' is used twice", m),
+            Self::UnknownKey(k) => write!(f, "unknown key '{}'", k),
+        }
+    }
+}
+
+impl KeySequence {
+    /// Parses a key sequence made of modifiers followed by a key, separated with `+`.
+    ///
+    /// The modifiers are `Ctrl` (or `Control`), `Alt`, `Shift` and `Meta`. The key is either a
+    /// single character or the name of one of the special keys, such as `F5`, `Escape` or
+    /// `Delete`. Names are case insensitive and a trailing `+` is the plus key, as in `"Ctrl++"`.
+    pub fn parse(sequence: &str) -> Result<Self, KeySequenceParseError<'_>> {
+        let sequence = sequence.trim();
+        let (modifiers, key) = if sequence == "+" {
+            ("", "+")
+        } else if let Some(modifiers) = sequence.strip_suffix("++") {
+            (modifiers, "+")
+        } else {
+            match sequence.rfind('+') {
+                Some(pos) => (&sequence[..pos], &sequence[pos + 1..]),
+                None => ("", sequence),
+            }
+        };
+
+        let mut result = KeySequence::default();
+        if !modifiers.is_empty() {
+            for modifier in modifiers.split('+').map(str::trim) {
+                let flag = if modifier.eq_ignore_ascii_case("ctrl")
+                    || modifier.eq_ignore_ascii_case("control")
+                {
+                    &mut result.control
+                } else if modifier.eq_ignore_ascii_case("alt") {
+                    &mut result.alt
+                } else if modifier.eq_ignore_ascii_case("shift") {
+                    &mut result.shift
+                } else if modifier.eq_ignore_ascii_case("meta") {
+                    &mut result.meta
+                } else if modifier.is_empty() {
+                    return Err(KeySequenceParseError::MissingKey);
+                } else {
+                    return Err(KeySequenceParseError::UnknownModifier(modifier));
+                };
+                if *flag {
+                    return Err(KeySequenceParseError::DuplicatedModifier(modifier));
+                }
+                *flag = true;
+            }
+        }
+
+        let key = key.trim();
+        let mut chars = key.chars();
+        result.key = match (chars.next(), chars.next()) {
+            (None, _) => return Err(KeySequenceParseError::MissingKey),
+            (Some(c), None) => lowercase(c),
+            _ => special_key_from_name(key)
+                .or_else(|| {
+                    KEY_ALIASES.iter().find(|(n, _)| key.eq_ignore_ascii_case(n)).map(|(_, c)| *c)
+                })
+                .ok_or(KeySequenceParseError::UnknownKey(key))?,
+        };
+        Ok(result)
+    }
+
+    /// Returns true if a key event with this text and these modifiers activates this sequence.
+    ///
+    /// When the key is a symbol and the sequence has no `Shift`, the state of the shift key is
+    /// ignored since it may be needed to type the symbol, like the `+` on many keyboard layouts.
+    pub fn matches(&self, text: &str, control: bool, alt: bool, shift: bool, meta: bool) -> bool {
+        let mut chars = text.chars();
+        let key = match (chars.next(), chars.next()) {
+            (Some(c), None) => lowercase(c),
+            _ => return false,
+        };
+        let ignore_shift = !self.shift && is_symbol(self.key);
+        key == self.key
+            && control == self.control
+            && alt == self.alt
+            && meta == self.meta
+            && (ignore_shift || shift == self.shift)
+    }
+}
+
+/// Formats the sequence the way it is shown in menus, like `Ctrl+Shift+S`
+impl Display for KeySequence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (enabled, name) in [
+            (self.control, "Ctrl+"),
+            (self.alt, "Alt+"),
+            (self.shift, "Shift+"),
+            (self.meta, "Meta+"),
+        ] {
+            if enabled {
+                f.write_str(name)?;
+            }
+        }
+        if self.key == ' ' {
+            f.write_str("Space")
+        } else if let Some(name) = special_key_name(self.key) {
+            f.write_str(name)
+        } else {
+            for c in self.key.to_uppercase() {
+                write!(f, "{}", c)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_alphanumeric() && c != ' ' && special_key_name(c).is_none()
+}
+
+#[test]
+fn parse_key_sequence() {
+    let ctrl_s = KeySequence { control: true, key: 's', ..Default::default() };
+    assert_eq!(KeySequence::parse("Ctrl+S"), Ok(ctrl_s));
+    assert_eq!(KeySequence::parse(" control + s "), Ok(ctrl_s));
+    assert_eq!(
+        KeySequence::parse("Ctrl+Shift+Z"),
+        Ok(KeySequence { control: true, shift: true, key: 'z', ..Default::default() })
+    );
+    assert_eq!(KeySequence::parse("F5"), Ok(KeySequence { key: '\u{F708}', ..Default::default() }));
+    assert_eq!(
+        KeySequence::parse("esc"),
+        Ok(KeySequence { key: '\u{001b}', ..Default::default() })
+    );
+    assert_eq!(
+        KeySequence::parse("Ctrl++"),
+        Ok(KeySequence { control: true, key: '+', ..Default::default() })
+    );
+    assert_eq!(KeySequence::parse("+"), Ok(KeySequence { key: '+', ..Default::default() }));
+    assert_eq!(KeySequence::parse(""), Err(KeySequenceParseError::MissingKey));
+    assert_eq!(KeySequence::parse("Ctrl+"), Err(KeySequenceParseError::MissingKey));
+    assert_eq!(
+        KeySequence::parse("Ctrl+Ctrl+S"),
+        Err(KeySequenceParseError::DuplicatedModifier("Ctrl"))
+    );
+    assert_eq!(KeySequence::parse("Hyper+S"), Err(KeySequenceParseError::UnknownModifier("Hyper")));
+    assert_eq!(KeySequence::parse("Ctrl+Foo"), Err(KeySequenceParseError::UnknownKey("Foo")));
+}
+
+#[test]
+fn match_key_sequence() {
+    let ctrl_s = KeySequence::parse("Ctrl+S").unwrap();
+    assert!(ctrl_s.matches("s", true, false, false, false));
+    assert!(!ctrl_s.matches("s", false, false, false, false));
+    assert!(!ctrl_s.matches("S", true, false, true, false));
+    let ctrl_shift_s = KeySequence::parse("Ctrl+Shift+S").unwrap();
+    assert!(ctrl_shift_s.matches("S", true, false, true, false));
+    let ctrl_plus = KeySequence::parse("Ctrl++").unwrap();
+    assert!(ctrl_plus.matches("+", true, false, true, false));
+    assert!(ctrl_plus.matches("+", true, false, false, false));
+    assert!(!ctrl_plus.matches("+=", true, false, false, false));
+}
+
+#[test]
+fn display_key_sequence() {
+    extern crate std;
+    use std::string::ToString;
+    let display = |s| KeySequence::parse(s).unwrap().to_string();
+    assert_eq!(display("shift+ctrl+s"), "Ctrl+Shift+S");
+    assert_eq!(display("alt+f4"), "Alt+F4");
+    assert_eq!(display("Meta+space"), "Meta+Space");
+    assert_eq!(display("enter"), "Return");
+}
@@ -17,3 +17,4 @@
 #![no_std]
 
 pub mod key_codes;
+pub mod key_sequence;
@@ -0,0 +1,308 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+    Support for assistive technologies such as screen readers.
+
+    The items of a component expose their semantic information (role, label, ...) through the
+    `accessible_role` and `accessible_string_property` functions of the component's vtable.
+    [`accessibility_tree`] collects that information into a tree of [`AccessibilityNode`].
+*/
+
+#![warn(missing_docs)]
+use crate::component::ComponentRc;
+use crate::graphics::{Point, Rect};
+use crate::item_tree::{ItemVisitorResult, TraversalOrder};
+use crate::items::{AccessibleRole, ItemRc, ItemRef};
+use crate::window::WindowRc;
+use crate::SharedString;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::pin::Pin;
+
+/// The accessible properties of an item that are exposed as strings.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AccessibleStringProperty {
+    /// The `accessible-label` property.
+    Label,
+    /// The `accessible-description` property.
+    Description,
+    /// The `accessible-value` property.
+    Value,
+    /// The `accessible-checked` property, as "true" or "false".
+    Checked,
+}
+
+/// An action that an assistive technology can perform on an [`AccessibilityNode`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AccessibleAction {
+    /// Activates the node, as if it was clicked.
+    Default,
+    /// Gives the keyboard focus to the node.
+    Focus,
+}
+
+/// The state of an [`AccessibilityNode`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AccessibleStates {
+    /// False when all the interactive items of the node are disabled.
+    pub enabled: bool,
+    /// True when the node can receive the keyboard focus.
+    pub focusable: bool,
+    /// True when the node has the keyboard focus.
+    pub focused: bool,
+    /// The checked state, for nodes that can be checked.
+    pub checked: Option<bool>,
+}
+
+impl Default for AccessibleStates {
+    fn default() -> Self {
+        Self { enabled: true, focusable: false, focused: false, checked: None }
+    }
+}
+
+/// A node in the accessibility tree of a component.
+///
+/// A node is created for each visible item which has an accessible role. The items
+/// without a role are skipped, and their children are attached to the closest ancestor node.
+/// Nodes with a role that represents a single control, such as `button` or `text`, have no
+/// children: the items they contain are only used to determine the states and actions of the node.
+#[derive(Clone)]
+pub struct AccessibilityNode {
+    /// The item this node was created for.
+    pub item: ItemRc,
+    /// The `accessible-role` of the item.
+    pub role: AccessibleRole,
+    /// The `accessible-label` of the item.
+    pub label: SharedString,
+    /// The `accessible-description` of the item.
+    pub description: SharedString,
+    /// The `accessible-value` of the item.
+    pub value: SharedString,
+    /// The geometry of the item, relative to the root of the tree.
+    pub geometry: Rect,
+    /// The states of the node.
+    pub states: AccessibleStates,
+    /// The actions that can be performed with [`Self::perform_action`].
+    pub actions: Vec<AccessibleAction>,
+    /// The child nodes.
+    pub children: Vec<AccessibilityNode>,
+    focus_item: Option<ItemRc>,
+    default_action_item: Option<ItemRc>,
+    has_disabled_input: bool,
+}
+
+impl AccessibilityNode {
+    fn new(item: ItemRc, role: AccessibleRole, geometry: Rect) -> Self {
+        let checked =
+            match item.accessible_string_property(AccessibleStringProperty::Checked).as_str() {
+                "true" => Some(true),
+                "false" => Some(false),
+                _ => None,
+            };
+        Self {
+            role,
+            label: item.accessible_string_property(AccessibleStringProperty::Label),
+            description: item.accessible_string_property(AccessibleStringProperty::Description),
+            value: item.accessible_string_property(AccessibleStringProperty::Value),
+            geometry,
+            states: AccessibleStates { checked, ..Default::default() },
+            actions: Vec::new(),
+            children: Vec::new(),
+            focus_item: None,
+            default_action_item: None,
+            has_disabled_input: false,
+            item,
+        }
+    }
+
+    /// Returns true if the node represents a single control, whose items are not exposed as children.
+    fn is_leaf(&self) -> bool {
+        !matches!(
+            self.role,
+            AccessibleRole::none
+                | AccessibleRole::groupbox
+                | AccessibleRole::list
+                | AccessibleRole::window
+        )
+    }
+
+    /// Records the input items contained in this node.
+    fn visit_item(&mut self, item_rc: &ItemRc, item: Pin<ItemRef>) {
+        if let Some(text_input) = ItemRef::downcast_pin::<crate::items::TextInput>(item) {
+            self.states.focused |= text_input.has_focus();
+            Self::record_input(
+                &mut self.focus_item,
+                &mut self.has_disabled_input,
+                item_rc,
+                text_input.enabled(),
+            );
+        } else if let Some(focus_scope) = ItemRef::downcast_pin::<crate::items::FocusScope>(item) {
+            self.states.focused |= focus_scope.has_focus();
+            Self::record_input(
+                &mut self.focus_item,
+                &mut self.has_disabled_input,
+                item_rc,
+                focus_scope.enabled(),
+            );
+        } else if let Some(touch_area) = ItemRef::downcast_pin::<crate::items::TouchArea>(item) {
+            Self::record_input(
+                &mut self.default_action_item,
+                &mut self.has_disabled_input,
+                item_rc,
+                touch_area.enabled(),
+            );
+        }
+    }
+
+    fn record_input(
+        target: &mut Option<ItemRc>,
+        has_disabled_input: &mut bool,
+        item_rc: &ItemRc,
+        enabled: bool,
+    ) {
+        if !enabled {
+            *has_disabled_input = true;
+        } else if target.is_none() {
+            *target = Some(item_rc.clone());
+        }
+    }
+
+    /// Computes the states and actions, once all the items of the node have been visited.
+    fn finish(&mut self) {
+        self.states.focusable = self.focus_item.is_some();
+        self.states.enabled = !self.has_disabled_input
+            || self.focus_item.is_some()
+            || self.default_action_item.is_some();
+        if self.default_action_item.is_some() {
+            self.actions.push(AccessibleAction::Default);
+        }
+        if self.focus_item.is_some() {
+            self.actions.push(AccessibleAction::Focus);
+        }
+    }
+
+    /// Performs the given action on the node. Returns false if the node doesn't support it.
+    pub fn perform_action(&self, action: AccessibleAction, window: &WindowRc) -> bool {
+        match action {
+            AccessibleAction::Default => {
+                let item = match &self.default_action_item {
+                    Some(item) => item,
+                    None => return false,
+                };
+                match ItemRef::downcast_pin::<crate::items::TouchArea>(item.borrow()) {
+                    Some(touch_area) => {
+                        crate::items::TouchArea::FIELD_OFFSETS
+                            .clicked
+                            .apply_pin(touch_area)
+                            .call(&());
+                        true
+                    }
+                    None => false,
+                }
+            }
+            AccessibleAction::Focus => match &self.focus_item {
+                Some(item) => {
+                    window.clone().set_focus_item(item);
+                    true
+                }
+                None => false,
+            },
+        }
+    }
+
+    /// Returns the first node, in depth-first order, that has the given role and label.
+    pub fn find(&self, role: AccessibleRole, label: &str) -> Option<&AccessibilityNode> {
+        if self.role == role && self.label.as_str() == label {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(role, label))
+    }
+
+    fn fmt_indented(&self, f: &mut core::fmt::Formatter<'_>, indent: usize) -> core::fmt::Result {
+        write!(f, "{:indent$}{}", "", self.role.to_string().replace('_', "-"), indent = indent)?;
+        if !self.label.is_empty() {
+            write!(f, " label={:?}", self.label.as_str())?;
+        }
+        if !self.value.is_empty() {
+            write!(f, " value={:?}", self.value.as_str())?;
+        }
+        if !self.description.is_empty() {
+            write!(f, " description={:?}", self.description.as_str())?;
+        }
+        match self.states.checked {
+            Some(true) => write!(f, " checked")?,
+            Some(false) => write!(f, " unchecked")?,
+            None => {}
+        }
+        if self.states.focusable {
+            write!(f, " focusable")?;
+        }
+        if self.states.focused {
+            write!(f, " focused")?;
+        }
+        if !self.states.enabled {
+            write!(f, " disabled")?;
+        }
+        writeln!(f)?;
+        self.children.iter().try_for_each(|child| child.fmt_indented(f, indent + 2))
+    }
+}
+
+/// Formats the tree with one line per node, indented by depth.
+/// This is meant to be used in tests to check the semantics exposed by a component.
+impl core::fmt::Display for AccessibilityNode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[derive(Clone, Default)]
+struct VisitState {
+    origin: Point,
+    hidden: bool,
+}
+
+/// Returns the accessibility tree of the component. The root node is always created for the
+/// root item of the component, regardless of its role.
+pub fn accessibility_tree(component: &ComponentRc) -> AccessibilityNode {
+    let stack: RefCell<Vec<AccessibilityNode>> = Default::default();
+    crate::item_tree::visit_items_with_post_visit(
+        component,
+        TraversalOrder::BackToFront,
+        |component, item, index, state: &VisitState| {
+            if state.hidden {
+                return (ItemVisitorResult::Continue(state.clone()), false);
+            }
+            let item_rc = ItemRc::new(component.clone(), index);
+            let geometry = item.as_ref().geometry().translate(state.origin.to_vector());
+            let mut stack = stack.borrow_mut();
+            let role = item_rc.accessible_role();
+            let pushed = match stack.last() {
+                None => true,
+                Some(parent) => role != AccessibleRole::none && !parent.is_leaf(),
+            };
+            if pushed {
+                stack.push(AccessibilityNode::new(item_rc.clone(), role, geometry));
+            }
+            stack.last_mut().unwrap().visit_item(&item_rc, item);
+            let state =
+                VisitState { origin: geometry.origin, hidden: crate::window::hides_children(item) };
+            (ItemVisitorResult::Continue(state), pushed)
+        },
+        |_, _, pushed, result| {
+            let mut stack = stack.borrow_mut();
+            if pushed && stack.len() > 1 {
+                let mut node = stack.pop().unwrap();
+                node.finish();
+                stack.last_mut().unwrap().children.push(node);
+            }
+            result
+        },
+        VisitState::default(),
+    );
+    let mut root = stack.into_inner().pop().expect("the component has no root item");
+    root.finish();
+    root
+}
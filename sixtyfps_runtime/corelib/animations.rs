@@ -5,7 +5,14 @@
 //! The animation system
 
 use alloc::boxed::Box;
-use core::cell::Cell;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+
+use crate::SharedString;
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
 
 #[cfg(feature = "std")]
 use lyon_algorithms::geom::cubic_bezier;
@@ -145,7 +152,31 @@ pub enum EasingCurve {
     Linear,
     /// A Cubic bezier curve, with its 4 parameter
     CubicBezier([f32; 4]),
-    //Custom(Box<dyn Fn(f32) -> f32>),
+    /// A spring, with its mass, stiffness and damping.
+    /// The duration of the animation is ignored: the animation runs until the spring is at rest.
+    Spring([f32; 3]),
+    /// Jumps from the start to the end value in the given number of equal steps
+    Steps(u32),
+    /// An easing curve registered with [`register_easing_curve`], identified by its index
+    /// in the registry. Use [`EasingCurve::custom`] to get it from its name.
+    Custom(u32),
+}
+
+impl EasingCurve {
+    /// Returns the easing curve that was, or will be, registered under that name
+    /// with [`register_easing_curve`]
+    pub fn custom(name: &str) -> Self {
+        Self::Custom(CUSTOM_EASING_CURVES.with(|curves| {
+            let mut curves = curves.borrow_mut();
+            match curves.iter().position(|(n, _)| n == name) {
+                Some(index) => index as u32,
+                None => {
+                    curves.push((name.into(), None));
+                    (curves.len() - 1) as u32
+                }
+            }
+        }))
+    }
 }
 
 impl Default for EasingCurve {
@@ -269,6 +300,26 @@ pub fn current_tick() -> Instant {
     CURRENT_ANIMATION_DRIVER.with(|driver| driver.current_tick())
 }
 
+type CustomEasingCurve = (SharedString, Option<Rc<dyn Fn(f32) -> f32>>);
+
+thread_local!(
+/// The easing curves registered with register_easing_curve, or just referenced by name
+static CUSTOM_EASING_CURVES : RefCell<Vec<CustomEasingCurve>> = RefCell::new(Vec::new())
+);
+
+/// Registers a function that can be used as an easing curve with `custom("name")` in `.60` files.
+///
+/// The function maps the progress of the animation, between 0 and 1, to the progress of the value.
+/// Animations using a name that is not registered are linear.
+/// Registering a function under the same name again replaces the previous function.
+pub fn register_easing_curve(name: &str, curve: impl Fn(f32) -> f32 + 'static) {
+    let index = match EasingCurve::custom(name) {
+        EasingCurve::Custom(index) => index as usize,
+        _ => unreachable!(),
+    };
+    CUSTOM_EASING_CURVES.with(|curves| curves.borrow_mut()[index].1 = Some(Rc::new(curve)));
+}
+
 /// map a value between 0 and 1 to another value between 0 and 1 according to the curve
 pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
     match curve {
@@ -287,7 +338,71 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
             let curve = curve.assume_monotonic();
             curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.01))
         }
+        // Springs do not depend on the duration, properties evaluate them with `spring_curve`
+        EasingCurve::Spring(_) => value,
+        EasingCurve::Steps(steps) => {
+            if *steps == 0 || value >= 1. {
+                return value;
+            }
+            (value * *steps as f32).floor() / *steps as f32
+        }
+        EasingCurve::Custom(index) => {
+            let curve = CUSTOM_EASING_CURVES
+                .with(|curves| curves.borrow().get(*index as usize).and_then(|c| c.1.clone()));
+            match curve {
+                Some(curve) => curve(value),
+                None => value,
+            }
+        }
+    }
+}
+
+/// Evaluates a spring animation going from 0 to 1.
+///
+/// `parameters` are the mass, stiffness and damping of the spring, `initial_velocity` is the
+/// velocity at the start of the animation in units per second, and `elapsed` is the time since
+/// the start of the animation.
+/// Returns the position, the velocity, and whether the spring is at rest.
+pub fn spring_curve(
+    parameters: &[f32; 3],
+    initial_velocity: f32,
+    elapsed: core::time::Duration,
+) -> (f32, f32, bool) {
+    let [mass, stiffness, damping] = *parameters;
+    if mass <= 0. || stiffness <= 0. || damping < 0. {
+        return (1., 0., true);
     }
+    let t = elapsed.as_secs_f32();
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping / (2. * (stiffness * mass).sqrt());
+    // Solve for the displacement to the target, which starts at -1
+    let (displacement, velocity) = if (zeta - 1.).abs() < 0.0001 {
+        // critically damped
+        let b = initial_velocity - omega;
+        let e = (-omega * t).exp();
+        ((-1. + b * t) * e, (b - omega * (-1. + b * t)) * e)
+    } else if zeta < 1. {
+        // under damped
+        let omega_d = omega * (1. - zeta * zeta).sqrt();
+        let b = (initial_velocity - zeta * omega) / omega_d;
+        let e = (-zeta * omega * t).exp();
+        let (sin, cos) = (omega_d * t).sin_cos();
+        (
+            e * (-cos + b * sin),
+            e * ((zeta * omega + omega_d * b) * cos + (omega_d - zeta * omega * b) * sin),
+        )
+    } else {
+        // over damped
+        let root = omega * (zeta * zeta - 1.).sqrt();
+        let r1 = -zeta * omega + root;
+        let r2 = -zeta * omega - root;
+        let c2 = (initial_velocity + r1) / (r2 - r1);
+        let c1 = -1. - c2;
+        let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+        (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+    };
+    let at_rest = displacement.abs() < 0.001 && velocity.abs() < 0.01;
+    (1. + displacement, velocity, at_rest)
 }
 
 /*
@@ -334,3 +449,74 @@ pub fn update_animations() {
         driver.update_animations(Instant(duration))
     });
 }
+
+#[test]
+fn easing_steps_and_custom_test() {
+    assert_eq!(easing_curve(&EasingCurve::Steps(4), 0.2), 0.);
+    assert_eq!(easing_curve(&EasingCurve::Steps(4), 0.3), 0.25);
+    assert_eq!(easing_curve(&EasingCurve::Steps(4), 1.), 1.);
+
+    let custom = EasingCurve::custom("test-custom-easing");
+    assert_eq!(custom, EasingCurve::custom("test-custom-easing"));
+    assert_eq!(easing_curve(&custom, 0.5), 0.5);
+    register_easing_curve("test-custom-easing", |t| t * t);
+    assert_eq!(easing_curve(&custom, 0.5), 0.25);
+}
+
+#[test]
+fn spring_curve_test() {
+    use core::time::Duration;
+    for parameters in [[1., 100., 10.], [1., 100., 20.], [1., 100., 40.]] {
+        let (position, velocity, at_rest) = spring_curve(&parameters, 0., Duration::ZERO);
+        assert!(position.abs() < 0.0001 && velocity.abs() < 0.0001 && !at_rest);
+        let (position, _, at_rest) = spring_curve(&parameters, 0., Duration::from_millis(100));
+        assert!(position > 0. && !at_rest);
+        let (position, _, at_rest) = spring_curve(&parameters, 0., Duration::from_secs(10));
+        assert!((position - 1.).abs() < 0.001 && at_rest);
+    }
+    // The initial velocity is kept
+    let (_, velocity, _) = spring_curve(&[1., 100., 10.], 5., Duration::ZERO);
+    assert!((velocity - 5.).abs() < 0.0001);
+}
+
+#[cfg(feature = "ffi")]
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    #[allow(non_camel_case_types)]
+    type c_void = ();
+
+    struct WrapFn {
+        callback: extern "C" fn(*mut c_void, f32) -> f32,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    }
+
+    impl Drop for WrapFn {
+        fn drop(&mut self) {
+            if let Some(x) = self.drop_user_data {
+                x(self.user_data)
+            }
+        }
+    }
+
+    /// Returns the easing curve registered under that name. See [`EasingCurve::custom`]
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_custom_easing_curve(name: &SharedString, out: &mut EasingCurve) {
+        *out = EasingCurve::custom(name);
+    }
+
+    /// Registers an easing curve under that name. See [`register_easing_curve`]
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_register_easing_curve(
+        name: &SharedString,
+        callback: extern "C" fn(*mut c_void, f32) -> f32,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    ) {
+        let wrap = WrapFn { callback, user_data, drop_user_data };
+        register_easing_curve(name, move |t| (wrap.callback)(wrap.user_data, t));
+    }
+}
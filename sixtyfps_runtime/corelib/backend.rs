@@ -39,6 +39,26 @@ pub trait Backend: Send + Sync {
     /// Exits the event loop.
     fn quit_event_loop(&'static self);
 
+    /// Processes the pending events without taking over the thread: the events of the windowing
+    /// system, the events posted with [`Self::post_event()`], the expired timers and the
+    /// animations. If there is no pending event, waits at most `timeout` for one.
+    ///
+    /// This allows driving SixtyFPS from an event loop owned by the application, by calling
+    /// this function repeatedly. Returns false once the event loop was asked to quit, either
+    /// with [`Self::quit_event_loop()`] or according to `behavior`.
+    ///
+    /// The GL backend (except on wasm), the Qt backend, the software backend and the testing
+    /// backend support this.
+    /// The default implementation processes nothing and returns false, as if the event loop
+    /// was asked to quit.
+    fn process_events(
+        &'static self,
+        _timeout: core::time::Duration,
+        _behavior: EventLoopQuitBehavior,
+    ) -> bool {
+        false
+    }
+
     #[cfg(feature = "std")] // FIXME: just because of the Error
     /// This function can be used to register a custom TrueType font with SixtyFPS,
     /// for use with the `font-family` property. The provided slice must be a valid TrueType
@@ -5,10 +5,12 @@
 
 //! This module contains the basic datastructures that are exposed to the C API
 
+use crate::accessibility::AccessibleStringProperty;
 use crate::item_tree::{ItemVisitorVTable, TraversalOrder, VisitChildrenResult};
-use crate::items::{ItemVTable, ItemWeak};
+use crate::items::{AccessibleRole, ItemVTable, ItemWeak};
 use crate::layout::{LayoutInfo, Orientation};
 use crate::window::WindowRc;
+use crate::{SharedString, SharedVector};
 use vtable::*;
 
 /// A Component is representing an unit that is allocated together
@@ -41,6 +43,27 @@ pub struct ComponentVTable {
     pub layout_info:
         extern "C" fn(core::pin::Pin<VRef<ComponentVTable>>, Orientation) -> LayoutInfo,
 
+    /// Returns the accessible role of the item at index `index`.
+    pub accessible_role:
+        extern "C" fn(core::pin::Pin<VRef<ComponentVTable>>, index: usize) -> AccessibleRole,
+
+    /// Sets `result` to the value of the accessible property `what` of the item at index `index`.
+    /// `result` is left untouched if the property is not set on that item.
+    pub accessible_string_property: extern "C" fn(
+        core::pin::Pin<VRef<ComponentVTable>>,
+        index: usize,
+        what: AccessibleStringProperty,
+        result: &mut SharedString,
+    ),
+
+    /// Sets `result` to the ids that the item at index `index` has in the .60 source.
+    /// `result` is left untouched if the item has no id.
+    pub element_ids: extern "C" fn(
+        core::pin::Pin<VRef<ComponentVTable>>,
+        index: usize,
+        result: &mut SharedVector<SharedString>,
+    ),
+
     /// in-place destructor (for VRc)
     pub drop_in_place: unsafe fn(VRefMut<ComponentVTable>) -> vtable::Layout,
     /// dealloc function (for VRc)
@@ -85,7 +85,8 @@ pub fn handle_mouse_filter(
                     InputEventFilterResult::ForwardEvent
                 }
             }
-            MouseEvent::MouseWheel { .. } => InputEventFilterResult::Intercept,
+            // The children may handle the wheel, the Flickable scrolls if they ignore it
+            MouseEvent::MouseWheel { .. } => InputEventFilterResult::ForwardEvent,
             // Not the left button
             MouseEvent::MousePressed { .. } | MouseEvent::MouseReleased { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -0,0 +1,223 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+    Support for running futures in the event loop.
+
+    Tasks spawned with [`spawn_local`] live in a thread local list. Their waker posts an event
+    to the backend that polls the task again from the thread running the event loop.
+*/
+
+#![warn(missing_docs)]
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::cell::{Cell, RefCell};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::task::Wake;
+
+use crate::timers::{Timer, TimerMode};
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local!(static TASKS : RefCell<slab::Slab<Option<Task>>> = RefCell::default());
+
+struct TaskWaker {
+    task_id: usize,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let task_id = self.task_id;
+        if let Some(backend) = crate::backend::instance() {
+            backend.post_event(Box::new(move || poll_task(task_id)));
+        }
+    }
+}
+
+/// Poll the task once. Does nothing if the task is finished or is currently being polled.
+fn poll_task(task_id: usize) {
+    let task = TASKS.with(|tasks| tasks.borrow_mut().get_mut(task_id).and_then(Option::take));
+    let mut task = match task {
+        Some(task) => task,
+        None => return,
+    };
+    let waker = Waker::from(Arc::new(TaskWaker { task_id }));
+    let pending = task.as_mut().poll(&mut Context::from_waker(&waker)).is_pending();
+    TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        if pending {
+            tasks[task_id] = Some(task);
+        } else {
+            tasks.remove(task_id);
+        }
+    });
+}
+
+struct JoinState<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// A handle to a task spawned with [`spawn_local`].
+///
+/// The handle is a future that resolves to the output of the task. Dropping the handle
+/// detaches the task: it keeps running until completion.
+pub struct JoinHandle<T>(Rc<RefCell<JoinState<T>>>);
+
+impl<T> JoinHandle<T> {
+    /// Returns true if the task has completed.
+    pub fn is_finished(&self) -> bool {
+        self.0.borrow().result.is_some()
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.0.borrow_mut();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Spawns a future on the thread running the event loop. The future does not need to be
+/// `Send`, so it can hold component handles or other values that are bound to the thread.
+///
+/// The future is first polled the next time the event loop processes events, and then each
+/// time its waker is woken. This function must be called from the thread running the event
+/// loop, after the backend was initialized.
+pub fn spawn_local<F: Future + 'static>(fut: F) -> JoinHandle<F::Output> {
+    let state = Rc::new(RefCell::new(JoinState { result: None, waker: None }));
+    let task_state = state.clone();
+    let task = async move {
+        let result = fut.await;
+        let waker = {
+            let mut state = task_state.borrow_mut();
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    };
+    let task_id = TASKS.with(|tasks| tasks.borrow_mut().insert(Some(Box::pin(task))));
+    Waker::from(Arc::new(TaskWaker { task_id })).wake();
+    JoinHandle(state)
+}
+
+#[derive(Default)]
+struct SleepState {
+    elapsed: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+/// A future returned by [`sleep`] that completes once the duration has elapsed.
+///
+/// Dropping the future stops the underlying timer.
+pub struct Sleep {
+    state: Rc<SleepState>,
+    _timer: Timer,
+}
+
+impl Future for Sleep {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.state.elapsed.get() {
+            Poll::Ready(())
+        } else {
+            *self.state.waker.borrow_mut() = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Returns a future that completes after the specified duration. It is driven by a
+/// [`Timer`], so, like timers, it must be created in the thread that runs the event loop.
+///
+/// ```rust,no_run
+/// # use sixtyfps_corelib::future::{sleep, spawn_local};
+/// spawn_local(async {
+///     sleep(core::time::Duration::from_millis(200)).await;
+///     println!("This will be printed after 200ms.");
+/// });
+/// ```
+pub fn sleep(duration: core::time::Duration) -> Sleep {
+    let state = Rc::new(SleepState::default());
+    let timer = Timer::default();
+    let weak_state = Rc::downgrade(&state);
+    timer.start(TimerMode::SingleShot, duration, move || {
+        if let Some(state) = weak_state.upgrade() {
+            state.elapsed.set(true);
+            if let Some(waker) = state.waker.borrow_mut().take() {
+                waker.wake();
+            }
+        }
+    });
+    Sleep { state, _timer: timer }
+}
+
+/// Conversion of a function returning a future into a callback handler.
+///
+/// This trait is implemented for functions and closures with up to six arguments that
+/// return a future with `()` as output. Use it through [`async_callback`].
+pub trait AsyncCallback<Args> {
+    /// The type of the callback handler that spawns the future.
+    type Handler;
+    /// Converts the function into a handler that spawns the returned future with
+    /// [`spawn_local`] each time it is invoked.
+    fn into_handler(self) -> Self::Handler;
+}
+
+macro_rules! impl_async_callback {
+    ($($arg:ident),*) => {
+        impl<Func, Fut, $($arg),*> AsyncCallback<($($arg,)*)> for Func
+        where
+            Func: Fn($($arg),*) -> Fut + 'static,
+            Fut: Future<Output = ()> + 'static,
+        {
+            type Handler = Box<dyn Fn($($arg),*)>;
+            #[allow(non_snake_case)]
+            fn into_handler(self) -> Self::Handler {
+                Box::new(move |$($arg),*| {
+                    spawn_local(self($($arg),*));
+                })
+            }
+        }
+    };
+}
+
+impl_async_callback!();
+impl_async_callback!(A1);
+impl_async_callback!(A1, A2);
+impl_async_callback!(A1, A2, A3);
+impl_async_callback!(A1, A2, A3, A4);
+impl_async_callback!(A1, A2, A3, A4, A5);
+impl_async_callback!(A1, A2, A3, A4, A5, A6);
+
+/// Adapts an async function or a closure returning a future so that it can be set as the
+/// handler of a callback. Each invocation of the callback spawns the returned future with
+/// [`spawn_local`]. The arguments of closures must be annotated with their types.
+///
+/// ```rust,no_run
+/// # use sixtyfps_corelib::future::{async_callback, sleep};
+/// # fn on_clicked(_: impl Fn(i32)) {}
+/// on_clicked(async_callback(|count: i32| async move {
+///     sleep(core::time::Duration::from_secs(1)).await;
+///     println!("Clicked {} times a second ago", count);
+/// }));
+/// ```
+pub fn async_callback<Args, F: AsyncCallback<Args>>(f: F) -> F::Handler {
+    f.into_handler()
+}
@@ -25,6 +25,9 @@ pub enum Brush {
     /// The linear gradient variant of a brush describes the gradient stops for a fill
     /// where all color stops are along a line that's rotated by the specified angle.
     LinearGradient(LinearGradientBrush),
+    /// The radial gradient variant of a brush describes a circle variant centered
+    /// in the middle
+    RadialGradient(RadialGradientBrush),
 }
 
 /// Construct a brush with transparent color
@@ -36,13 +39,16 @@ fn default() -> Self {
 
 impl Brush {
     /// If the brush is SolidColor, the contained color is returned.
-    /// If the brush is a LinearGradient, the color of the first stop is returned.
+    /// If the brush is a gradient, the color of the first stop is returned.
     pub fn color(&self) -> Color {
         match self {
             Brush::SolidColor(col) => *col,
             Brush::LinearGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::RadialGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
         }
     }
 
@@ -58,6 +64,7 @@ pub fn is_transparent(&self) -> bool {
         match self {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
+            Brush::RadialGradient(_) => false,
         }
     }
 }
@@ -94,6 +101,23 @@ pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
     }
 }
 
+/// The RadialGradientBrush describes a way of filling a shape with a circular gradient
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct RadialGradientBrush(SharedVector<GradientStop>);
+
+impl RadialGradientBrush {
+    /// Creates a new circle radial gradient, centered in the middle and described
+    /// by the provided color stops.
+    pub fn new_circle(stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        Self(stops.into_iter().collect())
+    }
+    /// Returns the color stops of the radial gradient.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        self.0.iter()
+    }
+}
+
 /// GradientStop describes a single color stop in a gradient. The colors between multiple
 /// stops are interpolated.
 #[repr(C)]
@@ -153,6 +177,42 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                     Brush::LinearGradient(new_grad)
                 }
             }
+            (Brush::SolidColor(col), Brush::RadialGradient(grad)) => {
+                let mut new_grad = grad.clone();
+                for x in new_grad.0.make_mut_slice().iter_mut() {
+                    x.color = col.interpolate(&x.color, t);
+                }
+                Brush::RadialGradient(new_grad)
+            }
+            (a @ Brush::RadialGradient(_), b @ Brush::SolidColor(_)) => {
+                Self::interpolate(b, a, 1. - t)
+            }
+            (Brush::RadialGradient(lhs), Brush::RadialGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate(target_value, self, 1. - t)
+                } else {
+                    let mut new_grad = lhs.clone();
+                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
+                    let mut rhs_stops = rhs.stops();
+                    while let (Some(s1), Some(s2)) = (iter.next(), rhs_stops.next()) {
+                        s1.color = s1.color.interpolate(&s2.color, t);
+                        s1.position = s1.position.interpolate(&s2.position, t);
+                    }
+                    for x in iter {
+                        x.position = x.position.interpolate(&1.0, t);
+                    }
+                    Brush::RadialGradient(new_grad)
+                }
+            }
+            // There is no meaningful interpolation between a linear and a radial gradient
+            (Brush::LinearGradient(_), Brush::RadialGradient(_))
+            | (Brush::RadialGradient(_), Brush::LinearGradient(_)) => {
+                if t < 0.5 {
+                    self.clone()
+                } else {
+                    target_value.clone()
+                }
+            }
         }
     }
 }
@@ -170,3 +230,19 @@ fn test_linear_gradient_encoding() {
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
 }
+
+#[test]
+fn test_radial_gradient_interpolation() {
+    let red = Color::from_argb_u8(255, 255, 0, 0);
+    let blue = Color::from_argb_u8(255, 0, 0, 255);
+    let grad = Brush::RadialGradient(RadialGradientBrush::new_circle([
+        GradientStop { position: 0.0, color: red },
+        GradientStop { position: 1.0, color: blue },
+    ]));
+    assert_eq!(grad.color(), red);
+    assert_eq!(Brush::SolidColor(red).interpolate(&grad, 1.), grad);
+    assert_eq!(grad.interpolate(&Brush::SolidColor(blue), 1.).color(), blue);
+    let linear = Brush::LinearGradient(LinearGradientBrush::new(90., []));
+    assert_eq!(grad.interpolate(&linear, 0.25), grad);
+    assert_eq!(grad.interpolate(&linear, 0.75), linear);
+}
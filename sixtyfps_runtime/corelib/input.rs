@@ -1,13 +1,16 @@
 // Copyright © SixtyFPS GmbH <info@sixtyfps.io>
 // SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
 
-/*! Module handling mouse events
+/*! Module handling mouse and touch events
 */
 #![warn(missing_docs)]
 
 use crate::graphics::Point;
 use crate::item_tree::{ItemVisitorResult, VisitChildrenResult};
-use crate::items::{ItemRc, ItemRef, ItemWeak, PointerEventButton};
+use crate::items::{
+    DragArea, DropArea, DropEvent, GestureArea, GestureTracker, ItemRc, ItemRef, ItemVTable,
+    ItemWeak, PointerEventButton,
+};
 use crate::window::WindowRc;
 use crate::Property;
 use crate::{component::ComponentRc, SharedString};
@@ -16,6 +19,7 @@
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
 use euclid::default::Vector2D;
+use vtable::HasStaticVTable;
 
 /// A Mouse event
 #[repr(C)]
@@ -63,6 +67,32 @@ pub fn translate(&mut self, vec: Vector2D<f32>) {
     }
 }
 
+/// The phase of a touch point, see [`TouchEvent`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TouchPhase {
+    /// A finger touched the screen
+    Started,
+    /// A finger moved on the screen
+    Moved,
+    /// A finger was lifted from the screen
+    Ended,
+    /// The system cancelled the tracking of the touch point
+    Cancelled,
+}
+
+/// A touch event, for one of the possibly many touch points on the screen
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TouchEvent {
+    /// Identifies the touch point for as long as the finger stays on the screen
+    pub id: u64,
+    /// What happened to this touch point
+    pub phase: TouchPhase,
+    /// The position of the touch point
+    pub pos: Point,
+}
+
 /// This value is returned by the `input_event` function of an Item
 /// to notify the run-time about how the event was handled and
 /// what the next steps are.
@@ -206,6 +236,107 @@ pub struct MouseInputState {
     item_stack: Vec<(ItemWeak, InputEventFilterResult)>,
     /// true if the top item of the stack has the mouse grab
     grabbed: bool,
+    /// The drag and drop operation in progress, if the grabber is a dragging `DragArea`
+    drag: Option<DragState>,
+}
+
+/// The state of a drag and drop operation started by a `DragArea`
+struct DragState {
+    /// The payload of the `DragArea`
+    event: DropEvent,
+    /// The `DropArea` under the pointer, and whether it accepts the drop
+    target: Option<(ItemWeak, bool)>,
+}
+
+/// Find the top-most item of type `T` under `pos` for which `filter` returns true,
+/// and the position relative to it
+fn find_item_at<T: HasStaticVTable<ItemVTable>>(
+    component: &ComponentRc,
+    pos: Point,
+    filter: impl Fn(Pin<&T>) -> bool,
+) -> Option<(ItemRc, Point)> {
+    let mut found = None;
+    crate::item_tree::visit_items(
+        component,
+        crate::item_tree::TraversalOrder::BackToFront,
+        |comp_rc, item, item_index, offset| {
+            let geom = item.as_ref().geometry().translate(*offset);
+            if let Some(item) = ItemRef::downcast_pin::<T>(item) {
+                if filter(item) && geom.contains(pos) {
+                    found = Some((ItemRc::new(comp_rc.clone(), item_index), pos - geom.origin));
+                }
+            }
+            ItemVisitorResult::Continue(geom.origin.to_vector())
+        },
+        Vector2D::new(0., 0.),
+    );
+    found.map(|(item, relative_pos)| (item, relative_pos.to_point()))
+}
+
+/// Dispatch the drag and drop operation to the `DropArea` under the pointer.
+/// Must be called after the event was sent to the grabber, which may be a `DragArea`.
+fn handle_drag(
+    component: &ComponentRc,
+    mouse_event: &MouseEvent,
+    mouse_input_state: &mut MouseInputState,
+) {
+    if mouse_input_state.drag.is_none() {
+        let grabber = match mouse_input_state.item_stack.last().and_then(|it| it.0.upgrade()) {
+            Some(grabber) => grabber,
+            None => return,
+        };
+        let grabber = grabber.borrow();
+        let drag_area = match ItemRef::downcast_pin::<DragArea>(grabber) {
+            Some(drag_area) if drag_area.dragging() => drag_area,
+            _ => return,
+        };
+        let event = DropEvent {
+            mime_type: drag_area.mime_type(),
+            data: drag_area.data(),
+            position: Default::default(),
+        };
+        mouse_input_state.drag = Some(DragState { event, target: None });
+    }
+    let drag = mouse_input_state.drag.as_mut().unwrap();
+
+    let target = mouse_event
+        .pos()
+        .and_then(|pos| find_item_at::<DropArea>(component, pos, |drop_area| drop_area.enabled()));
+    let previous_target = drag.target.as_ref().and_then(|(item, _)| item.upgrade());
+    if previous_target.as_ref() != target.as_ref().map(|(item, _)| item) {
+        if let Some(previous_target) = previous_target {
+            if let Some(drop_area) = ItemRef::downcast_pin::<DropArea>(previous_target.borrow()) {
+                drop_area.drag_leave();
+            }
+        }
+        drag.target = None;
+        if let Some((item, pos)) = &target {
+            drag.event.position = *pos;
+            let drop_area = ItemRef::downcast_pin::<DropArea>(item.borrow()).unwrap();
+            drag.target = Some((item.downgrade(), drop_area.drag_enter(&drag.event)));
+        }
+    }
+
+    match mouse_event {
+        MouseEvent::MouseMoved { .. }
+        | MouseEvent::MouseWheel { .. }
+        | MouseEvent::MousePressed { .. } => return,
+        MouseEvent::MouseReleased { .. } => {
+            if let Some((item, pos)) = target {
+                if let Some((_, true)) = drag.target {
+                    drag.event.position = pos;
+                    ItemRef::downcast_pin::<DropArea>(item.borrow())
+                        .unwrap()
+                        .perform_drop(&drag.event);
+                }
+            }
+        }
+        MouseEvent::MouseExit => {}
+    }
+    if let Some(target) = drag.target.take().and_then(|(item, _)| item.upgrade()) {
+        ItemRef::downcast_pin::<DropArea>(target.borrow()).unwrap().drag_leave();
+    }
+    mouse_input_state.drag = None;
 }
 
 /// Try to handle the mouse grabber. Return true if the event has handled, or false otherwise
@@ -289,8 +420,13 @@ pub fn process_mouse_input(
     mut mouse_input_state: MouseInputState,
 ) -> MouseInputState {
     if handle_mouse_grab(&mouse_event, window, &mut mouse_input_state) {
+        handle_drag(&component, &mouse_event, &mut mouse_input_state);
         return mouse_input_state;
     }
+    if mouse_input_state.drag.is_some() {
+        // The grabber went away: cancel the drag
+        handle_drag(&component, &MouseEvent::MouseExit, &mut mouse_input_state);
+    }
 
     send_exit_events(&mouse_input_state, mouse_event.pos(), window);
 
@@ -374,6 +510,65 @@ pub fn process_mouse_input(
     result
 }
 
+/// The state which a window should hold for the touch input
+#[derive(Default)]
+pub struct TouchInputState {
+    /// The gestures in progress on `GestureArea` items
+    trackers: Vec<GestureTracker>,
+    /// The touch point that is forwarded as mouse events
+    primary: Option<u64>,
+}
+
+/// Process the `touch_event` on the `component`.
+/// Touch points that start on an enabled `GestureArea` are used for the recognition of its
+/// gestures. Otherwise, the first touch point is converted to a mouse event, which is returned
+/// to be processed with [`process_mouse_input`].
+pub fn process_touch_input(
+    component: &ComponentRc,
+    touch_event: TouchEvent,
+    mut touch_input_state: TouchInputState,
+) -> (TouchInputState, Option<MouseEvent>) {
+    let trackers = &mut touch_input_state.trackers;
+    if let Some(tracker) = trackers.iter_mut().find(|t| t.contains(touch_event.id)) {
+        tracker.process(&touch_event);
+        trackers.retain(|t| !t.is_finished());
+        return (touch_input_state, None);
+    }
+    if touch_event.phase == TouchPhase::Started {
+        if let Some((area, relative_pos)) =
+            find_item_at::<GestureArea>(component, touch_event.pos, |area| area.enabled())
+        {
+            let index = match trackers.iter().position(|t| t.is_tracking(&area)) {
+                Some(index) => index,
+                None => {
+                    let origin = touch_event.pos - relative_pos.to_vector();
+                    trackers.push(GestureTracker::new(&area, origin));
+                    trackers.len() - 1
+                }
+            };
+            trackers[index].process(&touch_event);
+            return (touch_input_state, None);
+        }
+    }
+
+    let pos = touch_event.pos;
+    let button = PointerEventButton::left;
+    let is_primary = touch_input_state.primary == Some(touch_event.id);
+    let mouse_event = match touch_event.phase {
+        TouchPhase::Started if touch_input_state.primary.is_none() => {
+            touch_input_state.primary = Some(touch_event.id);
+            Some(MouseEvent::MousePressed { pos, button })
+        }
+        TouchPhase::Moved if is_primary => Some(MouseEvent::MouseMoved { pos }),
+        TouchPhase::Ended | TouchPhase::Cancelled if is_primary => {
+            touch_input_state.primary = None;
+            Some(MouseEvent::MouseReleased { pos, button })
+        }
+        _ => None,
+    };
+    (touch_input_state, mouse_event)
+}
+
 /// The TextCursorBlinker takes care of providing a toggled boolean property
 /// that can be used to animate a blinking cursor. It's typically stored in the
 /// Window using a Weak and set_binding() can be used to set up a binding on a given
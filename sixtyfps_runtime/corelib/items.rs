@@ -18,11 +18,12 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
+use crate::animations::Instant;
 use crate::component::ComponentVTable;
 use crate::graphics::{Brush, Color, Point, Rect};
 use crate::input::{
     FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, KeyEventType,
-    MouseEvent,
+    KeyboardModifiers, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
 use crate::layout::{LayoutInfo, Orientation};
@@ -41,6 +42,14 @@
 pub use text::*;
 mod image;
 pub use self::image::*;
+mod drag_and_drop;
+pub use drag_and_drop::*;
+mod gesture_area;
+pub use gesture_area::*;
+mod shortcut;
+pub use shortcut::*;
+mod timer;
+pub use timer::*;
 #[cfg(feature = "std")]
 mod path;
 #[cfg(feature = "std")]
@@ -54,7 +63,10 @@
 pub type VoidArg = ();
 type KeyEventArg = (KeyEvent,);
 type PointerEventArg = (PointerEvent,);
+type PointerScrollEventArg = (PointerScrollEvent,);
 type PointArg = (Point,);
+type DropEventArg = (DropEvent,);
+type SwipeDirectionArg = (SwipeDirection,);
 
 #[cfg(all(feature = "ffi", windows))]
 #[macro_export]
@@ -129,6 +141,10 @@ pub struct ItemVTable {
     pub focus_event:
         extern "C" fn(core::pin::Pin<VRef<ItemVTable>>, &FocusEvent, window: &WindowRc),
 
+    /// Returns the position of this item in the Tab focus chain, or a negative value if the
+    /// item can't currently receive the keyboard focus through Tab navigation.
+    pub tab_focus_index: extern "C" fn(core::pin::Pin<VRef<ItemVTable>>) -> i32,
+
     pub key_event: extern "C" fn(
         core::pin::Pin<VRef<ItemVTable>>,
         &KeyEvent,
@@ -183,6 +199,39 @@ pub fn index(&self) -> usize {
     pub fn component(&self) -> vtable::VRc<ComponentVTable> {
         self.component.clone()
     }
+
+    /// Returns the accessible role of the item.
+    pub fn accessible_role(&self) -> AccessibleRole {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        comp_ref_pin.as_ref().accessible_role(self.index)
+    }
+
+    /// Returns the value of an accessible property of the item, or an empty string if the
+    /// property is not set.
+    pub fn accessible_string_property(
+        &self,
+        what: crate::accessibility::AccessibleStringProperty,
+    ) -> SharedString {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        let mut result = Default::default();
+        comp_ref_pin.as_ref().accessible_string_property(self.index, what, &mut result);
+        result
+    }
+
+    /// Returns the ids that the item has in the .60 source. An item may have several ids when
+    /// it is the root of a component, or none.
+    pub fn element_ids(&self) -> SharedVector<SharedString> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        let mut result = Default::default();
+        comp_ref_pin.as_ref().element_ids(self.index, &mut result);
+        result
+    }
+}
+
+impl PartialEq for ItemRc {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && VRc::ptr_eq(&self.component, &other.component)
+    }
 }
 
 /// A Weak reference to an item that can be constructed from an ItemRc.
@@ -247,6 +296,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         (*backend).draw_rectangle(self)
     }
@@ -314,6 +367,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         (*backend).draw_border_rectangle(self)
     }
@@ -377,6 +434,9 @@ fn default() -> Self {
     }
 }
 
+/// The maximum time between two clicks of a `TouchArea` for them to be a double click
+const DOUBLE_CLICK_INTERVAL: core::time::Duration = core::time::Duration::from_millis(500);
+
 /// The implementation of the `TouchArea` element
 #[repr(C)]
 #[derive(FieldOffsets, Default, SixtyFPSElement)]
@@ -400,12 +460,48 @@ pub struct TouchArea {
     pub mouse_y: Property<f32>,
     pub mouse_cursor: Property<MouseCursor>,
     pub clicked: Callback<VoidArg>,
+    pub double_clicked: Callback<VoidArg>,
+    pub entered: Callback<VoidArg>,
+    pub exited: Callback<VoidArg>,
     pub moved: Callback<VoidArg>,
     pub pointer_event: Callback<PointerEventArg>,
+    pub scroll_event: Callback<PointerScrollEventArg, EventResult>,
     /// FIXME: remove this
     pub cached_rendering_data: CachedRenderingData,
     /// true when we are currently grabbing the mouse
     grabbed: Cell<bool>,
+    /// true when the last click may be the first one of a double click
+    clicked_once: Cell<bool>,
+    /// The time of the last click, in milliseconds, see [`Instant`]
+    last_click_time: Cell<u64>,
+}
+
+impl TouchArea {
+    fn set_hover(self: Pin<&Self>, hover: bool) {
+        if self.has_hover() == hover {
+            return;
+        }
+        Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(hover);
+        if hover {
+            Self::FIELD_OFFSETS.entered.apply_pin(self).call(&());
+        } else {
+            Self::FIELD_OFFSETS.exited.apply_pin(self).call(&());
+        }
+    }
+
+    fn click(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.clicked.apply_pin(self).call(&());
+        let now = crate::animations::current_tick();
+        if self.clicked_once.get()
+            && now - Instant(self.last_click_time.get()) <= DOUBLE_CLICK_INTERVAL
+        {
+            self.clicked_once.set(false);
+            Self::FIELD_OFFSETS.double_clicked.apply_pin(self).call(&());
+        } else {
+            self.clicked_once.set(true);
+            self.last_click_time.set(now.0);
+        }
+    }
 }
 
 impl Item for TouchArea {
@@ -433,7 +529,7 @@ fn input_event_filter_before_children(
             Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(pos.y);
         }
         let hovering = !matches!(event, MouseEvent::MouseExit);
-        Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(hovering);
+        self.set_hover(hovering);
         if hovering {
             window.set_mouse_cursor(self.mouse_cursor());
         }
@@ -447,17 +543,18 @@ fn input_event(
         _self_rc: &ItemRc,
     ) -> InputEventResult {
         if matches!(event, MouseEvent::MouseExit) {
-            Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(false);
+            self.set_hover(false);
             window.set_mouse_cursor(MouseCursor::default);
         }
         if !self.enabled() {
             return InputEventResult::EventIgnored;
         }
+        let modifiers = window.current_keyboard_modifiers();
         let result = if let MouseEvent::MouseReleased { pos, button } = event {
             if button == PointerEventButton::left
                 && euclid::rect(0., 0., self.width(), self.height()).contains(pos)
             {
-                Self::FIELD_OFFSETS.clicked.apply_pin(self).call(&());
+                self.click();
             }
             InputEventResult::EventAccepted
         } else {
@@ -472,10 +569,11 @@ fn input_event(
                     Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(pos.y);
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
                 }
-                Self::FIELD_OFFSETS
-                    .pointer_event
-                    .apply_pin(self)
-                    .call(&(PointerEvent { button, kind: PointerEventKind::down },));
+                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+                    button,
+                    kind: PointerEventKind::down,
+                    modifiers,
+                },));
             }
             MouseEvent::MouseExit => {
                 Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
@@ -483,6 +581,7 @@ fn input_event(
                     Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
                         button: PointerEventButton::none,
                         kind: PointerEventKind::cancel,
+                        modifiers,
                     },));
                 }
             }
@@ -491,10 +590,11 @@ fn input_event(
                 if button == PointerEventButton::left {
                     Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
                 }
-                Self::FIELD_OFFSETS
-                    .pointer_event
-                    .apply_pin(self)
-                    .call(&(PointerEvent { button, kind: PointerEventKind::up },));
+                Self::FIELD_OFFSETS.pointer_event.apply_pin(self).call(&(PointerEvent {
+                    button,
+                    kind: PointerEventKind::up,
+                    modifiers,
+                },));
             }
             MouseEvent::MouseMoved { .. } => {
                 return if self.grabbed.get() {
@@ -504,12 +604,18 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
-            MouseEvent::MouseWheel { .. } => {
+            MouseEvent::MouseWheel { delta, .. } => {
+                let event = PointerScrollEvent { delta_x: delta.x, delta_y: delta.y, modifiers };
+                let accepted = Self::FIELD_OFFSETS.scroll_event.apply_pin(self).call(&(event,))
+                    == EventResult::accept;
+                // When the event is rejected, it is propagated to the parents, such as a Flickable
                 return if self.grabbed.get() {
                     InputEventResult::GrabMouse
-                } else {
+                } else if accepted {
                     InputEventResult::EventAccepted
-                }
+                } else {
+                    InputEventResult::EventIgnored
+                };
             }
         };
         result
@@ -521,6 +627,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
 }
 
@@ -550,6 +660,21 @@ fn default() -> Self {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, strum::EnumString, strum::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+/// What is returned from the `close-requested` callback of a Window
+pub enum CloseRequestResponse {
+    hide_window,
+    keep_window_shown,
+}
+
+impl Default for CloseRequestResponse {
+    fn default() -> Self {
+        Self::hide_window
+    }
+}
+
 /// A runtime item that exposes key
 #[repr(C)]
 #[derive(FieldOffsets, Default, SixtyFPSElement)]
@@ -560,6 +685,8 @@ pub struct FocusScope {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub has_focus: Property<bool>,
+    pub enabled: Property<bool>,
+    pub tab_index: Property<i32>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
     /// FIXME: remove this
@@ -592,9 +719,9 @@ fn input_event(
         window: &WindowRc,
         self_rc: &ItemRc,
     ) -> InputEventResult {
-        /*if !self.enabled() {
+        if !self.enabled() {
             return InputEventResult::EventIgnored;
-        }*/
+        }
         if matches!(event, MouseEvent::MousePressed { .. }) && !self.has_focus() {
             window.clone().set_focus_item(self_rc);
         }
@@ -602,6 +729,9 @@ fn input_event(
     }
 
     fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        if !self.enabled() {
+            return KeyEventResult::EventIgnored;
+        }
         let r = match event.event_type {
             KeyEventType::KeyPressed => {
                 Self::FIELD_OFFSETS.key_pressed.apply_pin(self).call(&(event.clone(),))
@@ -627,6 +757,14 @@ fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &WindowRc) {
         }
     }
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
+
     fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
 }
 
@@ -698,6 +836,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         if self.clip() {
             let geometry = self.geometry();
@@ -768,6 +910,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         backend.apply_opacity(self.opacity());
     }
@@ -832,6 +978,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         (*backend).translate(self.origin_x(), self.origin_y());
         (*backend).rotate(self.angle());
@@ -921,6 +1071,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         let geometry = self.geometry();
         (*backend).combine_clip(euclid::rect(0., 0., geometry.width(), geometry.height()), 0., 0.)
@@ -978,9 +1132,40 @@ fn deref(&self) -> &Self::Target {
     core::ptr::drop_in_place(data);
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, strum::EnumString, strum::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+/// In which direction the iterations of an animation are played
+pub enum AnimationDirection {
+    /// Every iteration is played forward
+    normal,
+    /// Every iteration is played backward
+    reverse,
+    /// The first iteration is played forward, and the direction alternates for every iteration
+    alternate,
+    /// The first iteration is played backward, and the direction alternates for every iteration
+    alternate_reverse,
+}
+
+impl Default for AnimationDirection {
+    fn default() -> Self {
+        Self::normal
+    }
+}
+
+/// A keyframe of a keyframe animation
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KeyFrame {
+    /// The progress within an iteration, between 0 and 1
+    pub position: f32,
+    /// The value of the property at that position
+    pub value: f32,
+}
+
 /// The implementation of the `PropertyAnimation` element
 #[repr(C)]
-#[derive(FieldOffsets, Default, SixtyFPSElement, Clone, Debug)]
+#[derive(FieldOffsets, SixtyFPSElement, Clone, Debug)]
 #[pin]
 pub struct PropertyAnimation {
     #[rtti_field]
@@ -991,6 +1176,40 @@ pub struct PropertyAnimation {
     pub loop_count: i32,
     #[rtti_field]
     pub easing: crate::animations::EasingCurve,
+    /// The number of times the animation is played. A negative value means infinite
+    #[rtti_field]
+    pub iteration_count: f32,
+    #[rtti_field]
+    pub direction: AnimationDirection,
+    /// The intermediate values, sorted by position. If there is no keyframe at position 0 or 1,
+    /// the start and end values of the animation are used
+    #[rtti_field]
+    pub keyframes: SharedVector<KeyFrame>,
+}
+
+impl Default for PropertyAnimation {
+    fn default() -> Self {
+        Self {
+            delay: 0,
+            duration: 0,
+            loop_count: 0,
+            easing: Default::default(),
+            iteration_count: 1.,
+            direction: Default::default(),
+            keyframes: Default::default(),
+        }
+    }
+}
+
+impl PropertyAnimation {
+    /// Returns the total number of iterations, or None if the animation repeats forever
+    pub fn total_iterations(&self) -> Option<f64> {
+        if self.iteration_count < 0. || self.loop_count < 0 {
+            None
+        } else {
+            Some(self.iteration_count as f64 + self.loop_count as f64)
+        }
+    }
 }
 
 /// The implementation of the `Window` element
@@ -1007,6 +1226,9 @@ pub struct WindowItem {
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<f32>,
     pub default_font_weight: Property<i32>,
+    pub modal: Property<bool>,
+    pub transient: Property<bool>,
+    pub close_requested: Callback<VoidArg, CloseRequestResponse>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -1045,6 +1267,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
 }
 
@@ -1144,6 +1370,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         (*backend).draw_box_shadow(self)
     }
@@ -1174,6 +1404,26 @@ fn sixtyfps_get_ImageItemVTable() -> ImageItemVTable for ImageItem
     fn sixtyfps_get_ClippedImageVTable() -> ClippedImageVTable for ClippedImage
 }
 
+declare_item_vtable! {
+    fn sixtyfps_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn sixtyfps_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn sixtyfps_get_GestureAreaVTable() -> GestureAreaVTable for GestureArea
+}
+
+declare_item_vtable! {
+    fn sixtyfps_get_ShortcutVTable() -> ShortcutVTable for Shortcut
+}
+
+declare_item_vtable! {
+    fn sixtyfps_get_TimerItemVTable() -> TimerItemVTable for TimerItem
+}
+
 #[cfg(feature = "std")]
 declare_item_vtable! {
     fn sixtyfps_get_PathVTable() -> PathVTable for Path
@@ -1221,6 +1471,31 @@ fn default() -> Self {
     }
 }
 
+/// The role of an item, as exposed to assistive technologies such as screen readers.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, strum::EnumString, strum::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum AccessibleRole {
+    none,
+    button,
+    checkbox,
+    combobox,
+    groupbox,
+    list,
+    slider,
+    spinbox,
+    tab,
+    text,
+    text_input,
+    window,
+}
+
+impl Default for AccessibleRole {
+    fn default() -> Self {
+        Self::none
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, strum::EnumString, strum::Display)]
 #[repr(C)]
 #[allow(non_camel_case_types)]
@@ -1258,4 +1533,14 @@ fn default() -> Self {
 pub struct PointerEvent {
     pub button: PointerEventButton,
     pub kind: PointerEventKind,
+    pub modifiers: KeyboardModifiers,
+}
+
+/// Represents a mouse wheel or touchpad scroll event sent by the windowing system.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[repr(C)]
+pub struct PointerScrollEvent {
+    pub delta_x: f32,
+    pub delta_y: f32,
+    pub modifiers: KeyboardModifiers,
 }
@@ -0,0 +1,287 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+This module contains the builtin drag and drop related items.
+
+The items only track their own state: the drag itself is dispatched from the `DragArea`
+to the `DropArea` under the pointer by [`crate::input::process_mouse_input`].
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef, PointerEventButton, VoidArg};
+use crate::graphics::{Image, Point, Rect};
+use crate::input::{
+    FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowRc;
+use crate::{Callback, Property, SharedString};
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use sixtyfps_corelib_macros::*;
+
+/// The distance the pointer needs to move while pressed before a drag starts
+const DRAG_THRESHOLD: f32 = 4.;
+
+/// The payload of a drag and drop operation, as received by the `DropArea` callbacks.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[repr(C)]
+pub struct DropEvent {
+    /// The `mime-type` of the `DragArea` that started the drag
+    pub mime_type: SharedString,
+    /// The `data` of the `DragArea` that started the drag
+    pub data: SharedString,
+    /// The position of the pointer, relative to the `DropArea`
+    pub position: Point,
+}
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SixtyFPSElement)]
+#[pin]
+pub struct DragArea {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    pub image: Property<Image>,
+    pub dragging: Property<bool>,
+    pub drag_x: Property<f32>,
+    pub drag_y: Property<f32>,
+    pub started: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+    /// true when the left button was pressed and a drag may start
+    pressed: Cell<bool>,
+    /// The position of the left button press
+    pressed_x: Cell<f32>,
+    pressed_y: Cell<f32>,
+}
+
+impl DragArea {
+    fn press(self: Pin<&Self>, pos: Point) {
+        self.pressed.set(true);
+        self.pressed_x.set(pos.x);
+        self.pressed_y.set(pos.y);
+    }
+
+    /// Returns true if the pointer moved far enough from the press position to start a drag
+    fn exceeds_threshold(self: Pin<&Self>, pos: Point) -> bool {
+        self.pressed.get()
+            && (pos - Point::new(self.pressed_x.get(), self.pressed_y.get())).square_length()
+                > DRAG_THRESHOLD * DRAG_THRESHOLD
+    }
+
+    fn reset(self: Pin<&Self>) {
+        self.pressed.set(false);
+        Self::FIELD_OFFSETS.dragging.apply_pin(self).set(false);
+        Self::FIELD_OFFSETS.drag_x.apply_pin(self).set(0.);
+        Self::FIELD_OFFSETS.drag_y.apply_pin(self).set(0.);
+    }
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _window: &WindowRc) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(self.x(), self.y(), self.width(), self.height())
+    }
+
+    fn layout_info(self: Pin<&Self>, _orientation: Orientation, _window: &WindowRc) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        match event {
+            MouseEvent::MousePressed { pos, button: PointerEventButton::left } => {
+                self.press(pos);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            // Take the grab from the children once the pointer moved far enough
+            MouseEvent::MouseMoved { pos } if self.dragging() || self.exceeds_threshold(pos) => {
+                InputEventFilterResult::Intercept
+            }
+            // A child handled the press and release without starting a drag
+            MouseEvent::MouseReleased { .. } | MouseEvent::MouseExit if !self.dragging() => {
+                self.pressed.set(false);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            _ => InputEventFilterResult::ForwardAndInterceptGrab,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            self.reset();
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::MousePressed { pos, button: PointerEventButton::left } => {
+                self.press(pos);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::MouseMoved { pos } => {
+                if !self.pressed.get() {
+                    return InputEventResult::EventIgnored;
+                }
+                if !self.dragging() && self.exceeds_threshold(pos) {
+                    Self::FIELD_OFFSETS.dragging.apply_pin(self).set(true);
+                    Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                }
+                if self.dragging() {
+                    Self::FIELD_OFFSETS.drag_x.apply_pin(self).set(pos.x - self.pressed_x.get());
+                    Self::FIELD_OFFSETS.drag_y.apply_pin(self).set(pos.y - self.pressed_y.get());
+                }
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::MouseReleased { .. } | MouseEvent::MouseExit => {
+                let was_pressed = self.pressed.get();
+                self.reset();
+                if was_pressed {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::MousePressed { .. } | MouseEvent::MouseWheel { .. } => {
+                if self.pressed.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+        }
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
+    fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
+        // The children are the drag preview: they follow the pointer while dragging
+        if self.dragging() {
+            (*backend).translate(self.drag_x(), self.drag_y());
+        }
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DragArea,
+        CachedRenderingData,
+    > = DragArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SixtyFPSElement)]
+#[pin]
+pub struct DropArea {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl DropArea {
+    /// Called when a drag enters the area. Returns true if the drop would be accepted.
+    pub(crate) fn drag_enter(self: Pin<&Self>, event: &DropEvent) -> bool {
+        let accepted = Self::FIELD_OFFSETS.can_drop.apply_pin(self).call(&(event.clone(),));
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(accepted);
+        accepted
+    }
+
+    /// Called when a drag leaves the area, or when it is cancelled.
+    pub(crate) fn drag_leave(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+    }
+
+    /// Called when an accepted drag is released over the area.
+    pub(crate) fn perform_drop(self: Pin<&Self>, event: &DropEvent) {
+        self.drag_leave();
+        Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(event.clone(),));
+    }
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _window: &WindowRc) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(self.x(), self.y(), self.width(), self.height())
+    }
+
+    fn layout_info(self: Pin<&Self>, _orientation: Orientation, _window: &WindowRc) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
+    fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DropArea,
+        CachedRenderingData,
+    > = DropArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
@@ -0,0 +1,304 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+This module contains the `GestureArea` item, and the recognition of the gestures from the
+touch points that [`crate::input::process_touch_input`] dispatches to it.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{
+    Item, ItemConsts, ItemRc, ItemRef, ItemRendererRef, ItemWeak, PointArg, SwipeDirectionArg,
+    VoidArg,
+};
+use crate::animations::Instant;
+use crate::graphics::{Point, Rect};
+use crate::input::{
+    FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, MouseEvent,
+    TouchEvent, TouchPhase,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
+use crate::window::WindowRc;
+use crate::{Callback, Property};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use core::time::Duration;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
+use sixtyfps_corelib_macros::*;
+
+/// How long a touch point must stay down without moving to be a long press
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+/// How far a touch point may move and still be a long press
+const LONG_PRESS_TOLERANCE: f32 = 8.;
+/// The minimum distance a touch point must travel to be a swipe
+const SWIPE_DISTANCE: f32 = 50.;
+/// The maximum duration of a swipe
+const SWIPE_DURATION: Duration = Duration::from_millis(500);
+
+#[derive(Copy, Clone, Debug, PartialEq, strum::EnumString, strum::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+/// The direction of a swipe recognized by a `GestureArea`
+pub enum SwipeDirection {
+    left,
+    right,
+    up,
+    down,
+}
+
+impl Default for SwipeDirection {
+    fn default() -> Self {
+        Self::left
+    }
+}
+
+/// The implementation of the `GestureArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SixtyFPSElement)]
+#[pin]
+pub struct GestureArea {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    pub pinching: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub pinch_started: Callback<VoidArg>,
+    pub pinch_ended: Callback<VoidArg>,
+    pub swiped: Callback<SwipeDirectionArg>,
+    pub long_pressed: Callback<PointArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl GestureArea {
+    fn start_pinch(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+        Self::FIELD_OFFSETS.pinching.apply_pin(self).set(true);
+        Self::FIELD_OFFSETS.pinch_started.apply_pin(self).call(&());
+    }
+
+    fn update_pinch(self: Pin<&Self>, scale: f32, rotation: f32) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+    }
+
+    fn end_pinch(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.pinching.apply_pin(self).set(false);
+        Self::FIELD_OFFSETS.pinch_ended.apply_pin(self).call(&());
+    }
+}
+
+impl Item for GestureArea {
+    fn init(self: Pin<&Self>, _window: &WindowRc) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+    }
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(self.x(), self.y(), self.width(), self.height())
+    }
+
+    fn layout_info(self: Pin<&Self>, _orientation: Orientation, _window: &WindowRc) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
+    fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
+}
+
+impl ItemConsts for GestureArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        GestureArea,
+        CachedRenderingData,
+    > = GestureArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// Recognizes the gestures made with the touch points that started on a `GestureArea`
+pub(crate) struct GestureTracker {
+    area: ItemWeak,
+    /// The absolute position of the area when its first touch point started
+    origin: Point,
+    /// The id, start position and current position of the touch points, in window coordinates
+    points: Vec<(u64, Point, Point)>,
+    start_time: Instant,
+    /// The distance and the angle between the two first touch points when the pinch started
+    pinch_start: Option<(f32, f32)>,
+    /// Set when a second touch point was used or when the long press fired: this is no swipe
+    swipe_cancelled: Rc<Cell<bool>>,
+    long_press_timer: Timer,
+}
+
+impl GestureTracker {
+    pub(crate) fn new(area: &ItemRc, origin: Point) -> Self {
+        Self {
+            area: area.downgrade(),
+            origin,
+            points: Vec::new(),
+            start_time: Instant::now(),
+            pinch_start: None,
+            swipe_cancelled: Default::default(),
+            long_press_timer: Default::default(),
+        }
+    }
+
+    /// Returns true if the touch point with this id is handled by this tracker
+    pub(crate) fn contains(&self, id: u64) -> bool {
+        self.points.iter().any(|p| p.0 == id)
+    }
+
+    /// Returns true when all the touch points of this tracker ended
+    pub(crate) fn is_finished(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub(crate) fn is_tracking(&self, area: &ItemRc) -> bool {
+        self.area.upgrade().map_or(false, |item| item == *area)
+    }
+
+    /// The distance and the angle in degrees between the two first touch points
+    fn pinch_geometry(&self) -> (f32, f32) {
+        let v = self.points[1].2 - self.points[0].2;
+        (v.length(), v.angle_from_x_axis().to_degrees())
+    }
+
+    pub(crate) fn process(&mut self, event: &TouchEvent) {
+        let item = match self.area.upgrade() {
+            Some(item) => item,
+            None => {
+                self.points.clear();
+                return;
+            }
+        };
+        let area = match ItemRef::downcast_pin::<GestureArea>(item.borrow()) {
+            Some(area) => area,
+            None => return,
+        };
+        match event.phase {
+            TouchPhase::Started => {
+                self.points.push((event.id, event.pos, event.pos));
+                if self.points.len() == 1 {
+                    self.start_time = Instant::now();
+                    let area_weak = self.area.clone();
+                    let swipe_cancelled = self.swipe_cancelled.clone();
+                    let pos = event.pos - self.origin.to_vector();
+                    self.long_press_timer.start(
+                        TimerMode::SingleShot,
+                        LONG_PRESS_DURATION,
+                        move || {
+                            swipe_cancelled.set(true);
+                            if let Some(item) = area_weak.upgrade() {
+                                let area =
+                                    ItemRef::downcast_pin::<GestureArea>(item.borrow()).unwrap();
+                                GestureArea::FIELD_OFFSETS
+                                    .long_pressed
+                                    .apply_pin(area)
+                                    .call(&(pos,));
+                            }
+                        },
+                    );
+                } else {
+                    self.long_press_timer.stop();
+                    self.swipe_cancelled.set(true);
+                    if self.points.len() == 2 {
+                        self.pinch_start = Some(self.pinch_geometry());
+                        area.start_pinch();
+                    }
+                }
+            }
+            TouchPhase::Moved => {
+                if let Some(point) = self.points.iter_mut().find(|p| p.0 == event.id) {
+                    point.2 = event.pos;
+                    if (point.2 - point.1).square_length()
+                        > LONG_PRESS_TOLERANCE * LONG_PRESS_TOLERANCE
+                    {
+                        self.long_press_timer.stop();
+                    }
+                }
+                if let Some((start_distance, start_angle)) = self.pinch_start {
+                    let (distance, angle) = self.pinch_geometry();
+                    let scale = if start_distance > 0. { distance / start_distance } else { 1. };
+                    let mut rotation = angle - start_angle;
+                    if rotation > 180. {
+                        rotation -= 360.;
+                    } else if rotation <= -180. {
+                        rotation += 360.;
+                    }
+                    area.update_pinch(scale, rotation);
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let index = match self.points.iter().position(|p| p.0 == event.id) {
+                    Some(index) => index,
+                    None => return,
+                };
+                let (_, start, _) = self.points.remove(index);
+                if self.pinch_start.is_some() && self.points.len() < 2 {
+                    self.pinch_start = None;
+                    area.end_pinch();
+                }
+                if !self.points.is_empty() {
+                    return;
+                }
+                self.long_press_timer.stop();
+                let delta = event.pos - start;
+                if event.phase == TouchPhase::Ended
+                    && !self.swipe_cancelled.get()
+                    && delta.square_length() >= SWIPE_DISTANCE * SWIPE_DISTANCE
+                    && Instant::now() - self.start_time <= SWIPE_DURATION
+                {
+                    let direction = if delta.x.abs() > delta.y.abs() {
+                        if delta.x > 0. {
+                            SwipeDirection::right
+                        } else {
+                            SwipeDirection::left
+                        }
+                    } else if delta.y > 0. {
+                        SwipeDirection::down
+                    } else {
+                        SwipeDirection::up
+                    };
+                    GestureArea::FIELD_OFFSETS.swiped.apply_pin(area).call(&(direction,));
+                }
+            }
+        }
+    }
+}
@@ -102,6 +102,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut ItemRendererRef) {
         let clip = self.clip();
         if clip {
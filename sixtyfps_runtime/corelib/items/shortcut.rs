@@ -0,0 +1,125 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+This module contains the `Shortcut` item, which [`crate::window::Window::process_key_input`]
+activates when its key sequence is pressed, before the key event is delivered to the focus item.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{Item, ItemConsts, ItemRc, ItemRendererRef, VoidArg};
+use crate::graphics::Rect;
+use crate::input::{
+    FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowRc;
+use crate::{Callback, Property, SharedString};
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use sixtyfps_common::key_sequence::KeySequence;
+use sixtyfps_corelib_macros::*;
+
+/// The implementation of the `Shortcut` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SixtyFPSElement)]
+#[pin]
+pub struct Shortcut {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub keys: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub display_text: Property<SharedString>,
+    pub activated: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Shortcut {
+    /// Returns true if the shortcut is enabled and the key event matches its key sequence
+    pub(crate) fn matches(self: Pin<&Self>, event: &KeyEvent) -> bool {
+        if !self.enabled() {
+            return false;
+        }
+        let modifiers = event.modifiers;
+        KeySequence::parse(self.keys().as_str()).map_or(false, |sequence| {
+            sequence.matches(
+                event.text.as_str(),
+                modifiers.control,
+                modifiers.alt,
+                modifiers.shift,
+                modifiers.meta,
+            )
+        })
+    }
+}
+
+impl Item for Shortcut {
+    fn init(self: Pin<&Self>, _window: &WindowRc) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(self.x(), self.y(), self.width(), self.height())
+    }
+
+    fn layout_info(self: Pin<&Self>, _orientation: Orientation, _window: &WindowRc) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
+    fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
+}
+
+impl ItemConsts for Shortcut {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        Shortcut,
+        CachedRenderingData,
+    > = Shortcut::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// Returns the text shown in menus for the key sequence of a `Shortcut`, like `Ctrl+Shift+S`.
+/// Key sequences that can't be parsed are returned unchanged.
+pub fn key_sequence_display_text(keys: &SharedString) -> SharedString {
+    match KeySequence::parse(keys.as_str()) {
+        Ok(sequence) => alloc::format!("{}", sequence).into(),
+        Err(_) => keys.clone(),
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub extern "C" fn sixtyfps_key_sequence_display_text(keys: &SharedString, out: &mut SharedString) {
+    *out = key_sequence_display_text(keys);
+}
@@ -176,6 +176,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn render(self: Pin<&Self>, backend: &mut &mut dyn ItemRenderer) {
         (*backend).draw_text(self)
     }
@@ -249,6 +253,7 @@ pub struct TextInput {
     pub edited: Callback<VoidArg>,
     pub pressed: core::cell::Cell<bool>,
     pub single_line: Property<bool>,
+    pub tab_index: Property<i32>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -437,6 +442,14 @@ fn focus_event(self: Pin<&Self>, event: &FocusEvent, window: &WindowRc) {
         }
     }
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
+
     fn render(self: Pin<&Self>, backend: &mut &mut dyn ItemRenderer) {
         (*backend).draw_text_input(self)
     }
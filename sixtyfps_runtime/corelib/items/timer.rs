@@ -0,0 +1,176 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+This module contains the `Timer` element, which invokes its `triggered` callback periodically
+using a [`crate::timers::Timer`]. The timer is started or stopped when the `running` or
+`interval` properties change.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{Item, ItemConsts, ItemRc, ItemRendererRef, VoidArg};
+use crate::graphics::Rect;
+use crate::input::{
+    FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::properties::ChangeTracker;
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
+use crate::window::WindowRc;
+use crate::{Callback, Property};
+use alloc::boxed::Box;
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use sixtyfps_corelib_macros::*;
+
+/// The implementation of the `Timer` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SixtyFPSElement)]
+#[pin]
+pub struct TimerItem {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub interval: Property<i64>,
+    pub running: Property<bool>,
+    pub triggered: Callback<VoidArg>,
+    data: TimerItemDataBox,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl TimerItem {
+    /// Starts the timer if the element is running with a positive interval, stops it otherwise.
+    /// Restarting the timer resets the time until the next trigger.
+    fn update_timer(self: Pin<&Self>) {
+        let interval = self.interval();
+        if !self.running() || interval <= 0 {
+            self.data.timer.stop();
+            return;
+        }
+        let self_ptr = self.get_ref() as *const Self;
+        self.data.timer.start(
+            TimerMode::Repeated,
+            core::time::Duration::from_millis(interval as u64),
+            move || {
+                // Safety: the timer is owned by the item, and stopped when the item is dropped.
+                // The item is pinned, so the pointer is valid as long as the timer can fire.
+                let item = unsafe { Pin::new_unchecked(&*self_ptr) };
+                Self::FIELD_OFFSETS.triggered.apply_pin(item).call(&());
+            },
+        );
+    }
+}
+
+impl Item for TimerItem {
+    fn init(self: Pin<&Self>, _window: &WindowRc) {
+        let self_ptr = self.get_ref() as *const Self;
+        // Safety: the tracker is owned by the item, so the closures are dropped with it.
+        // The item is pinned, so the pointer is valid as long as they can be called.
+        let item = move || unsafe { Pin::new_unchecked(&*self_ptr) };
+        self.data
+            .tracker
+            .init(move || (item().running(), item().interval()), move || item().update_timer());
+        self.update_timer();
+    }
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(self.x(), self.y(), self.width(), self.height())
+    }
+
+    fn layout_info(self: Pin<&Self>, _orientation: Orientation, _window: &WindowRc) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &WindowRc,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
+    fn render(self: Pin<&Self>, _backend: &mut ItemRendererRef) {}
+}
+
+impl ItemConsts for TimerItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        TimerItem,
+        CachedRenderingData,
+    > = TimerItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The runtime state of the `Timer` element
+#[derive(Default)]
+pub struct TimerItemData {
+    timer: Timer,
+    /// Updates the timer when `running` or `interval` change
+    tracker: ChangeTracker,
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the Timer element
+pub struct TimerItemDataBox(core::ptr::NonNull<TimerItemData>);
+
+impl Default for TimerItemDataBox {
+    fn default() -> Self {
+        TimerItemDataBox(Box::leak(Box::new(TimerItemData::default())).into())
+    }
+}
+impl Drop for TimerItemDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in TimerItemDataBox::default
+        unsafe {
+            Box::from_raw(self.0.as_ptr());
+        }
+    }
+}
+impl core::ops::Deref for TimerItemDataBox {
+    type Target = TimerItemData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in TimerItemDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a TimerItemDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_timer_item_data_init(data: *mut TimerItemDataBox) {
+    core::ptr::write(data, TimerItemDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized TimerItemDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_timer_item_data_free(data: *mut TimerItemDataBox) {
+    core::ptr::drop_in_place(data);
+}
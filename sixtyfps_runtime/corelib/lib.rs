@@ -71,11 +71,14 @@ unsafe impl<T> Send for OnceCell<T> {}
     unsafe impl<T> Sync for OnceCell<T> {}
 }
 
+pub mod accessibility;
 pub mod animations;
 pub mod backend;
 pub mod callbacks;
 pub mod component;
 pub(crate) mod flickable;
+#[cfg(feature = "std")]
+pub mod future;
 pub mod graphics;
 pub mod input;
 pub mod item_rendering;
@@ -89,6 +92,7 @@ unsafe impl<T> Sync for OnceCell<T> {}
 pub mod string;
 pub mod tests;
 pub mod timers;
+pub mod translations;
 pub mod window;
 
 #[cfg(feature = "rtti")]
@@ -142,6 +146,8 @@ pub fn use_modules() -> usize {
             + window::ffi::sixtyfps_windowrc_drop as usize
             + component::ffi::sixtyfps_component_init_items as usize
             + timers::ffi::sixtyfps_timer_start as usize
+            + translations::ffi::sixtyfps_translate as usize
+            + animations::ffi::sixtyfps_custom_easing_curve as usize
             + graphics::color::ffi::sixtyfps_color_brighter as usize
             + graphics::image::ffi::sixtyfps_image_size as usize
     }
@@ -23,8 +23,11 @@
 #[cfg(not(feature = "std"))]
 use num_traits::float::Float;
 
+mod adapters;
+pub use adapters::{FilterModel, MapModel, SortModel};
+
 type DependencyListHead =
-    crate::properties::dependency_tracker::DependencyListHead<*const dyn ErasedRepeater>;
+    crate::properties::dependency_tracker::DependencyListHead<*const dyn ModelChangeListener>;
 type ComponentRc<C> = vtable::VRc<crate::component::ComponentVTable, C>;
 
 /// Represent a handle to a view that listens to changes to a model.
@@ -33,7 +36,7 @@
 #[derive(Clone)]
 pub struct ModelPeer {
     // FIXME: add a lifetime to ModelPeer so we can put the DependencyNode directly in the Repeater
-    inner: PinWeak<DependencyNode<*const dyn ErasedRepeater>>,
+    inner: PinWeak<DependencyNode<*const dyn ModelChangeListener>>,
 }
 
 /// This trait defines the interface that users of a model can use to track changes
@@ -111,6 +114,36 @@ pub fn row_removed(&self, index: usize, count: usize) {
                 .for_each(|p| unsafe { &**p }.row_removed(index, count))
         }
     }
+    /// Notify the peers that rows were moved: the `count` rows starting at `from` are removed
+    /// and inserted back so that the first of them ends up at index `to`.
+    ///
+    /// The views will reuse the components of the moved rows instead of re-creating them.
+    pub fn row_moved(&self, from: usize, to: usize, count: usize) {
+        if let Some(inner) = self.inner.get() {
+            inner.tracked_rows.borrow_mut().clear();
+            inner.model_row_data_dirty_property.mark_dirty();
+            inner
+                .as_ref()
+                .project_ref()
+                .peers
+                .for_each(|p| unsafe { &**p }.row_moved(from, to, count))
+        }
+    }
+    /// Notify the peers that the model has changed entirely: any row may have changed, and the
+    /// row count may be different.
+    ///
+    /// This is cheaper than removing all the rows and adding them back, because the views keep
+    /// their components and compare the new data of each row with the data its component
+    /// shows: only the components of the rows that changed are updated. If the row count
+    /// changed, components are created or destroyed at the end.
+    pub fn reset(&self) {
+        if let Some(inner) = self.inner.get() {
+            inner.model_row_count_dirty_property.mark_dirty();
+            inner.tracked_rows.borrow_mut().clear();
+            inner.model_row_data_dirty_property.mark_dirty();
+            inner.as_ref().project_ref().peers.for_each(|p| unsafe { &**p }.reset())
+        }
+    }
 }
 
 impl ModelTracker for ModelNotify {
@@ -330,6 +363,23 @@ pub fn remove(&self, index: usize) {
         self.array.borrow_mut().remove(index);
         self.notify.row_removed(index, 1)
     }
+
+    /// Replace the inner Vec with the new data
+    pub fn set_vec(&self, new: impl Into<Vec<T>>) {
+        *self.array.borrow_mut() = new.into();
+        self.notify.reset();
+    }
+
+    /// Moves the `count` rows starting at `from` so that the first of them ends up at index `to`.
+    /// This function panics if `from + count` or `to + count` is > row_count().
+    pub fn move_rows(&self, from: usize, to: usize, count: usize) {
+        {
+            let mut array = self.array.borrow_mut();
+            let moved: Vec<T> = array.drain(from..from + count).collect();
+            array.splice(to..to, moved);
+        }
+        self.notify.row_moved(from, to, count)
+    }
 }
 
 impl<T> From<Vec<T>> for VecModel<T> {
@@ -539,8 +589,9 @@ fn as_any(&self) -> &dyn core::any::Any {
 
 /// Component that can be instantiated by a repeater.
 pub trait RepeatedComponent: crate::component::Component {
-    /// The data corresponding to the model
-    type Data: 'static;
+    /// The data corresponding to the model. It is compared with the new data of the model so
+    /// that the component is only updated when it changed.
+    type Data: Clone + PartialEq + 'static;
 
     /// Update this component at the given index and the given data
     fn update(&self, index: usize, data: Self::Data);
@@ -572,8 +623,35 @@ enum RepeatedComponentState {
     /// The model data is stale and needs to be refreshed
     Dirty,
 }
+
+/// A component of the repeater, with its state and the row and data it was last updated with
+type RepeatedComponentEntry<C> = (
+    RepeatedComponentState,
+    Option<ComponentRc<C>>,
+    Option<(usize, <C as RepeatedComponent>::Data)>,
+);
+
+/// Updates the component of the entry with the data of `row`, unless it already shows the same
+/// data at the same row. The entry must have a component.
+fn update_repeated_component<C: RepeatedComponent>(
+    entry: &mut RepeatedComponentEntry<C>,
+    row: usize,
+    model: &dyn Model<Data = C::Data>,
+) {
+    let data = model.row_data(row).unwrap();
+    if entry
+        .2
+        .as_ref()
+        .map_or(true, |(shown_row, shown_data)| *shown_row != row || *shown_data != data)
+    {
+        entry.1.as_ref().unwrap().update(row, data.clone());
+        entry.2 = Some((row, data));
+    }
+    entry.0 = RepeatedComponentState::Clean;
+}
+
 struct RepeaterInner<C: RepeatedComponent> {
-    components: Vec<(RepeatedComponentState, Option<ComponentRc<C>>)>,
+    components: Vec<RepeatedComponentEntry<C>>,
     /// The model row (index) of the first component in the `components` vector.
     /// Only used for ListView
     offset: usize,
@@ -586,13 +664,33 @@ fn default() -> Self {
         RepeaterInner { components: Default::default(), offset: 0, cached_item_height: 0. }
     }
 }
-trait ErasedRepeater {
+
+/// Receives the notifications sent through a [`ModelNotify`].
+/// Implemented by the [`Repeater`] and by the model adapters
+trait ModelChangeListener {
     fn row_changed(&self, row: usize);
     fn row_added(&self, index: usize, count: usize);
     fn row_removed(&self, index: usize, count: usize);
+    fn row_moved(&self, from: usize, to: usize, count: usize);
+    fn reset(&self);
 }
 
-impl<C: RepeatedComponent> ErasedRepeater for Repeater<C> {
+/// Returns the row that was at `row` before `count` rows were moved from `from` to `to`,
+/// as described in [`ModelNotify::row_moved`]
+fn row_before_move(row: usize, from: usize, to: usize, count: usize) -> usize {
+    if row >= to && row < to + count {
+        return from + (row - to);
+    }
+    // The index in the model without the moved rows
+    let row = if row < to { row } else { row - count };
+    if row < from {
+        row
+    } else {
+        row + count
+    }
+}
+
+impl<C: RepeatedComponent> ModelChangeListener for Repeater<C> {
     /// Notify the peers that a specific row was changed
     fn row_changed(&self, row: usize) {
         self.is_dirty.set(true);
@@ -620,7 +718,7 @@ fn row_added(&self, mut index: usize, mut count: usize) {
         self.is_dirty.set(true);
         inner.components.splice(
             index..index,
-            core::iter::repeat((RepeatedComponentState::Dirty, None)).take(count),
+            core::iter::repeat_with(|| (RepeatedComponentState::Dirty, None, None)).take(count),
         );
     }
     /// Notify the peers that rows were removed
@@ -648,6 +746,38 @@ fn row_removed(&self, mut index: usize, mut count: usize) {
             c.0 = RepeatedComponentState::Dirty;
         }
     }
+    /// Notify the peers that rows were moved. The components are moved along with their row
+    fn row_moved(&self, from: usize, to: usize, count: usize) {
+        if count == 0 || from == to {
+            return;
+        }
+        self.is_dirty.set(true);
+        let mut inner = self.inner.borrow_mut();
+        let offset = inner.offset;
+        let mut old_components = core::mem::take(&mut inner.components);
+        inner.components = (offset..offset + old_components.len())
+            .map(|row| {
+                let old_row = row_before_move(row, from, to, count);
+                match old_row.checked_sub(offset).and_then(|i| old_components.get_mut(i)) {
+                    // The index changed, so the component needs to be updated
+                    Some(c) if old_row != row => {
+                        (RepeatedComponentState::Dirty, c.1.take(), c.2.take())
+                    }
+                    Some(c) => (c.0, c.1.take(), c.2.take()),
+                    None => (RepeatedComponentState::Dirty, None, None),
+                }
+            })
+            .collect();
+    }
+    /// Notify the peers that the whole model changed. The components are kept at their index,
+    /// and `ensure_updated` compares the data they show with the new data of their row to only
+    /// update the ones that changed. It also truncates or extends the list to the new row count
+    fn reset(&self) {
+        self.is_dirty.set(true);
+        for c in self.inner.borrow_mut().components.iter_mut() {
+            c.0 = RepeatedComponentState::Dirty;
+        }
+    }
 }
 
 /// This field is put in a component when using the `for` syntax
@@ -665,7 +795,7 @@ pub struct Repeater<C: RepeatedComponent> {
 
     /// Will be initialized when the ModelPeer is initialized.
     /// The DependencyNode points to self
-    peer: OnceCell<Pin<Rc<DependencyNode<*const dyn ErasedRepeater>>>>,
+    peer: OnceCell<Pin<Rc<DependencyNode<*const dyn ModelChangeListener>>>>,
 }
 
 impl<C: RepeatedComponent> Default for Repeater<C> {
@@ -704,9 +834,8 @@ fn model(self: Pin<&Self>) -> ModelHandle<C::Data> {
             if let ModelHandle(Some(m)) = model.get() {
                 let peer = self.peer.get_or_init(|| {
                     //Safety: we will reset it when we Drop the Repeater
-                    Rc::pin(DependencyNode::new(
-                        self.get_ref() as &dyn ErasedRepeater as *const dyn ErasedRepeater
-                    ))
+                    Rc::pin(DependencyNode::new(self.get_ref() as &dyn ModelChangeListener
+                        as *const dyn ModelChangeListener))
                 });
 
                 m.model_tracker()
@@ -736,7 +865,7 @@ fn ensure_updated_impl(
         count: usize,
     ) -> bool {
         let mut inner = self.inner.borrow_mut();
-        inner.components.resize_with(count, || (RepeatedComponentState::Dirty, None));
+        inner.components.resize_with(count, || (RepeatedComponentState::Dirty, None, None));
         let offset = inner.offset;
         let mut created = false;
         for (i, c) in inner.components.iter_mut().enumerate() {
@@ -745,8 +874,7 @@ fn ensure_updated_impl(
                     created = true;
                     c.1 = Some(init());
                 }
-                c.1.as_ref().unwrap().update(i + offset, model.row_data(i + offset).unwrap());
-                c.0 = RepeatedComponentState::Clean;
+                update_repeated_component(c, i + offset, &**model);
             }
         }
         self.is_dirty.set(false);
@@ -891,13 +1019,13 @@ fn set_offset(&self, offset: usize, count: usize) {
         if to_remove < inner.components.len() {
             inner.components.splice(
                 0..to_remove,
-                core::iter::repeat((RepeatedComponentState::Dirty, None))
+                core::iter::repeat_with(|| (RepeatedComponentState::Dirty, None, None))
                     .take(old_offset.saturating_sub(offset)),
             );
         } else {
             inner.components.truncate(0);
         }
-        inner.components.resize_with(count, || (RepeatedComponentState::Dirty, None));
+        inner.components.resize_with(count, || (RepeatedComponentState::Dirty, None, None));
         inner.offset = offset;
         self.is_dirty.set(true);
     }
@@ -907,11 +1035,8 @@ pub fn model_set_row_data(self: Pin<&Self>, row: usize, data: C::Data) {
         if let ModelHandle(Some(model)) = self.model() {
             model.set_row_data(row, data);
             if let Some(c) = self.inner.borrow_mut().components.get_mut(row) {
-                if c.0 == RepeatedComponentState::Dirty {
-                    if let Some(comp) = c.1.as_ref() {
-                        comp.update(row, model.row_data(row).unwrap());
-                        c.0 = RepeatedComponentState::Clean;
-                    }
+                if c.0 == RepeatedComponentState::Dirty && c.1.is_some() {
+                    update_repeated_component(c, row, &*model);
                 }
             }
         }
@@ -1070,3 +1195,33 @@ fn test_data_tracking() {
     model.insert(0, 255);
     assert!(tracker.is_dirty());
 }
+
+#[test]
+fn test_move_and_reset_tracking() {
+    let model: Rc<VecModel<u8>> = Rc::new(VecModel::from(vec![0, 1, 2, 3, 4]));
+    let handle = ModelHandle::new(model.clone());
+    let count_tracker = Box::pin(crate::properties::PropertyTracker::default());
+    let data_tracker = Box::pin(crate::properties::PropertyTracker::default());
+    let evaluate = || {
+        count_tracker.as_ref().evaluate(|| {
+            handle.model_tracker().track_row_count_changes();
+            handle.row_count()
+        });
+        data_tracker.as_ref().evaluate(|| {
+            handle.model_tracker().track_row_data_changes(1);
+            handle.row_data(1).unwrap()
+        })
+    };
+    assert_eq!(evaluate(), 1);
+
+    model.move_rows(0, 3, 2);
+    assert_eq!(model.iter().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+    assert!(!count_tracker.is_dirty());
+    assert!(data_tracker.is_dirty());
+    assert_eq!(evaluate(), 3);
+
+    model.set_vec(vec![5, 6]);
+    assert!(count_tracker.is_dirty());
+    assert!(data_tracker.is_dirty());
+    assert_eq!(evaluate(), 6);
+}
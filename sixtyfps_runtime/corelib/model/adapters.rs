@@ -0,0 +1,708 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+//! Models that wrap another model and present its rows filtered, sorted or mapped
+
+use super::{Model, ModelChangeListener, ModelNotify, ModelPeer, ModelTracker};
+use crate::properties::dependency_tracker::DependencyNode;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::cmp::Ordering;
+use core::pin::Pin;
+use once_cell::unsync::OnceCell;
+use pin_weak::rc::{PinWeak, Rc};
+
+/// Keeps a [`ModelChangeListener`] at a stable address so that it can be registered as a peer
+/// of the wrapped model. The peer is removed from the wrapped model when this is dropped.
+struct ListenerContainer<L: ModelChangeListener + 'static> {
+    listener: L,
+    peer: OnceCell<Pin<Rc<DependencyNode<*const dyn ModelChangeListener>>>>,
+}
+
+impl<L: ModelChangeListener + 'static> ListenerContainer<L> {
+    fn new(listener: L) -> Box<Self> {
+        Box::new(Self { listener, peer: Default::default() })
+    }
+
+    fn model_peer(&self) -> ModelPeer {
+        let peer = self.peer.get_or_init(|| {
+            // Safety: the node is removed from the list in our Drop implementation, and
+            // the listener does not move since it is always boxed
+            Rc::pin(DependencyNode::new(
+                &self.listener as &dyn ModelChangeListener as *const dyn ModelChangeListener,
+            ))
+        });
+        ModelPeer { inner: PinWeak::downgrade(peer.clone()) }
+    }
+}
+
+impl<L: ModelChangeListener + 'static> Drop for ListenerContainer<L> {
+    fn drop(&mut self) {
+        if let Some(peer) = self.peer.get() {
+            peer.remove();
+        }
+    }
+}
+
+/// Returns the row at which `row` ends up after `count` rows were moved from `from` to `to`,
+/// as described in [`ModelNotify::row_moved`]
+fn row_after_move(row: usize, from: usize, to: usize, count: usize) -> usize {
+    if row >= from && row < from + count {
+        return to + (row - from);
+    }
+    // The index in the model without the moved rows
+    let row = if row < from { row } else { row - count };
+    if row < to {
+        row
+    } else {
+        row + count
+    }
+}
+
+struct FilterModelInner<T> {
+    wrapped_model: Rc<dyn Model<Data = T>>,
+    filter_function: Box<dyn Fn(&T) -> bool>,
+    /// The sorted list of the rows of the wrapped model which are accepted by the filter
+    mapping: RefCell<Vec<usize>>,
+    notify: ModelNotify,
+}
+
+impl<T> FilterModelInner<T> {
+    fn accepts_row(&self, row: usize) -> bool {
+        self.wrapped_model.row_data(row).map_or(false, |data| (self.filter_function)(&data))
+    }
+
+    fn build_mapping(&self) -> Vec<usize> {
+        (0..self.wrapped_model.row_count()).filter(|row| self.accepts_row(*row)).collect()
+    }
+}
+
+impl<T> ModelChangeListener for FilterModelInner<T> {
+    fn row_changed(&self, row: usize) {
+        let accepted = self.accepts_row(row);
+        let mut mapping = self.mapping.borrow_mut();
+        let position = mapping.binary_search(&row);
+        match (position, accepted) {
+            (Ok(index), true) => {
+                drop(mapping);
+                self.notify.row_changed(index);
+            }
+            (Ok(index), false) => {
+                mapping.remove(index);
+                drop(mapping);
+                self.notify.row_removed(index, 1);
+            }
+            (Err(index), true) => {
+                mapping.insert(index, row);
+                drop(mapping);
+                self.notify.row_added(index, 1);
+            }
+            (Err(_), false) => {}
+        }
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        let added: Vec<usize> =
+            (index..index + count).filter(|row| self.accepts_row(*row)).collect();
+        let insertion_point = {
+            let mut mapping = self.mapping.borrow_mut();
+            let insertion_point = mapping.partition_point(|row| *row < index);
+            for row in &mut mapping[insertion_point..] {
+                *row += count;
+            }
+            mapping.splice(insertion_point..insertion_point, added.iter().copied());
+            insertion_point
+        };
+        if !added.is_empty() {
+            self.notify.row_added(insertion_point, added.len());
+        }
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        let (start, end) = {
+            let mut mapping = self.mapping.borrow_mut();
+            let start = mapping.partition_point(|row| *row < index);
+            let end = mapping.partition_point(|row| *row < index + count);
+            mapping.drain(start..end);
+            for row in &mut mapping[start..] {
+                *row -= count;
+            }
+            (start, end)
+        };
+        if end > start {
+            self.notify.row_removed(start, end - start);
+        }
+    }
+
+    fn row_moved(&self, from: usize, to: usize, count: usize) {
+        let (filtered_from, filtered_to, filtered_count) = {
+            let mut mapping = self.mapping.borrow_mut();
+            let filtered_from = mapping.partition_point(|row| *row < from);
+            let filtered_count = mapping.partition_point(|row| *row < from + count) - filtered_from;
+            for row in mapping.iter_mut() {
+                *row = row_after_move(*row, from, to, count);
+            }
+            mapping.sort_unstable();
+            (filtered_from, mapping.partition_point(|row| *row < to), filtered_count)
+        };
+        if filtered_count > 0 && filtered_from != filtered_to {
+            self.notify.row_moved(filtered_from, filtered_to, filtered_count);
+        }
+    }
+
+    fn reset(&self) {
+        *self.mapping.borrow_mut() = self.build_mapping();
+        self.notify.reset();
+    }
+}
+
+/// A model that only shows the rows of another model that are accepted by a filter function.
+///
+/// The FilterModel listens to the changes of the wrapped model and updates itself accordingly.
+/// Setting the data of a row changes the corresponding row in the wrapped model.
+///
+/// ```
+/// # use sixtyfps_corelib::model::*;
+/// # use std::rc::Rc;
+/// let model = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5, 6]));
+/// let even = FilterModel::new(model.clone(), |x| x % 2 == 0);
+/// assert_eq!(even.iter().collect::<Vec<_>>(), vec![2, 4, 6]);
+/// model.push(8);
+/// model.remove(1);
+/// assert_eq!(even.iter().collect::<Vec<_>>(), vec![4, 6, 8]);
+/// ```
+pub struct FilterModel<T: 'static>(Box<ListenerContainer<FilterModelInner<T>>>);
+
+impl<T: 'static> FilterModel<T> {
+    /// Creates a new FilterModel showing the rows of `wrapped_model` for which
+    /// `filter_function` returns true.
+    pub fn new(
+        wrapped_model: Rc<dyn Model<Data = T>>,
+        filter_function: impl Fn(&T) -> bool + 'static,
+    ) -> Self {
+        let inner = FilterModelInner {
+            wrapped_model,
+            filter_function: Box::new(filter_function),
+            mapping: Default::default(),
+            notify: Default::default(),
+        };
+        *inner.mapping.borrow_mut() = inner.build_mapping();
+        let container = ListenerContainer::new(inner);
+        container.listener.wrapped_model.model_tracker().attach_peer(container.model_peer());
+        Self(container)
+    }
+
+    /// Applies the filter function again on all the rows of the wrapped model.
+    ///
+    /// Call this when the filter function depends on state that has changed.
+    pub fn reset(&self) {
+        self.0.listener.reset();
+    }
+
+    /// Returns the row in the wrapped model that corresponds to `row` in this model.
+    ///
+    /// Panics if `row >= row_count()`.
+    pub fn unfiltered_row(&self, row: usize) -> usize {
+        self.0.listener.mapping.borrow()[row]
+    }
+}
+
+impl<T: 'static> Model for FilterModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.listener.mapping.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let source_row = self.0.listener.mapping.borrow().get(row).copied()?;
+        self.0.listener.wrapped_model.row_data(source_row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let source_row = self.0.listener.mapping.borrow().get(row).copied();
+        if let Some(source_row) = source_row {
+            self.0.listener.wrapped_model.set_row_data(source_row, data);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.listener.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+struct SortModelInner<T> {
+    wrapped_model: Rc<dyn Model<Data = T>>,
+    sort_function: Box<dyn Fn(&T, &T) -> Ordering>,
+    /// The rows of the wrapped model, in sorted order
+    mapping: RefCell<Vec<usize>>,
+    notify: ModelNotify,
+}
+
+impl<T> SortModelInner<T> {
+    fn build_mapping(&self) -> Vec<usize> {
+        let mut rows: Vec<(usize, T)> = (0..self.wrapped_model.row_count())
+            .filter_map(|row| Some((row, self.wrapped_model.row_data(row)?)))
+            .collect();
+        rows.sort_by(|a, b| (self.sort_function)(&a.1, &b.1));
+        rows.into_iter().map(|(row, _)| row).collect()
+    }
+
+    /// The position at which a row with `data` needs to be inserted in the mapping.
+    /// Equal rows are inserted after the existing ones, like a stable sort would do.
+    fn insertion_point(&self, mapping: &[usize], data: &T) -> usize {
+        mapping.partition_point(|row| {
+            self.wrapped_model
+                .row_data(*row)
+                .map_or(true, |other| (self.sort_function)(&other, data) != Ordering::Greater)
+        })
+    }
+}
+
+impl<T> ModelChangeListener for SortModelInner<T> {
+    fn row_changed(&self, row: usize) {
+        let data = match self.wrapped_model.row_data(row) {
+            Some(data) => data,
+            None => return,
+        };
+        let (old_index, new_index) = {
+            let mut mapping = self.mapping.borrow_mut();
+            let old_index = match mapping.iter().position(|r| *r == row) {
+                Some(old_index) => old_index,
+                None => return,
+            };
+            mapping.remove(old_index);
+            let new_index = self.insertion_point(&mapping, &data);
+            mapping.insert(new_index, row);
+            (old_index, new_index)
+        };
+        if old_index != new_index {
+            self.notify.row_moved(old_index, new_index, 1);
+        }
+        self.notify.row_changed(new_index);
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        let mut inserted = Vec::new();
+        {
+            let mut mapping = self.mapping.borrow_mut();
+            for row in mapping.iter_mut() {
+                if *row >= index {
+                    *row += count;
+                }
+            }
+            for row in index..index + count {
+                if let Some(data) = self.wrapped_model.row_data(row) {
+                    let insertion_point = self.insertion_point(&mapping, &data);
+                    mapping.insert(insertion_point, row);
+                    inserted.push(insertion_point);
+                }
+            }
+        }
+        for insertion_point in inserted {
+            self.notify.row_added(insertion_point, 1);
+        }
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        // In decreasing order, so that each index is still valid once the previous ones are removed
+        let mut removed = Vec::new();
+        {
+            let mut mapping = self.mapping.borrow_mut();
+            for i in (0..mapping.len()).rev() {
+                if mapping[i] >= index + count {
+                    mapping[i] -= count;
+                } else if mapping[i] >= index {
+                    mapping.remove(i);
+                    removed.push(i);
+                }
+            }
+        }
+        for i in removed {
+            self.notify.row_removed(i, 1);
+        }
+    }
+
+    fn row_moved(&self, from: usize, to: usize, count: usize) {
+        // The order does not change, only the rows of the wrapped model they refer to
+        for row in self.mapping.borrow_mut().iter_mut() {
+            *row = row_after_move(*row, from, to, count);
+        }
+    }
+
+    fn reset(&self) {
+        *self.mapping.borrow_mut() = self.build_mapping();
+        self.notify.reset();
+    }
+}
+
+/// A model that shows the rows of another model sorted with a comparison function.
+///
+/// The SortModel listens to the changes of the wrapped model and updates itself accordingly.
+/// Setting the data of a row changes the corresponding row in the wrapped model.
+///
+/// ```
+/// # use sixtyfps_corelib::model::*;
+/// # use std::rc::Rc;
+/// let model = Rc::new(VecModel::from(vec![3, 1, 2]));
+/// let sorted = SortModel::new_ascending(model.clone());
+/// assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// model.push(0);
+/// assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+/// ```
+pub struct SortModel<T: 'static>(Box<ListenerContainer<SortModelInner<T>>>);
+
+impl<T: 'static> SortModel<T> {
+    /// Creates a new SortModel showing the rows of `wrapped_model` in the order given by
+    /// `sort_function`.
+    pub fn new(
+        wrapped_model: Rc<dyn Model<Data = T>>,
+        sort_function: impl Fn(&T, &T) -> Ordering + 'static,
+    ) -> Self {
+        let inner = SortModelInner {
+            wrapped_model,
+            sort_function: Box::new(sort_function),
+            mapping: Default::default(),
+            notify: Default::default(),
+        };
+        *inner.mapping.borrow_mut() = inner.build_mapping();
+        let container = ListenerContainer::new(inner);
+        container.listener.wrapped_model.model_tracker().attach_peer(container.model_peer());
+        Self(container)
+    }
+
+    /// Creates a new SortModel showing the rows of `wrapped_model` in ascending order.
+    pub fn new_ascending(wrapped_model: Rc<dyn Model<Data = T>>) -> Self
+    where
+        T: Ord,
+    {
+        Self::new(wrapped_model, |a, b| a.cmp(b))
+    }
+
+    /// Sorts all the rows of the wrapped model again.
+    ///
+    /// Call this when the sort function depends on state that has changed.
+    pub fn reset(&self) {
+        self.0.listener.reset();
+    }
+
+    /// Returns the row in the wrapped model that corresponds to `row` in this model.
+    ///
+    /// Panics if `row >= row_count()`.
+    pub fn unsorted_row(&self, row: usize) -> usize {
+        self.0.listener.mapping.borrow()[row]
+    }
+}
+
+impl<T: 'static> Model for SortModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.listener.mapping.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let source_row = self.0.listener.mapping.borrow().get(row).copied()?;
+        self.0.listener.wrapped_model.row_data(source_row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let source_row = self.0.listener.mapping.borrow().get(row).copied();
+        if let Some(source_row) = source_row {
+            self.0.listener.wrapped_model.set_row_data(source_row, data);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.listener.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+/// A model that shows the rows of another model converted with a map function.
+///
+/// The notifications of the wrapped model are passed through unchanged.
+/// Setting the data of a row is ignored, unless the model was created with
+/// [`MapModel::new_with_inverse`]: the data is then converted back with the inverse function
+/// and set in the wrapped model.
+///
+/// ```
+/// # use sixtyfps_corelib::model::*;
+/// # use std::rc::Rc;
+/// let model = Rc::new(VecModel::from(vec![1, 2, 3]));
+/// let strings = MapModel::new(model.clone(), |x| x.to_string());
+/// assert_eq!(strings.iter().collect::<Vec<_>>(), vec!["1", "2", "3"]);
+///
+/// let editable = MapModel::new_with_inverse(model.clone(), |x| x * 10, |x| x / 10);
+/// editable.set_row_data(0, 40);
+/// assert_eq!(model.row_data(0), Some(4));
+/// ```
+pub struct MapModel<T, U> {
+    wrapped_model: Rc<dyn Model<Data = T>>,
+    map_function: Box<dyn Fn(T) -> U>,
+    inverse_function: Option<Box<dyn Fn(U) -> T>>,
+}
+
+impl<T, U> MapModel<T, U> {
+    /// Creates a new MapModel showing the rows of `wrapped_model` converted with
+    /// `map_function`. Setting the data of a row of this model is ignored.
+    pub fn new(
+        wrapped_model: Rc<dyn Model<Data = T>>,
+        map_function: impl Fn(T) -> U + 'static,
+    ) -> Self {
+        Self { wrapped_model, map_function: Box::new(map_function), inverse_function: None }
+    }
+
+    /// Creates a new MapModel showing the rows of `wrapped_model` converted with
+    /// `map_function`. Setting the data of a row sets the data converted with
+    /// `inverse_function` in the wrapped model.
+    pub fn new_with_inverse(
+        wrapped_model: Rc<dyn Model<Data = T>>,
+        map_function: impl Fn(T) -> U + 'static,
+        inverse_function: impl Fn(U) -> T + 'static,
+    ) -> Self {
+        Self {
+            wrapped_model,
+            map_function: Box::new(map_function),
+            inverse_function: Some(Box::new(inverse_function)),
+        }
+    }
+}
+
+impl<T: 'static, U: 'static> Model for MapModel<T, U> {
+    type Data = U;
+
+    fn row_count(&self) -> usize {
+        self.wrapped_model.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.wrapped_model.row_data(row).map(&self.map_function)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if let Some(inverse_function) = &self.inverse_function {
+            self.wrapped_model.set_row_data(row, inverse_function(data));
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.wrapped_model.model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::VecModel;
+
+    #[derive(Debug, PartialEq)]
+    enum Change {
+        Changed(usize),
+        Added(usize, usize),
+        Removed(usize, usize),
+        Moved(usize, usize, usize),
+        Reset,
+    }
+    use Change::*;
+
+    /// Records the notifications a model sends to its peers
+    #[derive(Default)]
+    struct TestView {
+        changes: RefCell<Vec<Change>>,
+    }
+
+    impl ModelChangeListener for TestView {
+        fn row_changed(&self, row: usize) {
+            self.changes.borrow_mut().push(Changed(row));
+        }
+        fn row_added(&self, index: usize, count: usize) {
+            self.changes.borrow_mut().push(Added(index, count));
+        }
+        fn row_removed(&self, index: usize, count: usize) {
+            self.changes.borrow_mut().push(Removed(index, count));
+        }
+        fn row_moved(&self, from: usize, to: usize, count: usize) {
+            self.changes.borrow_mut().push(Moved(from, to, count));
+        }
+        fn reset(&self) {
+            self.changes.borrow_mut().push(Reset);
+        }
+    }
+
+    fn attach_view(model: &dyn Model<Data = i32>) -> Box<ListenerContainer<TestView>> {
+        let view = ListenerContainer::new(TestView::default());
+        model.model_tracker().attach_peer(view.model_peer());
+        view
+    }
+
+    fn take_changes(view: &ListenerContainer<TestView>) -> Vec<Change> {
+        core::mem::take(&mut *view.listener.changes.borrow_mut())
+    }
+
+    #[test]
+    fn filter_model() {
+        let wrapped = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5, 6]));
+        let filter = FilterModel::new(wrapped.clone(), |x| x % 2 == 0);
+        let view = attach_view(&filter);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![2, 4, 6]);
+
+        wrapped.insert(0, 10);
+        wrapped.insert(0, 11);
+        assert_eq!(take_changes(&view), vec![Added(0, 1)]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![10, 2, 4, 6]);
+        assert_eq!(filter.unfiltered_row(1), 3);
+
+        wrapped.remove(4);
+        assert_eq!(take_changes(&view), vec![]);
+        wrapped.remove(3);
+        assert_eq!(take_changes(&view), vec![Removed(1, 1)]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![10, 4, 6]);
+
+        wrapped.set_row_data(2, 12);
+        assert_eq!(take_changes(&view), vec![Added(1, 1)]);
+        wrapped.set_row_data(1, 13);
+        assert_eq!(take_changes(&view), vec![Removed(0, 1)]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![12, 4, 6]);
+
+        filter.set_row_data(1, 8);
+        assert_eq!(take_changes(&view), vec![Changed(1)]);
+        assert_eq!(wrapped.row_data(3), Some(8));
+        filter.set_row_data(1, 9);
+        assert_eq!(take_changes(&view), vec![Removed(1, 1)]);
+        assert_eq!(wrapped.row_data(3), Some(9));
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![12, 6]);
+    }
+
+    #[test]
+    fn filter_model_reset() {
+        let wrapped = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        let limit = Rc::new(core::cell::Cell::new(2));
+        let filter = FilterModel::new(wrapped, {
+            let limit = limit.clone();
+            move |x| *x <= limit.get()
+        });
+        let view = attach_view(&filter);
+        assert_eq!(filter.row_count(), 2);
+        limit.set(3);
+        filter.reset();
+        assert_eq!(take_changes(&view), vec![Reset]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn sort_model() {
+        let wrapped = Rc::new(VecModel::from(vec![3, 1, 4, 1, 5]));
+        let sorted = SortModel::new_ascending(wrapped.clone());
+        let view = attach_view(&sorted);
+        assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![1, 1, 3, 4, 5]);
+        // stable: the first 1 of the wrapped model comes first
+        assert_eq!(sorted.unsorted_row(0), 1);
+        assert_eq!(sorted.unsorted_row(1), 3);
+
+        wrapped.insert(0, 2);
+        assert_eq!(take_changes(&view), vec![Added(2, 1)]);
+        assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![1, 1, 2, 3, 4, 5]);
+
+        wrapped.remove(1);
+        assert_eq!(take_changes(&view), vec![Removed(3, 1)]);
+        assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![1, 1, 2, 4, 5]);
+
+        wrapped.set_row_data(0, 6);
+        assert_eq!(take_changes(&view), vec![Moved(2, 4, 1), Changed(4)]);
+        assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![1, 1, 4, 5, 6]);
+
+        sorted.set_row_data(2, 3);
+        assert_eq!(take_changes(&view), vec![Changed(2)]);
+        assert_eq!(wrapped.iter().collect::<Vec<_>>(), vec![6, 1, 3, 1, 5]);
+    }
+
+    #[test]
+    fn filter_model_move_and_reset() {
+        let wrapped = Rc::new(VecModel::from(vec![0, 1, 2, 3, 4, 5, 6, 7]));
+        let filter = FilterModel::new(wrapped.clone(), |x| x % 2 == 0);
+        let view = attach_view(&filter);
+
+        wrapped.move_rows(1, 5, 3);
+        assert_eq!(wrapped.iter().collect::<Vec<_>>(), vec![0, 4, 5, 6, 7, 1, 2, 3]);
+        assert_eq!(take_changes(&view), vec![Moved(1, 3, 1)]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![0, 4, 6, 2]);
+
+        wrapped.move_rows(0, 1, 1);
+        assert_eq!(take_changes(&view), vec![Moved(0, 1, 1)]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![4, 0, 6, 2]);
+
+        wrapped.set_vec(vec![8, 9, 10]);
+        assert_eq!(take_changes(&view), vec![Reset]);
+        assert_eq!(filter.iter().collect::<Vec<_>>(), vec![8, 10]);
+    }
+
+    #[test]
+    fn sort_model_move_and_reset() {
+        let wrapped = Rc::new(VecModel::from(vec![3, 1, 2]));
+        let sorted = SortModel::new_ascending(wrapped.clone());
+        let view = attach_view(&sorted);
+
+        wrapped.move_rows(0, 2, 1);
+        assert_eq!(take_changes(&view), vec![]);
+        assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(sorted.unsorted_row(2), 2);
+
+        wrapped.set_vec(vec![5, 4]);
+        assert_eq!(take_changes(&view), vec![Reset]);
+        assert_eq!(sorted.iter().collect::<Vec<_>>(), vec![4, 5]);
+    }
+
+    #[test]
+    fn row_move_mapping() {
+        for (from, to, count) in [(0, 3, 2), (4, 1, 3), (2, 2, 1), (5, 0, 2)] {
+            for row in 0..8 {
+                let moved = row_after_move(row, from, to, count);
+                assert!(moved < 8);
+                assert_eq!(crate::model::row_before_move(moved, from, to, count), row);
+            }
+        }
+    }
+
+    #[test]
+    fn map_model() {
+        let wrapped = Rc::new(VecModel::from(vec![1, 2, 3]));
+        let map = MapModel::new(wrapped.clone(), |x| x * 10);
+        let view = attach_view(&map);
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+        wrapped.push(4);
+        assert_eq!(take_changes(&view), vec![Added(3, 1)]);
+        assert_eq!(map.row_data(3), Some(40));
+
+        map.set_row_data(0, 50);
+        assert_eq!(wrapped.row_data(0), Some(1));
+
+        let map = MapModel::new_with_inverse(wrapped.clone(), |x| x * 10, |x| x / 10);
+        let view = attach_view(&map);
+        map.set_row_data(1, 70);
+        assert_eq!(wrapped.row_data(1), Some(7));
+        assert_eq!(take_changes(&view), vec![Changed(1)]);
+        assert_eq!(map.row_data(1), Some(70));
+    }
+
+    #[test]
+    fn adapter_drop_detaches_from_wrapped_model() {
+        let wrapped = Rc::new(VecModel::from(vec![1, 2, 3]));
+        let filter = FilterModel::new(wrapped.clone(), |_| true);
+        drop(filter);
+        wrapped.push(4);
+        wrapped.remove(0);
+    }
+}
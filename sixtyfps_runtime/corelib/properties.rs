@@ -223,12 +223,13 @@ fn drop(&mut self) {
 type DependencyNode = dependency_tracker::DependencyNode<*const BindingHolder>;
 
 use alloc::boxed::Box;
-use alloc::rc::Rc;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
 use core::cell::{Cell, RefCell, UnsafeCell};
 use core::marker::PhantomPinned;
 use core::pin::Pin;
 
-use crate::items::PropertyAnimation;
+use crate::items::{AnimationDirection, PropertyAnimation};
 
 /// if a DependencyListHead points to that value, it is because the property is actually
 /// constant and cannot have dependencies
@@ -251,6 +252,7 @@ struct BindingVTable {
     intercept_set: unsafe fn(_self: *const BindingHolder, value: *const ()) -> bool,
     intercept_set_binding:
         unsafe fn(_self: *const BindingHolder, new_binding: *mut BindingHolder) -> bool,
+    current_velocity: unsafe fn(_self: *const BindingHolder) -> f32,
 }
 
 /// A binding trait object can be used to dynamically produces values for a property.
@@ -278,6 +280,13 @@ unsafe fn intercept_set(self: Pin<&Self>, _value: *const ()) -> bool {
     unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHolder) -> bool {
         false
     }
+
+    /// If this binding is a running animation, returns its velocity, in units of the property
+    /// value per second. This is used to keep the momentum when the animation is interrupted
+    /// by another one. The default implementation returns 0.
+    fn current_velocity(self: Pin<&Self>) -> f32 {
+        0.
+    }
 }
 
 impl<F: Fn(*mut ()) -> BindingResult> BindingCallable for F {
@@ -393,6 +402,11 @@ unsafe fn intercept_set_binding<B: BindingCallable>(
             .intercept_set_binding(new_binding)
     }
 
+    /// Safety: _self must be a pointer to a `BindingHolder<B>`
+    unsafe fn current_velocity<B: BindingCallable>(_self: *const BindingHolder) -> f32 {
+        Pin::new_unchecked(&((*(_self as *const BindingHolder<B>)).binding)).current_velocity()
+    }
+
     trait HasBindingVTable {
         const VT: &'static BindingVTable;
     }
@@ -403,6 +417,7 @@ impl<B: BindingCallable> HasBindingVTable for B {
             mark_dirty: mark_dirty::<B>,
             intercept_set: intercept_set::<B>,
             intercept_set_binding: intercept_set_binding::<B>,
+            current_velocity: current_velocity::<B>,
         };
     }
 
@@ -854,27 +869,21 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     ///
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
+    ///
+    /// If the property is already being animated, the new animation starts with the velocity
+    /// of the current one, which matters for spring animations.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(PropertyValueAnimationData::new(
-            self.get_internal(),
-            value,
-            animation_data,
-        ));
+        let mut d = PropertyValueAnimationData::new(self.get_internal(), value, animation_data);
+        let velocity = self.handle.access(|b| {
+            // Safety: b is a BindingHolder<T>
+            b.map_or(0., |b| unsafe { (b.vtable.current_velocity)(&*b as *const BindingHolder) })
+        });
+        d.set_initial_value_velocity(velocity);
         // Safety: the BindingCallable will cast its argument to T
         unsafe {
             self.handle.set_binding(
-                move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                    *(val as *mut T) = value;
-                    if finished {
-                        BindingResult::RemoveBinding
-                    } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
-                        BindingResult::KeepBinding
-                    }
-                },
+                AnimatedValueCallable { animation_data: RefCell::new(d) },
                 #[cfg(sixtyfps_debug_property)]
                 self.debug_name.borrow().as_str(),
             );
@@ -1116,13 +1125,47 @@ struct PropertyValueAnimationData<T> {
     to_value: T,
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
-    loop_iteration: i32,
+    /// The velocity at the start of a spring animation, in units of the distance between
+    /// `from_value` and `to_value` per second
+    initial_velocity: f32,
+    /// The velocity of a spring animation at the last computed value, in the same units
+    velocity: f32,
+    /// The velocity of the animation interrupted by a change of the binding, in units of the
+    /// property value per second. It becomes the initial velocity of the next animation.
+    interrupted_velocity: f32,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
-        Self { from_value, to_value, details, start_time, loop_iteration: 0 }
+        Self {
+            from_value,
+            to_value,
+            details,
+            start_time,
+            initial_velocity: 0.,
+            velocity: 0.,
+            interrupted_velocity: 0.,
+        }
+    }
+
+    /// The current velocity of a spring animation, in units of the property value per second.
+    /// Returns 0 if the distance between the values is unknown.
+    fn value_velocity(&self) -> f32 {
+        self.from_value
+            .signed_distance(&self.to_value)
+            .map_or(0., |distance| self.velocity * distance)
+    }
+
+    /// Sets the initial velocity of a spring animation from a velocity in units of the property
+    /// value per second. The velocity is converted to the distance between `from_value` and
+    /// `to_value`, so that an animation towards a value in the opposite direction starts by
+    /// moving away from it.
+    fn set_initial_value_velocity(&mut self, velocity: f32) {
+        self.initial_velocity = match self.from_value.signed_distance(&self.to_value) {
+            Some(distance) if distance != 0. => velocity / distance,
+            _ => 0.,
+        };
     }
 
     fn compute_interpolated_value(&mut self) -> (T, bool) {
@@ -1131,30 +1174,76 @@ fn compute_interpolated_value(&mut self) -> (T, bool) {
 
         let new_tick = crate::animations::current_tick();
 
-        let mut time_progress = new_tick.duration_since(self.start_time).as_millis();
-        if self.loop_iteration == 0 {
-            if time_progress >= delay {
-                time_progress -= delay;
-            } else {
+        if let crate::animations::EasingCurve::Spring(parameters) = &self.details.easing {
+            let elapsed = new_tick.duration_since(self.start_time);
+            let delay = core::time::Duration::from_millis(delay as u64);
+            if elapsed < delay {
                 return (self.from_value.clone(), false);
             }
+            let (t, velocity, at_rest) =
+                crate::animations::spring_curve(parameters, self.initial_velocity, elapsed - delay);
+            if at_rest {
+                self.velocity = 0.;
+                return (self.to_value.clone(), true);
+            }
+            self.velocity = velocity;
+            return (self.from_value.interpolate(&self.to_value, t), false);
         }
 
-        if time_progress >= duration {
-            if self.loop_iteration < self.details.loop_count || self.details.loop_count < 0 {
-                self.loop_iteration += (time_progress / duration) as i32;
-                time_progress %= duration;
-                self.start_time =
-                    new_tick - core::time::Duration::from_millis(time_progress as u64);
-            } else {
+        let time_progress = new_tick.duration_since(self.start_time).as_millis();
+        if time_progress < delay {
+            return (self.from_value.clone(), false);
+        }
+        let time_progress = time_progress - delay;
+
+        if duration == 0 {
+            return (self.to_value.clone(), true);
+        }
+        if let Some(total_iterations) = self.details.total_iterations() {
+            if time_progress as f64 >= total_iterations * duration as f64 {
                 return (self.to_value.clone(), true);
             }
         }
-        let progress = time_progress as f32 / self.details.duration as f32;
-        assert!(progress <= 1.);
-        let t = crate::animations::easing_curve(&self.details.easing, progress);
-        let val = self.from_value.interpolate(&self.to_value, t);
-        (val, false)
+
+        let iteration = time_progress / duration;
+        let progress = (time_progress % duration) as f32 / duration as f32;
+        let backward = match self.details.direction {
+            AnimationDirection::normal => false,
+            AnimationDirection::reverse => true,
+            AnimationDirection::alternate => iteration % 2 == 1,
+            AnimationDirection::alternate_reverse => iteration % 2 == 0,
+        };
+        let progress = if backward { 1. - progress } else { progress };
+        (self.value_at(progress), false)
+    }
+
+    /// Returns the value at the given progress within one iteration, taking the keyframes
+    /// into account. The easing curve applies to each segment between two keyframes.
+    fn value_at(&self, progress: f32) -> T {
+        let easing = &self.details.easing;
+        let mut segment_start = (0., self.from_value.clone());
+        let mut segment_end = (1., self.to_value.clone());
+        for keyframe in self.details.keyframes.iter() {
+            let value = match T::from_keyframe_value(keyframe.value) {
+                Some(value) => value,
+                None => break,
+            };
+            if progress < keyframe.position {
+                segment_end = (keyframe.position, value);
+                break;
+            }
+            segment_start = (keyframe.position, value);
+        }
+        let (start_position, start_value) = segment_start;
+        let (end_position, end_value) = segment_end;
+        if end_position <= start_position {
+            return start_value;
+        }
+        let t = crate::animations::easing_curve(
+            easing,
+            (progress - start_position) / (end_position - start_position),
+        );
+        start_value.interpolate(&end_value, t)
     }
 }
 
@@ -1201,13 +1290,14 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                 let value = &mut *(value as *mut T);
                 self.state.set(AnimatedBindingState::Animating);
                 let mut animation_data = self.animation_data.borrow_mut();
-                animation_data.loop_iteration = 0;
                 animation_data.from_value = value.clone();
                 self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
                 if let Some((details, start_time)) = (self.compute_animation_details)() {
                     animation_data.start_time = start_time;
                     animation_data.details = details;
                 }
+                let velocity = animation_data.interrupted_velocity;
+                animation_data.set_initial_value_velocity(velocity);
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
@@ -1226,10 +1316,44 @@ fn mark_dirty(self: Pin<&Self>) {
         }
         let original_dirty = self.original_binding.access(|b| b.unwrap().dirty.get());
         if original_dirty {
+            let mut animation_data = self.animation_data.borrow_mut();
+            // Keep the momentum if the animation is interrupted
+            let was_animating = self.state.get() == AnimatedBindingState::Animating;
+            animation_data.interrupted_velocity =
+                if was_animating { animation_data.value_velocity() } else { 0. };
             self.state.set(AnimatedBindingState::ShouldStart);
-            self.animation_data.borrow_mut().start_time = crate::animations::current_tick();
+            animation_data.start_time = crate::animations::current_tick();
         }
     }
+    fn current_velocity(self: Pin<&Self>) -> f32 {
+        if self.state.get() == AnimatedBindingState::Animating {
+            self.animation_data.borrow().value_velocity()
+        } else {
+            0.
+        }
+    }
+}
+
+/// The binding used by [`Property::set_animated_value`]
+struct AnimatedValueCallable<T> {
+    animation_data: RefCell<PropertyValueAnimationData<T>>,
+}
+
+impl<T: InterpolatedPropertyValue + Clone> BindingCallable for AnimatedValueCallable<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+        *(value as *mut T) = val;
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.set_has_active_animations());
+            BindingResult::KeepBinding
+        }
+    }
+    fn current_velocity(self: Pin<&Self>) -> f32 {
+        self.animation_data.borrow().value_velocity()
+    }
 }
 
 /// InterpolatedPropertyValue is a trait used to enable properties to be used with
@@ -1241,36 +1365,81 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns the value corresponding to the value of a [`KeyFrame`](crate::items::KeyFrame),
+    /// or None if this type cannot be animated with keyframes.
+    fn from_keyframe_value(_value: f32) -> Option<Self> {
+        None
+    }
+
+    /// Returns the difference between `target_value` and self as a number, or None if the
+    /// type is not a single number. This is used to carry the velocity of a spring animation
+    /// over to an animation towards another value.
+    fn signed_distance(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value)
+    }
+
+    fn signed_distance(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32) as i32
     }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value as i32)
+    }
+
+    fn signed_distance(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32) as Self
     }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value as i64)
+    }
+
+    fn signed_distance(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).min(255.).max(0.) as u8
     }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value.min(255.).max(0.) as u8)
+    }
+
+    fn signed_distance(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 #[cfg(test)]
 mod animation_tests {
     use super::*;
-    use crate::items::PropertyAnimation;
+    use crate::items::{KeyFrame, PropertyAnimation};
     use std::rc::Rc;
 
     #[derive(Default)]
@@ -1340,6 +1509,76 @@ fn properties_test_animation_triggered_by_set() {
         compo.width.handle.access(|binding| assert!(binding.is_none()));
     }
 
+    #[test]
+    fn properties_test_spring_animation_keeps_velocity() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            easing: crate::animations::EasingCurve::Spring([1., 100., 10.]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(1000, animation_details.clone());
+
+        let step = instant::Duration::from_millis(100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step));
+        let width = get_prop_value(&compo.width);
+        assert!(width > 0 && width < 1000);
+
+        // Interrupting the animation keeps the momentum, unlike a new animation from the same value
+        compo.width.set_animated_value(2000, animation_details.clone());
+        let other = Property::new(width);
+        other.set_animated_value(2000, animation_details);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step + step / 2));
+        assert!(get_prop_value(&compo.width) > get_prop_value(&other));
+
+        // The spring comes to rest, whatever the duration
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 2000);
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn properties_test_spring_animation_reversed_keeps_direction() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            easing: crate::animations::EasingCurve::Spring([1., 100., 10.]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(1000, animation_details.clone());
+
+        let step = instant::Duration::from_millis(50);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step));
+        let width = get_prop_value(&compo.width);
+        assert!(width > 0 && width < 1000);
+
+        // Going back to the start, the momentum first carries the value further away from it,
+        // while a new animation from the same value goes straight back
+        compo.width.set_animated_value(0, animation_details.clone());
+        let other = Property::new(width);
+        other.set_animated_value(0, animation_details);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step + step / 2));
+        assert!(get_prop_value(&compo.width) > width);
+        assert!(get_prop_value(&other) < width);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 0);
+    }
+
     #[test]
     fn properties_test_delayed_animation_triggered_by_set() {
         let compo = Component::new_test_component();
@@ -1653,6 +1892,133 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    #[test]
+    fn test_alternate_direction() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 3.,
+            direction: AnimationDirection::alternate,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2 + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn test_infinite_reverse() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: -1.,
+            direction: AnimationDirection::reverse,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 100 + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        // the animation is still running
+        compo.width.handle.access(|binding| assert!(binding.is_some()));
+    }
+
+    #[test]
+    fn test_fractional_iteration_count() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.5,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn test_keyframes() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            keyframes: [KeyFrame { position: 0.5, value: 300. }].iter().cloned().collect(),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 300);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 250);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
 }
 
 /// Value of the state property
@@ -1737,6 +2103,7 @@ fn default() -> Self {
             mark_dirty: |_, _| (),
             intercept_set: |_, _| false,
             intercept_set_binding: |_, _| false,
+            current_velocity: |_| 0.,
         };
 
         let holder = BindingHolder {
@@ -1849,6 +2216,7 @@ impl<B: PropertyChangeHandler> HasBindingVTable for B {
                 mark_dirty: mark_dirty::<B>,
                 intercept_set: |_, _| false,
                 intercept_set_binding: |_, _| false,
+                current_velocity: |_| 0.,
             };
         }
 
@@ -1866,6 +2234,129 @@ impl<B: PropertyChangeHandler> HasBindingVTable for B {
     }
 }
 
+#[cfg(all(not(feature = "std"), feature = "unsafe_single_core"))]
+use crate::unsafe_single_core::thread_local;
+
+thread_local!(
+    /// The change trackers that got notified and whose handlers have not been run yet
+    static PENDING_CHANGE_TRACKERS: RefCell<Vec<Weak<ChangeTrackerInner>>> = RefCell::default()
+);
+
+struct ChangeTrackerNotifier(RefCell<Weak<ChangeTrackerInner>>);
+
+impl PropertyChangeHandler for ChangeTrackerNotifier {
+    fn notify(&self) {
+        let inner = self.0.borrow().clone();
+        let was_empty = PENDING_CHANGE_TRACKERS.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            let was_empty = pending.is_empty();
+            pending.push(inner);
+            was_empty
+        });
+        if was_empty {
+            // Wake up the event loop so that the handlers are run after the current event
+            crate::timers::Timer::single_shot(
+                core::time::Duration::from_millis(0),
+                ChangeTracker::run_change_handlers,
+            );
+        }
+    }
+}
+
+struct ChangeTrackerInner {
+    tracker: PropertyTracker<ChangeTrackerNotifier>,
+    /// Evaluate the tracked value and return true if it is different from the previous time
+    eval_fn: Box<dyn Fn() -> bool>,
+    notify_fn: Box<dyn Fn()>,
+}
+
+impl ChangeTrackerInner {
+    /// Re-evaluate the tracked value, returns true if it changed
+    fn evaluate(&self) -> bool {
+        // Safety: the tracker is never moved out of the Rc
+        let tracker = unsafe { Pin::new_unchecked(&self.tracker) };
+        tracker.evaluate_as_dependency_root(|| (self.eval_fn)())
+    }
+}
+
+/// A ChangeTracker calls a handler after the value returned by a function
+/// has changed.
+///
+/// Unlike a [`PropertyTracker`] with a change handler, the handler is not called when
+/// a dependency is marked dirty, but later, from [`ChangeTracker::run_change_handlers`],
+/// which the event loop runs after processing the current events.
+#[derive(Default)]
+pub struct ChangeTracker {
+    inner: Cell<Option<Rc<ChangeTrackerInner>>>,
+}
+
+impl ChangeTracker {
+    /// Initialize the tracker.
+    ///
+    /// `eval_fn` is evaluated right away to record its value and dependencies. After
+    /// any of these dependencies changed, `eval_fn` is evaluated again and `notify_fn`
+    /// is called if the new value is different.
+    /// Any previous initialization is discarded.
+    pub fn init<T: PartialEq + 'static>(
+        &self,
+        eval_fn: impl Fn() -> T + 'static,
+        notify_fn: impl Fn() + 'static,
+    ) {
+        let value = RefCell::new(None::<T>);
+        self.init_raw(
+            Box::new(move || {
+                let new_value = eval_fn();
+                let changed = value.borrow().as_ref() != Some(&new_value);
+                if changed {
+                    *value.borrow_mut() = Some(new_value);
+                }
+                changed
+            }),
+            Box::new(notify_fn),
+        )
+    }
+
+    fn init_raw(&self, eval_fn: Box<dyn Fn() -> bool>, notify_fn: Box<dyn Fn()>) {
+        let inner = Rc::new(ChangeTrackerInner {
+            tracker: PropertyTracker::new_with_change_handler(ChangeTrackerNotifier(
+                Default::default(),
+            )),
+            eval_fn,
+            notify_fn,
+        });
+        *inner.tracker.holder.binding.0.borrow_mut() = Rc::downgrade(&inner);
+        inner.evaluate();
+        self.inner.set(Some(inner));
+    }
+
+    /// Run the handlers of all the change trackers whose value has changed.
+    ///
+    /// Handlers that change properties observed by other trackers cause these trackers to
+    /// be processed as well, until no more change is pending.
+    pub fn run_change_handlers() {
+        /// Guard against handlers that keep on changing the value of each other
+        const MAX_ITERATIONS: usize = 100;
+        for _ in 0..MAX_ITERATIONS {
+            let pending = PENDING_CHANGE_TRACKERS.with(|p| core::mem::take(&mut *p.borrow_mut()));
+            if pending.is_empty() {
+                return;
+            }
+            for inner in pending.into_iter().filter_map(|weak| weak.upgrade()) {
+                if inner.evaluate() {
+                    (inner.notify_fn)();
+                }
+            }
+        }
+        // Leave the remaining handlers for the next iteration of the event loop
+        if PENDING_CHANGE_TRACKERS.with(|p| !p.borrow().is_empty()) {
+            crate::timers::Timer::single_shot(
+                core::time::Duration::from_millis(0),
+                ChangeTracker::run_change_handlers,
+            );
+        }
+    }
+}
+
 #[test]
 fn test_property_listener_scope() {
     let scope = Box::pin(PropertyTracker::default());
@@ -2001,6 +2492,53 @@ fn test_nested_property_tracker_evaluate_if_dirty() {
     assert_eq!(r, 12);
 }
 
+#[test]
+fn test_change_tracker() {
+    let prop1 = Rc::pin(Property::new(42));
+    let prop2 = Rc::pin(Property::new(10));
+    let result = Rc::new(Cell::new(0));
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        {
+            let prop1 = prop1.clone();
+            let prop2 = prop2.clone();
+            move || prop1.as_ref().get() + prop2.as_ref().get()
+        },
+        {
+            let prop1 = prop1.clone();
+            let result = result.clone();
+            move || result.set(prop1.as_ref().get())
+        },
+    );
+    // The handler is not called at initialization
+    ChangeTracker::run_change_handlers();
+    assert_eq!(result.get(), 0);
+
+    // The handler is deferred until the handlers are run
+    prop1.as_ref().set(43);
+    assert_eq!(result.get(), 0);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(result.get(), 43);
+
+    // No handler call if the value did not change
+    result.set(0);
+    prop1.as_ref().set(44);
+    prop2.as_ref().set(9);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(result.get(), 0);
+
+    prop2.as_ref().set(100);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(result.get(), 44);
+
+    // No more calls once the tracker is dropped
+    result.set(0);
+    drop(tracker);
+    prop1.as_ref().set(1);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(result.get(), 0);
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     use super::*;
@@ -2456,4 +2994,59 @@ pub struct PropertyTrackerOpaque {
     pub unsafe extern "C" fn sixtyfps_property_tracker_drop(handle: *mut PropertyTrackerOpaque) {
         core::ptr::drop_in_place(handle as *mut PropertyTracker);
     }
+
+    #[repr(C)]
+    /// Opaque type representing the ChangeTracker
+    pub struct ChangeTrackerOpaque {
+        inner: *const c_void,
+    }
+
+    static_assertions::assert_eq_align!(ChangeTrackerOpaque, ChangeTracker);
+    static_assertions::assert_eq_size!(ChangeTrackerOpaque, ChangeTracker);
+
+    /// Initialize the ChangeTracker.
+    /// `out` is assumed to be uninitialized
+    /// sixtyfps_change_tracker_drop need to be called after that
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_change_tracker_construct(out: *mut ChangeTrackerOpaque) {
+        core::ptr::write(out as *mut ChangeTracker, ChangeTracker::default());
+    }
+
+    /// Initialize the change tracker with the functions evaluating the value and running the handler.
+    /// `eval_fn` must return true if the value changed since the last time it was called.
+    /// `drop_user_data` is called when the user_data is no longer in use.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_change_tracker_init(
+        handle: *const ChangeTrackerOpaque,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+        eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+        notify_fn: extern "C" fn(user_data: *mut c_void),
+    ) {
+        struct CUserData {
+            user_data: *mut c_void,
+            drop_user_data: Option<extern "C" fn(*mut c_void)>,
+        }
+
+        impl Drop for CUserData {
+            fn drop(&mut self) {
+                if let Some(x) = self.drop_user_data {
+                    x(self.user_data)
+                }
+            }
+        }
+
+        let data = Rc::new(CUserData { user_data, drop_user_data });
+        let notify_data = data.clone();
+        (*(handle as *const ChangeTracker)).init_raw(
+            Box::new(move || eval_fn(data.user_data)),
+            Box::new(move || notify_fn(notify_data.user_data)),
+        );
+    }
+
+    /// Destroy handle
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_change_tracker_drop(handle: *mut ChangeTrackerOpaque) {
+        core::ptr::drop_in_place(handle as *mut ChangeTracker);
+    }
 }
@@ -40,15 +40,22 @@ pub trait ValueType: 'static + Default + Clone $(+ TryInto<$ty> + TryFrom<$ty>)*
     crate::items::ImageRendering,
     crate::input::KeyEvent,
     crate::items::EventResult,
+    crate::items::CloseRequestResponse,
     crate::Brush,
     crate::items::FillRule,
     crate::items::MouseCursor,
     crate::items::DialogButtonRole,
     crate::items::StandardButtonKind,
+    crate::items::SwipeDirection,
     crate::graphics::Point,
     crate::items::PointerEvent,
+    crate::items::PointerScrollEvent,
+    crate::items::DropEvent,
     crate::items::PointerEventButton,
     crate::items::PointerEventKind,
+    crate::items::AnimationDirection,
+    crate::items::AccessibleRole,
+    crate::SharedVector<crate::items::KeyFrame>,
 ];
 
 /// What kind of animation is on a binding
@@ -5,21 +5,168 @@
 #![warn(missing_docs)]
 #![allow(unsafe_code)]
 
-use crate::input::{KeyEvent, KeyEventType, KeyboardModifiers, MouseEvent};
+use crate::animations::Instant;
+use crate::component::ComponentRc;
+use crate::graphics::{Point, Rect};
+use crate::input::{KeyEvent, KeyEventType, KeyboardModifiers, MouseEvent, TouchEvent, TouchPhase};
+use crate::item_tree::{ItemVisitorResult, TraversalOrder};
+use crate::items::{ItemRc, ItemRef, PointerEventButton};
 use crate::window::WindowRc;
-use crate::SharedString;
+use crate::{SharedString, SharedVector};
+use alloc::vec::Vec;
+use core::pin::Pin;
+#[cfg(not(feature = "std"))]
+use num_traits::float::Float;
+
+/// Maximum number of steps [`sixtyfps_run_until_idle`] takes before giving up, so that
+/// a single shot timer that keeps re-arming itself does not make the test hang.
+const MAX_IDLE_STEPS: usize = 10_000;
+
+/// Time step used by [`sixtyfps_run_until_idle`] while animations are running.
+const ANIMATION_FRAME_MS: u64 = 16;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe_single_core"))]
+use crate::unsafe_single_core::thread_local;
+
+thread_local!(static MOCKED_TIME : core::cell::Cell<Instant> = core::cell::Cell::default());
+
+#[cfg(feature = "std")]
+type PostedEvent = alloc::boxed::Box<dyn FnOnce() + Send>;
+
+/// The events posted to the mocked event loops, with the thread running the event loop they
+/// were posted from, or None if they were posted from another thread.
+#[cfg(feature = "std")]
+type PostedEvents = Vec<(Option<std::thread::ThreadId>, PostedEvent)>;
+
+#[cfg(feature = "std")]
+static POSTED_EVENTS: once_cell::sync::Lazy<std::sync::Mutex<PostedEvents>> =
+    once_cell::sync::Lazy::new(Default::default);
+
+#[cfg(feature = "std")]
+thread_local!(static RUNS_MOCKED_EVENT_LOOP : core::cell::Cell<bool> = core::cell::Cell::new(false));
+
+/// Marks the current thread as running a mocked event loop, as each test does with its own
+/// mocked clock. The events posted from this thread are then only processed by this thread,
+/// so that tests running in parallel do not process each other's events.
+///
+/// Backends used for testing call this when creating a window. The thread calling
+/// [`sixtyfps_mock_elapsed_time`] or [`sixtyfps_run_until_idle`] is marked as well.
+#[cfg(feature = "std")]
+pub fn set_runs_mocked_event_loop() {
+    RUNS_MOCKED_EVENT_LOOP.with(|runs| runs.set(true));
+}
+
+/// Returns the time elapsed on the mocked clock of the current thread.
+///
+/// Backends used for testing return this from [`crate::backend::Backend::duration_since_start`],
+/// so that timers only fire when the test advances the time with [`sixtyfps_mock_elapsed_time`].
+pub fn mocked_duration_since_start() -> core::time::Duration {
+    MOCKED_TIME.with(|time| core::time::Duration::from_millis(time.get().0))
+}
+
+/// Queue an event posted to the event loop of a backend used for testing.
+///
+/// The event is invoked the next time [`sixtyfps_mock_elapsed_time`] or
+/// [`sixtyfps_run_until_idle`] is called, by the same thread if it runs a mocked event loop
+/// (see [`set_runs_mocked_event_loop`]), otherwise by the first thread that processes events.
+#[cfg(feature = "std")]
+pub fn post_mocked_event(event: PostedEvent) {
+    let thread =
+        RUNS_MOCKED_EVENT_LOOP.with(|runs| runs.get()).then(|| std::thread::current().id());
+    POSTED_EVENTS.lock().unwrap().push((thread, event));
+}
+
+/// Invoke the events queued with [`post_mocked_event`]. Returns true if there was any.
+fn process_posted_events() -> bool {
+    #[cfg(feature = "std")]
+    {
+        set_runs_mocked_event_loop();
+        let current_thread = std::thread::current().id();
+        let events = {
+            let mut posted_events = POSTED_EVENTS.lock().unwrap();
+            let (events, others) =
+                core::mem::take(&mut *posted_events).into_iter().partition::<Vec<_>, _>(
+                    |(thread, _)| thread.map_or(true, |thread| thread == current_thread),
+                );
+            *posted_events = others;
+            events
+        };
+        let any_event = !events.is_empty();
+        for (_, event) in events {
+            event();
+        }
+        any_event
+    }
+    #[cfg(not(feature = "std"))]
+    false
+}
+
+/// Advance the mocked clock and the animation tick by `step`, then fire the expired timers.
+fn advance_mocked_time(step: core::time::Duration) {
+    MOCKED_TIME.with(|time| time.set(time.get() + step));
+    crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+        let tick = driver.current_tick() + step;
+        driver.update_animations(tick)
+    });
+    crate::timers::TimerList::maybe_activate_timers();
+    crate::properties::ChangeTracker::run_change_handlers();
+}
 
 /// SixtyFPS animations do not use real time, but use a mocked time.
 /// Normally, the event loop update the time of the animation using
 /// real time, but in tests, it is more convenient to use the fake time.
-/// This function will add some milliseconds to the fake time
+/// This function will add some milliseconds to the fake time.
+///
+/// The events posted to the event loop are processed, and the time is advanced in steps so
+/// that every timer expiring in between fires at its own timeout, in order. It also runs the
+/// pending property change handlers.
 #[no_mangle]
 pub extern "C" fn sixtyfps_mock_elapsed_time(time_in_ms: u64) {
-    crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
-        let mut tick = driver.current_tick();
-        tick += core::time::Duration::from_millis(time_in_ms);
-        driver.update_animations(tick)
-    })
+    process_posted_events();
+    let mut remaining = core::time::Duration::from_millis(time_in_ms);
+    loop {
+        let step = match crate::timers::TimerList::next_timeout() {
+            Some(timeout) if timeout > Instant::now() => (timeout - Instant::now()).min(remaining),
+            // The timer is already expired (or the backend does not use the mocked clock):
+            // make sure we still make progress.
+            Some(_) => core::time::Duration::from_millis(1).min(remaining),
+            None => remaining,
+        };
+        advance_mocked_time(step);
+        remaining -= step;
+        process_posted_events();
+        if remaining.is_zero() {
+            break;
+        }
+    }
+}
+
+/// Process the events posted to the event loop and advance the mocked time until there is
+/// nothing left to do: no posted event, no running animation and no pending single shot timer.
+///
+/// Repeated timers keep firing while the time advances, but they are not waited for since
+/// they never expire for good.
+#[no_mangle]
+pub extern "C" fn sixtyfps_run_until_idle() {
+    for _ in 0..MAX_IDLE_STEPS {
+        if process_posted_events() {
+            crate::properties::ChangeTracker::run_change_handlers();
+            continue;
+        }
+        if crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.has_active_animations())
+        {
+            sixtyfps_mock_elapsed_time(ANIMATION_FRAME_MS);
+            continue;
+        }
+        match crate::timers::TimerList::next_single_shot_timeout() {
+            Some(timeout) => {
+                let now = Instant::now();
+                let delay = if timeout > now { (timeout - now).as_millis() as u64 } else { 0 };
+                sixtyfps_mock_elapsed_time(delay.max(1));
+            }
+            None => return,
+        }
+    }
 }
 
 /// Simulate a click on a position within the component.
@@ -30,6 +177,11 @@ pub extern "C" fn sixtyfps_send_mouse_click(
     y: f32,
     window: &WindowRc,
 ) {
+    // Like the events from the windowing system, the click is ignored while a modal child window
+    // is shown
+    if window.is_blocked_by_modal_child_window() {
+        return;
+    }
     let mut state = crate::input::MouseInputState::default();
     let pos = euclid::point2(x, y);
 
@@ -41,14 +193,14 @@ pub extern "C" fn sixtyfps_send_mouse_click(
     );
     state = crate::input::process_mouse_input(
         component.clone(),
-        MouseEvent::MousePressed { pos, button: crate::items::PointerEventButton::left },
+        MouseEvent::MousePressed { pos, button: PointerEventButton::left },
         window,
         state,
     );
     sixtyfps_mock_elapsed_time(50);
     crate::input::process_mouse_input(
         component.clone(),
-        MouseEvent::MouseReleased { pos, button: crate::items::PointerEventButton::left },
+        MouseEvent::MouseReleased { pos, button: PointerEventButton::left },
         window,
         state,
     );
@@ -118,3 +270,417 @@ macro_rules! debug_log {
         }
     }
 }
+
+/// Write in `result` a textual dump of the accessibility tree of the component,
+/// as formatted by the `Display` implementation of [`crate::accessibility::AccessibilityNode`].
+#[no_mangle]
+pub extern "C" fn sixtyfps_accessibility_tree_dump(
+    component: &crate::component::ComponentRc,
+    result: &mut SharedString,
+) {
+    *result = alloc::format!("{}", crate::accessibility::accessibility_tree(component)).into();
+}
+
+/// The number of mouse move events sent by [`ElementHandle::drag_by`] between the press and
+/// the release.
+const DRAG_STEPS: u16 = 10;
+
+/// The distance from the center of the element to each finger when [`ElementHandle::pinch`]
+/// starts.
+const PINCH_RADIUS: f32 = 20.;
+
+/// A handle to an element of a component, to inspect it and send it input events in tests.
+///
+/// Element handles are obtained with [`ElementHandle::find_all_by_id`] or
+/// [`ElementHandle::find_all_by_text`]. The mouse events are sent to the window at the center of
+/// the element, as a user would do, so they are received by the element under that position.
+#[derive(Clone)]
+pub struct ElementHandle {
+    item: ItemRc,
+    window: WindowRc,
+}
+
+impl ElementHandle {
+    /// Creates a handle for the given item, shown in the window.
+    pub fn new(item: ItemRc, window: WindowRc) -> Self {
+        Self { item, window }
+    }
+
+    /// Returns the elements of the component that have the given `id` in the .60 source, in
+    /// the order in which they are drawn. Dashes and underscores are equivalent in the id, as in
+    /// the .60 language.
+    pub fn find_all_by_id(component: &ComponentRc, window: &WindowRc, id: &str) -> Vec<Self> {
+        let id = id.replace('_', "-");
+        find_items(component, |item_rc, _| item_rc.element_ids().iter().any(|x| x.as_str() == id))
+            .into_iter()
+            .map(|item| Self::new(item, window.clone()))
+            .collect()
+    }
+
+    /// Returns the elements of the component whose text or `accessible-label` is equal to
+    /// `text`, in the order in which they are drawn. The text of the `Text` and `TextInput`
+    /// elements is matched.
+    pub fn find_all_by_text(component: &ComponentRc, window: &WindowRc, text: &str) -> Vec<Self> {
+        find_items(component, |item_rc, item| {
+            let item_text = if let Some(text_item) =
+                ItemRef::downcast_pin::<crate::items::Text>(item)
+            {
+                text_item.text()
+            } else if let Some(text_input) = ItemRef::downcast_pin::<crate::items::TextInput>(item)
+            {
+                text_input.text()
+            } else {
+                Default::default()
+            };
+            item_text.as_str() == text
+                || item_rc
+                    .accessible_string_property(
+                        crate::accessibility::AccessibleStringProperty::Label,
+                    )
+                    .as_str()
+                    == text
+        })
+        .into_iter()
+        .map(|item| Self::new(item, window.clone()))
+        .collect()
+    }
+
+    /// Returns the item of this element.
+    pub fn item(&self) -> &ItemRc {
+        &self.item
+    }
+
+    /// Returns the ids of the element in the .60 source.
+    pub fn element_ids(&self) -> SharedVector<SharedString> {
+        self.item.element_ids()
+    }
+
+    /// Returns the geometry of the element, relative to the window.
+    pub fn absolute_geometry(&self) -> Rect {
+        let mut geometry = self.item.borrow().as_ref().geometry();
+        let mut parent = self.item.parent_item().upgrade();
+        while let Some(parent_item) = parent {
+            geometry.origin += parent_item.borrow().as_ref().geometry().origin.to_vector();
+            parent = parent_item.parent_item().upgrade();
+        }
+        geometry
+    }
+
+    fn center(&self) -> Point {
+        self.absolute_geometry().center()
+    }
+
+    fn send_mouse_event(&self, event: MouseEvent) {
+        self.window.clone().process_mouse_input(event);
+    }
+
+    /// Moves the mouse over the center of the element.
+    pub fn hover(&self) {
+        self.send_mouse_event(MouseEvent::MouseMoved { pos: self.center() });
+    }
+
+    /// Clicks with the left button at the center of the element.
+    pub fn click(&self) {
+        let pos = self.center();
+        self.send_mouse_event(MouseEvent::MouseMoved { pos });
+        self.send_mouse_event(MouseEvent::MousePressed { pos, button: PointerEventButton::left });
+        sixtyfps_mock_elapsed_time(50);
+        self.send_mouse_event(MouseEvent::MouseReleased { pos, button: PointerEventButton::left });
+    }
+
+    /// Clicks twice in a row at the center of the element.
+    pub fn double_click(&self) {
+        self.click();
+        self.click();
+    }
+
+    /// Presses the left button at the center of the element, moves the mouse by `dx` and `dy`
+    /// in several steps, and releases the button.
+    pub fn drag_by(&self, dx: f32, dy: f32) {
+        let start = self.center();
+        self.send_mouse_event(MouseEvent::MouseMoved { pos: start });
+        self.send_mouse_event(MouseEvent::MousePressed {
+            pos: start,
+            button: PointerEventButton::left,
+        });
+        let mut pos = start;
+        for step in 1..=DRAG_STEPS {
+            let progress = step as f32 / DRAG_STEPS as f32;
+            pos = start + euclid::vec2(dx * progress, dy * progress);
+            sixtyfps_mock_elapsed_time(16);
+            self.send_mouse_event(MouseEvent::MouseMoved { pos });
+        }
+        self.send_mouse_event(MouseEvent::MouseReleased { pos, button: PointerEventButton::left });
+    }
+
+    /// Sends a mouse wheel event at the center of the element. `dx` and `dy` are the amount of
+    /// pixels to scroll.
+    pub fn scroll(&self, dx: f32, dy: f32) {
+        let pos = self.center();
+        self.send_mouse_event(MouseEvent::MouseMoved { pos });
+        self.send_mouse_event(MouseEvent::MouseWheel { pos, delta: euclid::point2(dx, dy) });
+    }
+
+    fn send_touch_event(&self, id: u64, phase: TouchPhase, pos: Point) {
+        self.window.clone().process_touch_input(TouchEvent { id, phase, pos });
+    }
+
+    /// Touches the center of the element with two fingers, and moves them in several steps
+    /// until the distance between them is multiplied by `scale` and they turned by `rotation`
+    /// degrees, before lifting them.
+    pub fn pinch(&self, scale: f32, rotation: f32) {
+        let center = self.center();
+        let finger_offset = |progress: f32| {
+            let radius = PINCH_RADIUS * (1. + (scale - 1.) * progress);
+            let angle = (rotation * progress).to_radians();
+            euclid::vec2(radius * angle.cos(), radius * angle.sin())
+        };
+        self.send_touch_event(1, TouchPhase::Started, center - finger_offset(0.));
+        self.send_touch_event(2, TouchPhase::Started, center + finger_offset(0.));
+        let mut offset = finger_offset(0.);
+        for step in 1..=DRAG_STEPS {
+            offset = finger_offset(step as f32 / DRAG_STEPS as f32);
+            sixtyfps_mock_elapsed_time(16);
+            self.send_touch_event(1, TouchPhase::Moved, center - offset);
+            self.send_touch_event(2, TouchPhase::Moved, center + offset);
+        }
+        self.send_touch_event(1, TouchPhase::Ended, center - offset);
+        self.send_touch_event(2, TouchPhase::Ended, center + offset);
+    }
+
+    /// Touches the center of the element with one finger, moves it quickly by `dx` and `dy`
+    /// in several steps, and lifts it.
+    pub fn swipe(&self, dx: f32, dy: f32) {
+        let start = self.center();
+        self.send_touch_event(1, TouchPhase::Started, start);
+        let mut pos = start;
+        for step in 1..=DRAG_STEPS {
+            let progress = step as f32 / DRAG_STEPS as f32;
+            pos = start + euclid::vec2(dx * progress, dy * progress);
+            sixtyfps_mock_elapsed_time(16);
+            self.send_touch_event(1, TouchPhase::Moved, pos);
+        }
+        self.send_touch_event(1, TouchPhase::Ended, pos);
+    }
+
+    /// Touches the center of the element with one finger for a second without moving it.
+    pub fn long_press(&self) {
+        let pos = self.center();
+        self.send_touch_event(1, TouchPhase::Started, pos);
+        sixtyfps_mock_elapsed_time(1000);
+        self.send_touch_event(1, TouchPhase::Ended, pos);
+    }
+
+    /// Gives the keyboard focus to the first enabled `TextInput` or `FocusScope` within the
+    /// element, including the element itself. Returns false if there is no such item.
+    pub fn focus(&self) -> bool {
+        let root = self.window.component();
+        let focus_item = find_items(&root, |item_rc, item| {
+            let enabled =
+                if let Some(text_input) = ItemRef::downcast_pin::<crate::items::TextInput>(item) {
+                    text_input.enabled()
+                } else if let Some(focus_scope) =
+                    ItemRef::downcast_pin::<crate::items::FocusScope>(item)
+                {
+                    focus_scope.enabled()
+                } else {
+                    false
+                };
+            enabled && self.contains(item_rc)
+        })
+        .into_iter()
+        .next();
+        match focus_item {
+            Some(focus_item) => {
+                self.window.clone().set_focus_item(&focus_item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns true if `item` is the item of this element or one of its descendants.
+    fn contains(&self, item: &ItemRc) -> bool {
+        let mut current = Some(item.clone());
+        while let Some(item) = current {
+            if item.index() == self.item.index()
+                && vtable::VRc::ptr_eq(&item.component(), &self.item.component())
+            {
+                return true;
+            }
+            current = item.parent_item().upgrade();
+        }
+        false
+    }
+
+    fn send_key_event(&self, event_type: KeyEventType, text: &str, modifiers: KeyboardModifiers) {
+        self.focus();
+        self.window.clone().process_key_input(&KeyEvent {
+            event_type,
+            text: text.into(),
+            modifiers,
+        });
+    }
+
+    /// Focuses the element, as with [`Self::focus`], and sends it a key press event.
+    pub fn key_press(&self, text: &str, modifiers: KeyboardModifiers) {
+        self.send_key_event(KeyEventType::KeyPressed, text, modifiers)
+    }
+
+    /// Focuses the element, as with [`Self::focus`], and sends it a key release event.
+    pub fn key_release(&self, text: &str, modifiers: KeyboardModifiers) {
+        self.send_key_event(KeyEventType::KeyReleased, text, modifiers)
+    }
+}
+
+/// Returns the items of the component for which `filter` returns true, in the order in
+/// which they are drawn.
+fn find_items(
+    component: &ComponentRc,
+    mut filter: impl FnMut(&ItemRc, Pin<ItemRef>) -> bool,
+) -> Vec<ItemRc> {
+    let mut result = Vec::new();
+    crate::item_tree::visit_items(
+        component,
+        TraversalOrder::BackToFront,
+        |component, item, index, _| {
+            let item_rc = ItemRc::new(component.clone(), index);
+            if filter(&item_rc, item) {
+                result.push(item_rc);
+            }
+            ItemVisitorResult::Continue(())
+        },
+        (),
+    );
+    result
+}
+
+/// Sets `result` to the items of the component that have the given id, as with
+/// [`ElementHandle::find_all_by_id`].
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_find_by_id(
+    component: &ComponentRc,
+    id: &SharedString,
+    window: &WindowRc,
+    result: &mut SharedVector<ItemRc>,
+) {
+    *result = ElementHandle::find_all_by_id(component, window, id.as_str())
+        .into_iter()
+        .map(|element| element.item)
+        .collect();
+}
+
+/// Sets `result` to the items of the component that have the given text, as with
+/// [`ElementHandle::find_all_by_text`].
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_find_by_text(
+    component: &ComponentRc,
+    text: &SharedString,
+    window: &WindowRc,
+    result: &mut SharedVector<ItemRc>,
+) {
+    *result = ElementHandle::find_all_by_text(component, window, text.as_str())
+        .into_iter()
+        .map(|element| element.item)
+        .collect();
+}
+
+/// Sets `result` to the geometry of the element, relative to the window.
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_absolute_geometry(
+    item: &ItemRc,
+    window: &WindowRc,
+    result: &mut Rect,
+) {
+    *result = ElementHandle::new(item.clone(), window.clone()).absolute_geometry();
+}
+
+/// The kind of mouse interaction sent with [`sixtyfps_testing_element_send_mouse_event`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ElementMouseEvent {
+    /// [`ElementHandle::hover`]
+    Hover,
+    /// [`ElementHandle::click`]
+    Click,
+    /// [`ElementHandle::double_click`]
+    DoubleClick,
+    /// [`ElementHandle::drag_by`]
+    Drag,
+    /// [`ElementHandle::scroll`]
+    Scroll,
+}
+
+/// Sends a mouse interaction to the element. `dx` and `dy` are the offset of the drag or the
+/// amount to scroll, and are ignored by the other kinds of events.
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_send_mouse_event(
+    item: &ItemRc,
+    event: ElementMouseEvent,
+    dx: f32,
+    dy: f32,
+    window: &WindowRc,
+) {
+    let element = ElementHandle::new(item.clone(), window.clone());
+    match event {
+        ElementMouseEvent::Hover => element.hover(),
+        ElementMouseEvent::Click => element.click(),
+        ElementMouseEvent::DoubleClick => element.double_click(),
+        ElementMouseEvent::Drag => element.drag_by(dx, dy),
+        ElementMouseEvent::Scroll => element.scroll(dx, dy),
+    }
+}
+
+/// The kind of touch gesture sent with [`sixtyfps_testing_element_send_touch_gesture`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ElementTouchGesture {
+    /// [`ElementHandle::pinch`]
+    Pinch,
+    /// [`ElementHandle::swipe`]
+    Swipe,
+    /// [`ElementHandle::long_press`]
+    LongPress,
+}
+
+/// Sends a touch gesture to the element. For a pinch, `a` is the scale and `b` the rotation in
+/// degrees. For a swipe, `a` and `b` are the offset of the finger. They are ignored for a
+/// long press.
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_send_touch_gesture(
+    item: &ItemRc,
+    gesture: ElementTouchGesture,
+    a: f32,
+    b: f32,
+    window: &WindowRc,
+) {
+    let element = ElementHandle::new(item.clone(), window.clone());
+    match gesture {
+        ElementTouchGesture::Pinch => element.pinch(a, b),
+        ElementTouchGesture::Swipe => element.swipe(a, b),
+        ElementTouchGesture::LongPress => element.long_press(),
+    }
+}
+
+/// Gives the keyboard focus to the element, as with [`ElementHandle::focus`].
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_focus(item: &ItemRc, window: &WindowRc) -> bool {
+    ElementHandle::new(item.clone(), window.clone()).focus()
+}
+
+/// Sends a key press or release event to the element, as with [`ElementHandle::key_press`]
+/// and [`ElementHandle::key_release`].
+#[no_mangle]
+pub extern "C" fn sixtyfps_testing_element_send_key_event(
+    item: &ItemRc,
+    event_type: KeyEventType,
+    text: &SharedString,
+    modifiers: KeyboardModifiers,
+    window: &WindowRc,
+) {
+    ElementHandle::new(item.clone(), window.clone()).send_key_event(
+        event_type,
+        text.as_str(),
+        modifiers,
+    )
+}
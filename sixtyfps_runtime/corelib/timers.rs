@@ -212,6 +212,21 @@ pub fn next_timeout() -> Option<Instant> {
         })
     }
 
+    /// Returns the timeout of the single shot timer that should fire the soonest, or None if
+    /// there is no single shot timer active.
+    pub fn next_single_shot_timeout() -> Option<Instant> {
+        CURRENT_TIMERS.with(|timers| {
+            let timers = timers.borrow();
+            timers
+                .active_timers
+                .iter()
+                .find(|active_timer| {
+                    matches!(timers.timers[active_timer.id].mode, TimerMode::SingleShot)
+                })
+                .map(|active_timer| active_timer.timeout)
+        })
+    }
+
     /// Activates any expired timers by calling their callback function. Returns true if any timers were
     /// activated; false otherwise.
     pub fn maybe_activate_timers() -> bool {
@@ -226,30 +241,42 @@ pub fn maybe_activate_timers() -> bool {
 
             let mut any_activated = false;
 
-            // The active timer list is cleared here and not-yet-fired ones are inserted below, in order to allow
-            // timer callbacks to register their own timers.
-            let timers_to_process = core::mem::take(&mut timers.borrow_mut().active_timers);
-            for active_timer in timers_to_process.into_iter() {
-                if active_timer.timeout <= now {
-                    any_activated = true;
-
-                    timers.borrow_mut().callback_active = Some(active_timer.id);
-                    let callback = core::mem::replace(
-                        &mut timers.borrow_mut().timers[active_timer.id].callback,
-                        CallbackVariant::Empty,
-                    );
-                    let callback = callback.invoke();
-                    let mut timers = timers.borrow_mut();
-                    timers.timers[active_timer.id].callback = callback;
-                    timers.callback_active = None;
-
-                    if timers.timers[active_timer.id].removed {
-                        timers.timers.remove(active_timer.id);
-                    } else if matches!(timers.timers[active_timer.id].mode, TimerMode::Repeated) {
-                        timers.activate_timer(active_timer.id);
-                    }
-                } else {
-                    timers.borrow_mut().register_active_timer(active_timer);
+            // Only the timers expired at this point are processed, so that the timers registered
+            // by the callbacks fire at the next activation.
+            let expired_timers = timers
+                .borrow()
+                .active_timers
+                .iter()
+                .take_while(|active_timer| active_timer.timeout <= now)
+                .copied()
+                .collect::<Vec<_>>();
+            for active_timer in expired_timers {
+                // A previous callback may have stopped or restarted this timer
+                let position = timers
+                    .borrow()
+                    .active_timers
+                    .iter()
+                    .position(|t| t.id == active_timer.id && t.timeout == active_timer.timeout);
+                match position {
+                    Some(position) => timers.borrow_mut().active_timers.remove(position),
+                    None => continue,
+                };
+                any_activated = true;
+
+                timers.borrow_mut().callback_active = Some(active_timer.id);
+                let callback = core::mem::replace(
+                    &mut timers.borrow_mut().timers[active_timer.id].callback,
+                    CallbackVariant::Empty,
+                );
+                let callback = callback.invoke();
+                let mut timers = timers.borrow_mut();
+                timers.timers[active_timer.id].callback = callback;
+                timers.callback_active = None;
+
+                if timers.timers[active_timer.id].removed {
+                    timers.timers.remove(active_timer.id);
+                } else if matches!(timers.timers[active_timer.id].mode, TimerMode::Repeated) {
+                    timers.activate_timer(active_timer.id);
                 }
             }
 
@@ -0,0 +1,335 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+    Support for the translation of the strings marked with `@tr(...)` in `.60` files.
+
+    The lookup in the translation catalog is delegated to a [`Translator`] installed with
+    [`set_translator`]. Every translated string depends on the current translator, so
+    bindings are re-evaluated when the translator changes or when
+    [`mark_all_translations_dirty`] is called.
+*/
+
+#![warn(missing_docs)]
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::cell::RefCell;
+use core::pin::Pin;
+
+use crate::properties::Property;
+use crate::SharedString;
+
+/// A Translator looks up translated strings in the active catalog.
+///
+/// Install one with [`set_translator`].
+pub trait Translator {
+    /// Returns the translation of `string` in the given `context` and `domain`,
+    /// or `None` if the catalog has no translation for it.
+    fn translate(&self, string: &str, context: Option<&str>, domain: &str) -> Option<SharedString>;
+
+    /// Returns the translation of the plural form selected by `n`, or `None` if the catalog
+    /// has no translation for it.
+    fn ntranslate(
+        &self,
+        n: u64,
+        singular: &str,
+        plural: &str,
+        context: Option<&str>,
+        domain: &str,
+    ) -> Option<SharedString>;
+}
+
+struct TranslationState {
+    translator: RefCell<Option<Box<dyn Translator>>>,
+    /// Read by every translation so that it gets re-evaluated when this changes
+    dirty: Pin<Box<Property<usize>>>,
+}
+
+#[cfg(all(not(feature = "std"), feature = "unsafe_single_core"))]
+use crate::unsafe_single_core::thread_local;
+
+thread_local!(static TRANSLATION_STATE: TranslationState = TranslationState {
+    translator: Default::default(),
+    dirty: Box::pin(Property::new(0)),
+});
+
+/// Sets the translator used to look up the translations, and re-evaluates all the
+/// translated strings. Pass `None` to show the untranslated strings.
+pub fn set_translator(translator: Option<Box<dyn Translator>>) {
+    TRANSLATION_STATE.with(|state| *state.translator.borrow_mut() = translator);
+    mark_all_translations_dirty();
+}
+
+/// Re-evaluates all the translated strings.
+///
+/// Call this function when the language or the catalog of the current translator changes.
+pub fn mark_all_translations_dirty() {
+    TRANSLATION_STATE
+        .with(|state| state.dirty.as_ref().set(state.dirty.as_ref().get_untracked() + 1));
+}
+
+/// Translates `original` and substitutes its placeholders with `arguments`.
+///
+/// This is the implementation of `@tr(...)`. `context` and `plural` are empty when they are
+/// not given; `n` is the count used to select the plural form.
+pub fn translate(
+    original: &str,
+    context: &str,
+    domain: &str,
+    arguments: &[SharedString],
+    n: i32,
+    plural: &str,
+) -> SharedString {
+    let translated = TRANSLATION_STATE.with(|state| {
+        state.dirty.as_ref().get();
+        let translator = state.translator.borrow();
+        let translator = translator.as_ref()?;
+        let context = (!context.is_empty()).then(|| context);
+        if plural.is_empty() {
+            translator.translate(original, context, domain)
+        } else {
+            translator.ntranslate(n.unsigned_abs() as u64, original, plural, context, domain)
+        }
+    });
+    let format = match &translated {
+        Some(translated) => translated.as_str(),
+        None if !plural.is_empty() && n != 1 => plural,
+        None => original,
+    };
+    format_translated(format, arguments, n)
+}
+
+/// Substitutes `{}`, `{0}`, `{1}`, ... with the arguments and `{n}` with the count.
+/// `{{` and `}}` are replaced by single braces.
+fn format_translated(format: &str, arguments: &[SharedString], n: i32) -> SharedString {
+    let mut result = String::with_capacity(format.len());
+    let mut next_positional = 0;
+    let mut rest = format;
+    while let Some(pos) = rest.find(&['{', '}'][..]) {
+        result.push_str(&rest[..pos]);
+        let c = rest.as_bytes()[pos] as char;
+        rest = &rest[pos + 1..];
+        if rest.starts_with(c) {
+            result.push(c);
+            rest = &rest[1..];
+            continue;
+        }
+        let end = match rest.find('}') {
+            Some(end) if c == '{' => end,
+            _ => {
+                result.push(c);
+                continue;
+            }
+        };
+        let placeholder = &rest[..end];
+        if placeholder.is_empty() {
+            result.push_str(arguments.get(next_positional).map_or("", |a| a.as_str()));
+            next_positional += 1;
+        } else if placeholder == "n" {
+            result.push_str(&alloc::format!("{}", n));
+        } else if let Ok(index) = placeholder.parse::<usize>() {
+            result.push_str(arguments.get(index).map_or("", |a| a.as_str()));
+        } else {
+            result.push('{');
+            result.push_str(placeholder);
+            result.push('}');
+        }
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    result.into()
+}
+
+#[test]
+fn test_format_translated() {
+    let args = [SharedString::from("foo"), SharedString::from("bar")];
+    assert_eq!(format_translated("Hello {}, {}!", &args, 1), "Hello foo, bar!");
+    assert_eq!(format_translated("{1} {0} {1}", &args, 1), "bar foo bar");
+    assert_eq!(format_translated("{n} files in {}", &args, 42), "42 files in foo");
+    assert_eq!(format_translated("{{}} {{{}}}", &args, 1), "{} {foo}");
+    assert_eq!(format_translated("{} {} {}", &args, 1), "foo bar ");
+}
+
+#[test]
+fn test_translate_with_translator() {
+    struct TestTranslator;
+    impl Translator for TestTranslator {
+        fn translate(
+            &self,
+            string: &str,
+            context: Option<&str>,
+            _domain: &str,
+        ) -> Option<SharedString> {
+            match (string, context) {
+                ("Hello {}", None) => Some("Bonjour {}".into()),
+                ("Open", Some("door")) => Some("Ouvrir".into()),
+                _ => None,
+            }
+        }
+        fn ntranslate(
+            &self,
+            n: u64,
+            singular: &str,
+            _plural: &str,
+            _context: Option<&str>,
+            _domain: &str,
+        ) -> Option<SharedString> {
+            (singular == "{n} file")
+                .then(|| if n > 1 { "{n} fichiers" } else { "{n} fichier" }.into())
+        }
+    }
+
+    let args = [SharedString::from("world")];
+    assert_eq!(translate("Hello {}", "", "", &args, 1, ""), "Hello world");
+    assert_eq!(translate("{n} file", "", "", &[], 3, "{n} files"), "3 files");
+    set_translator(Some(Box::new(TestTranslator)));
+    assert_eq!(translate("Hello {}", "", "", &args, 1, ""), "Bonjour world");
+    assert_eq!(translate("Open", "door", "", &[], 1, ""), "Ouvrir");
+    assert_eq!(translate("Open", "", "", &[], 1, ""), "Open");
+    assert_eq!(translate("{n} file", "", "", &[], 3, "{n} files"), "3 fichiers");
+    assert_eq!(translate("{n} file", "", "", &[], 1, "{n} files"), "1 fichier");
+    set_translator(None);
+    assert_eq!(translate("Hello {}", "", "", &args, 1, ""), "Hello world");
+}
+
+#[test]
+fn test_translation_dirty_tracking() {
+    struct TestTranslator;
+    impl Translator for TestTranslator {
+        fn translate(&self, _: &str, _: Option<&str>, _: &str) -> Option<SharedString> {
+            Some("Hallo".into())
+        }
+        fn ntranslate(
+            &self,
+            _: u64,
+            _: &str,
+            _: &str,
+            _: Option<&str>,
+            _: &str,
+        ) -> Option<SharedString> {
+            None
+        }
+    }
+
+    let prop = Box::pin(Property::<SharedString>::default());
+    prop.set_binding(|| translate("Hello", "", "", &[], 1, ""));
+    assert_eq!(prop.as_ref().get(), "Hello");
+    set_translator(Some(Box::new(TestTranslator)));
+    assert_eq!(prop.as_ref().get(), "Hallo");
+    set_translator(None);
+    assert_eq!(prop.as_ref().get(), "Hello");
+}
+
+#[cfg(feature = "ffi")]
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use crate::slice::Slice;
+
+    #[allow(non_camel_case_types)]
+    type c_void = ();
+
+    /// Translates the string. See [`translate`].
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_translate(
+        original: &SharedString,
+        context: &SharedString,
+        domain: &SharedString,
+        arguments: Slice<SharedString>,
+        n: i32,
+        plural: &SharedString,
+        out: &mut SharedString,
+    ) {
+        *out = translate(original, context, domain, arguments.as_slice(), n, plural);
+    }
+
+    /// Re-evaluates all the translated strings.
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_mark_all_translations_dirty() {
+        mark_all_translations_dirty()
+    }
+
+    type TranslateFn = extern "C" fn(
+        user_data: *mut c_void,
+        string: &SharedString,
+        context: &SharedString,
+        domain: &SharedString,
+        n: u64,
+        plural: &SharedString,
+        out: &mut SharedString,
+    ) -> bool;
+
+    struct WrapTranslator {
+        translate: TranslateFn,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    }
+
+    impl Drop for WrapTranslator {
+        fn drop(&mut self) {
+            if let Some(x) = self.drop_user_data {
+                x(self.user_data)
+            }
+        }
+    }
+
+    impl WrapTranslator {
+        fn call(
+            &self,
+            string: &str,
+            context: Option<&str>,
+            domain: &str,
+            n: u64,
+            plural: &str,
+        ) -> Option<SharedString> {
+            let mut out = SharedString::default();
+            (self.translate)(
+                self.user_data,
+                &string.into(),
+                &context.unwrap_or_default().into(),
+                &domain.into(),
+                n,
+                &plural.into(),
+                &mut out,
+            )
+            .then(|| out)
+        }
+    }
+
+    impl Translator for WrapTranslator {
+        fn translate(
+            &self,
+            string: &str,
+            context: Option<&str>,
+            domain: &str,
+        ) -> Option<SharedString> {
+            self.call(string, context, domain, 1, "")
+        }
+
+        fn ntranslate(
+            &self,
+            n: u64,
+            singular: &str,
+            plural: &str,
+            context: Option<&str>,
+            domain: &str,
+        ) -> Option<SharedString> {
+            self.call(singular, context, domain, n, plural)
+        }
+    }
+
+    /// Installs a translator. `translate` is called with `user_data` and must set `out` and
+    /// return true when a translation was found. The plural string is empty when there is no
+    /// plural form. Passing a null `translate` removes the current translator.
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_set_translator(
+        translate: Option<TranslateFn>,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
+    ) {
+        set_translator(translate.map(|translate| {
+            Box::new(WrapTranslator { translate, user_data, drop_user_data }) as Box<dyn Translator>
+        }));
+    }
+}
@@ -6,11 +6,15 @@
 
 use crate::component::{ComponentRc, ComponentWeak};
 use crate::graphics::{Point, Size};
-use crate::input::{KeyEvent, MouseEvent, MouseInputState, TextCursorBlinker};
+use crate::input::{
+    key_codes, KeyEvent, KeyEventType, KeyboardModifiers, MouseEvent, MouseInputState,
+    TextCursorBlinker, TouchEvent, TouchInputState,
+};
 use crate::items::{ItemRc, ItemRef, ItemWeak, MouseCursor};
 use crate::properties::{Property, PropertyTracker};
 use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 
@@ -78,6 +82,46 @@ fn text_input_position_for_byte_offset(
 
     /// Return self as any so the backend can upcast
     fn as_any(&self) -> &dyn core::any::Any;
+
+    /// Returns the position of the window on the screen, in logical pixels.
+    fn position(&self) -> Point {
+        Point::default()
+    }
+
+    /// Request the windowing system to move the window to the given position, in logical pixels.
+    fn set_position(&self, _position: Point) {}
+
+    /// Request the windowing system to resize the window, in logical pixels.
+    fn set_size(&self, _size: Size) {}
+
+    /// Returns whether the window is minimized, maximized or fullscreen.
+    fn window_state(&self) -> WindowState {
+        WindowState::Normal
+    }
+
+    /// Request the windowing system to minimize, maximize or restore the window, or to show it
+    /// fullscreen.
+    fn set_window_state(&self, _state: WindowState) {}
+}
+
+/// The state of a window on the screen, set with [`api::Window::set_window_state()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub enum WindowState {
+    /// The window is neither minimized, maximized nor fullscreen.
+    Normal,
+    /// The window is minimized, typically to the task bar.
+    Minimized,
+    /// The window covers the screen, except for the panels of the desktop.
+    Maximized,
+    /// The window covers the whole screen and has no frame.
+    Fullscreen,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self::Normal
+    }
 }
 
 struct WindowPropertiesTracker {
@@ -125,12 +169,39 @@ pub struct PopupWindow {
     pub component: ComponentRc,
 }
 
+/// A window declared with a `Window` element inside of a component, and opened with its `show()`
+/// function. It is kept alive by the top-level window until it is hidden.
+struct ChildWindow {
+    /// The index of the `Window` element among the child windows of the component of `parent_item`
+    index: usize,
+    /// The item of the element that contains the `Window` element
+    parent_item: ItemWeak,
+    window: Rc<Window>,
+    /// Keeps the component shown in the child window alive, the window only has a weak reference
+    component: ComponentRc,
+}
+
+impl ChildWindow {
+    fn is(&self, index: usize, parent_item: &ItemRc) -> bool {
+        self.index == index && self.parent_item.upgrade().as_ref() == Some(parent_item)
+    }
+
+    fn is_modal(&self) -> bool {
+        let component = ComponentRc::borrow_pin(&self.component);
+        let root_item = component.as_ref().get_item_ref(0);
+        ItemRef::downcast_pin::<crate::items::WindowItem>(root_item)
+            .map_or(false, |window_item| window_item.modal())
+    }
+}
+
 /// Structure that represent a Window in the runtime
 pub struct Window {
     /// FIXME! use Box instead;
     platform_window: once_cell::unsync::OnceCell<Rc<dyn PlatformWindow>>,
     component: RefCell<ComponentWeak>,
     mouse_input_state: Cell<MouseInputState>,
+    touch_input_state: Cell<TouchInputState>,
+    keyboard_modifiers: Cell<KeyboardModifiers>,
     redraw_tracker: once_cell::unsync::OnceCell<Pin<Box<PropertyTracker<WindowRedrawTracker>>>>,
     window_properties_tracker:
         once_cell::unsync::OnceCell<Pin<Box<PropertyTracker<WindowPropertiesTracker>>>>,
@@ -143,6 +214,12 @@ pub struct Window {
     scale_factor: Pin<Box<Property<f32>>>,
     active: Pin<Box<Property<bool>>>,
     active_popup: RefCell<Option<PopupWindow>>,
+
+    /// The child windows that are shown, in the order they were shown. Only the top-level window
+    /// has child windows.
+    child_windows: RefCell<Vec<ChildWindow>>,
+    /// The top-level window, if this window is a child window
+    parent_window: RefCell<Weak<Window>>,
 }
 
 impl Drop for Window {
@@ -163,6 +240,8 @@ pub fn new(
             platform_window: Default::default(),
             component: Default::default(),
             mouse_input_state: Default::default(),
+            touch_input_state: Default::default(),
+            keyboard_modifiers: Default::default(),
             redraw_tracker: Default::default(),
             window_properties_tracker: Default::default(),
             meta_properties_tracker: Rc::pin(Default::default()),
@@ -171,6 +250,8 @@ pub fn new(
             scale_factor: Box::pin(Property::new(1.)),
             active: Box::pin(Property::new(false)),
             active_popup: Default::default(),
+            child_windows: Default::default(),
+            parent_window: Default::default(),
         });
         let window_weak = Rc::downgrade(&window);
         window.platform_window.set(platform_window_fn(&window_weak)).ok().unwrap();
@@ -205,6 +286,7 @@ pub fn set_component(&self, component: &ComponentRc) {
         self.close_popup();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.touch_input_state.replace(Default::default());
         self.component.replace(ComponentRc::downgrade(component));
         self.meta_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
         self.request_window_properties_update();
@@ -232,6 +314,10 @@ pub fn try_component(&self) -> Option<ComponentRc> {
     pub fn process_mouse_input(self: Rc<Self>, mut event: MouseEvent) {
         crate::animations::update_animations();
 
+        if self.is_blocked_by_modal_child_window() {
+            return;
+        }
+
         let embedded_popup_component =
             self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
                 PopupWindowLocation::TopLevel(_) => None,
@@ -282,13 +368,64 @@ pub fn process_mouse_input(self: Rc<Self>, mut event: MouseEvent) {
             }
         }
     }
+    /// Receive a touch event and pass it to the items of the component to
+    /// change their state.
+    ///
+    /// Touch points that start on a `GestureArea` are used to recognize its gestures,
+    /// the first other touch point is processed like the left mouse button.
+    ///
+    /// Arguments:
+    /// * `event`: The touch event, with the position in window physical coordinates.
+    pub fn process_touch_input(self: Rc<Self>, event: TouchEvent) {
+        crate::animations::update_animations();
+
+        if self.is_blocked_by_modal_child_window() {
+            return;
+        }
+
+        let component = if let Some(component) = self.component.borrow().upgrade() {
+            component
+        } else {
+            return;
+        };
+
+        let (touch_input_state, mouse_event) =
+            crate::input::process_touch_input(&component, event, self.touch_input_state.take());
+        self.touch_input_state.set(touch_input_state);
+
+        if let Some(mouse_event) = mouse_event {
+            self.process_mouse_input(mouse_event);
+        }
+    }
+
+    /// Returns the keyboard modifiers that are currently pressed, as last reported by the
+    /// windowing system. They are reported to the items along with the mouse events.
+    pub fn current_keyboard_modifiers(&self) -> KeyboardModifiers {
+        self.keyboard_modifiers.get()
+    }
+
+    /// Sets the keyboard modifiers that are currently pressed. Key events also update them.
+    pub fn set_current_keyboard_modifiers(&self, modifiers: KeyboardModifiers) {
+        self.keyboard_modifiers.set(modifiers);
+    }
+
     /// Receive a key event and pass it to the items of the component to
     /// change their state.
     ///
+    /// A key press that matches an enabled `Shortcut` activates it and is not delivered to the
+    /// focus item.
+    ///
     /// Arguments:
     /// * `event`: The key event received by the windowing system.
     /// * `component`: The SixtyFPS compiled component that provides the tree of items.
     pub fn process_key_input(self: Rc<Self>, event: &KeyEvent) {
+        self.keyboard_modifiers.set(event.modifiers);
+        if self.is_blocked_by_modal_child_window() {
+            return;
+        }
+        if event.event_type == KeyEventType::KeyPressed && self.activate_shortcut(event) {
+            return;
+        }
         let mut item = self.focus_item.borrow().clone();
         while let Some(focus_item) = item.upgrade() {
             if focus_item.borrow().as_ref().key_event(event, &self.clone())
@@ -298,6 +435,120 @@ pub fn process_key_input(self: Rc<Self>, event: &KeyEvent) {
             }
             item = focus_item.parent_item();
         }
+
+        // Nobody handled the event, so treat Tab and Shift+Tab as keyboard focus navigation.
+        if event.event_type == KeyEventType::KeyPressed {
+            match event.text.chars().next() {
+                Some(key_codes::Tab) if !event.modifiers.shift => self.focus_next_item(),
+                Some(key_codes::Tab) | Some(key_codes::Backtab) => self.focus_previous_item(),
+                _ => {}
+            }
+        }
+    }
+
+    /// Activates the first visible and enabled `Shortcut` that matches the key event, looking
+    /// in the active popup first. Returns true if a shortcut was activated.
+    fn activate_shortcut(&self, event: &KeyEvent) -> bool {
+        let popup = self.active_popup.borrow().as_ref().map(|popup| popup.component.clone());
+        let component = self.component.borrow().upgrade();
+        for root in popup.into_iter().chain(component) {
+            let mut found = None;
+            crate::item_tree::visit_items(
+                &root,
+                crate::item_tree::TraversalOrder::BackToFront,
+                |component, item, index, visible| {
+                    if !*visible {
+                        return crate::item_tree::ItemVisitorResult::Continue(false);
+                    }
+                    if ItemRef::downcast_pin::<crate::items::Shortcut>(item)
+                        .map_or(false, |shortcut| shortcut.matches(event))
+                    {
+                        found = Some(ItemRc::new(component.clone(), index));
+                        return crate::item_tree::ItemVisitorResult::Abort;
+                    }
+                    crate::item_tree::ItemVisitorResult::Continue(!hides_children(item))
+                },
+                true,
+            );
+            if let Some(item) = found {
+                // The callback is called after the visit, since it may change the item tree
+                use crate::items::Shortcut;
+                let shortcut = ItemRef::downcast_pin::<Shortcut>(item.borrow()).unwrap();
+                Shortcut::FIELD_OFFSETS.activated.apply_pin(shortcut).call(&());
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves the keyboard focus to the next item in the focus chain, wrapping around at the end.
+    /// See [`Self::focus_chain`] for the order in which items are visited.
+    pub fn focus_next_item(self: Rc<Self>) {
+        let chain = self.focus_chain();
+        let next = match self.focus_chain_position(&chain) {
+            Some(pos) => chain.get((pos + 1) % chain.len()),
+            None => chain.first(),
+        };
+        if let Some(next) = next {
+            self.set_focus_item(next);
+        }
+    }
+
+    /// Moves the keyboard focus to the previous item in the focus chain, wrapping around at the
+    /// beginning. See [`Self::focus_chain`] for the order in which items are visited.
+    pub fn focus_previous_item(self: Rc<Self>) {
+        let chain = self.focus_chain();
+        let previous = match self.focus_chain_position(&chain) {
+            Some(pos) => chain.get((pos + chain.len() - 1) % chain.len()),
+            None => chain.last(),
+        };
+        if let Some(previous) = previous {
+            self.set_focus_item(previous);
+        }
+    }
+
+    fn focus_chain_position(&self, chain: &[ItemRc]) -> Option<usize> {
+        let focus_item = self.focus_item.borrow().upgrade()?;
+        chain.iter().position(|item| {
+            item.index() == focus_item.index()
+                && vtable::VRc::ptr_eq(&item.component(), &focus_item.component())
+        })
+    }
+
+    /// Returns the items that can receive the keyboard focus with Tab and Shift+Tab, in order.
+    /// The position of each item is given by its `tab_focus_index` vtable function:
+    /// items with a positive index come first, in increasing order, followed by the items
+    /// with an index of zero in tree order. Items with a negative index and invisible items
+    /// are skipped. When a popup is shown, only the items of the popup are part of the chain.
+    fn focus_chain(&self) -> Vec<ItemRc> {
+        let root = match self.active_popup.borrow().as_ref() {
+            Some(popup) => popup.component.clone(),
+            None => match self.component.borrow().upgrade() {
+                Some(component) => component,
+                None => return Vec::new(),
+            },
+        };
+
+        let mut candidates = Vec::new();
+        crate::item_tree::visit_items(
+            &root,
+            crate::item_tree::TraversalOrder::BackToFront,
+            |component, item, index, visible| {
+                if !*visible {
+                    return crate::item_tree::ItemVisitorResult::Continue(false);
+                }
+                let tab_index = item.as_ref().tab_focus_index();
+                if tab_index >= 0 {
+                    candidates.push((tab_index, ItemRc::new(component.clone(), index)));
+                }
+                crate::item_tree::ItemVisitorResult::Continue(!hides_children(item))
+            },
+            true,
+        );
+
+        // sort_by_key is stable, so items with the same tab index remain in tree order.
+        candidates.sort_by_key(|(tab_index, _)| if *tab_index > 0 { *tab_index } else { i32::MAX });
+        candidates.into_iter().map(|(_, item)| item).collect()
     }
 
     /// Installs a binding on the specified property that's toggled whenever the text cursor is supposed to be visible or not.
@@ -423,8 +674,47 @@ pub fn show(&self) {
     }
 
     /// De-registers the window with the windowing system.
+    ///
+    /// The child windows of a top-level window are hidden with it, and a child window that is
+    /// hidden is released.
     pub fn hide(&self) {
+        for child_window in self.child_windows.take() {
+            child_window.window.hide();
+        }
         self.platform_window.get().unwrap().clone().hide();
+        if let Some(top_level) = self.parent_window.take().upgrade() {
+            let position = top_level
+                .child_windows
+                .borrow()
+                .iter()
+                .position(|child_window| core::ptr::eq(&*child_window.window, self));
+            // Drop the component outside of the borrow, its destruction may access the windows
+            let child_window = position.map(|pos| top_level.child_windows.borrow_mut().remove(pos));
+            drop(child_window);
+        }
+    }
+
+    /// Calls the `close-requested` callback of the root `Window` element, and hides the window
+    /// unless the callback returns `keep-window-shown`. Backends call this when the user closes
+    /// the window. Returns true if the window was hidden.
+    pub fn request_close(&self) -> bool {
+        let response = self
+            .try_component()
+            .and_then(|component_rc| {
+                let component = ComponentRc::borrow_pin(&component_rc);
+                let root_item = component.as_ref().get_item_ref(0);
+                ItemRef::downcast_pin(root_item).map(
+                    |window_item: Pin<&crate::items::WindowItem>| {
+                        window_item.close_requested.call(&())
+                    },
+                )
+            })
+            .unwrap_or_default();
+        if response == crate::items::CloseRequestResponse::keep_window_shown {
+            return false;
+        }
+        self.hide();
+        true
     }
 
     /// Registers the specified window and component to be considered the active popup.
@@ -500,6 +790,67 @@ pub fn close_popup(&self) {
         }
     }
 
+    /// Returns the top-level window of the child windows shown from this window
+    fn top_level_window(self: Rc<Self>) -> Rc<Self> {
+        let parent_window = self.parent_window.borrow().upgrade();
+        parent_window.unwrap_or(self)
+    }
+
+    /// Returns true if the child window declared by the `Window` element at `index` in the
+    /// component of `parent_item` is shown.
+    pub fn is_child_window_shown(self: Rc<Self>, index: usize, parent_item: &ItemRc) -> bool {
+        let top_level = self.top_level_window();
+        let shown = top_level.child_windows.borrow().iter().any(|c| c.is(index, parent_item));
+        shown
+    }
+
+    /// Shows `child_window`, whose component was created for the `Window` element at `index` in
+    /// the component of `parent_item`. The component is kept alive until the window is hidden.
+    pub fn show_child_window(
+        self: Rc<Self>,
+        index: usize,
+        parent_item: &ItemRc,
+        child_window: &Rc<Window>,
+    ) {
+        let top_level = self.top_level_window();
+        child_window.parent_window.replace(Rc::downgrade(&top_level));
+        top_level.child_windows.borrow_mut().push(ChildWindow {
+            index,
+            parent_item: parent_item.downgrade(),
+            window: child_window.clone(),
+            component: child_window.component(),
+        });
+        child_window.show();
+    }
+
+    /// Hides the child window declared by the `Window` element at `index` in the component of
+    /// `parent_item`, if it is shown.
+    pub fn hide_child_window(self: Rc<Self>, index: usize, parent_item: &ItemRc) {
+        let top_level = self.top_level_window();
+        let child_window = top_level
+            .child_windows
+            .borrow()
+            .iter()
+            .find(|c| c.is(index, parent_item))
+            .map(|c| c.window.clone());
+        if let Some(child_window) = child_window {
+            child_window.hide();
+        }
+    }
+
+    /// Returns true if a modal child window was shown after this window. The window then doesn't
+    /// react to input events until the modal window is hidden.
+    pub(crate) fn is_blocked_by_modal_child_window(&self) -> bool {
+        let parent_window = self.parent_window.borrow().upgrade();
+        let top_level = parent_window.as_deref().unwrap_or(self);
+        let child_windows = top_level.child_windows.borrow();
+        let shown_after = match child_windows.iter().position(|c| core::ptr::eq(&*c.window, self)) {
+            Some(pos) => &child_windows[pos + 1..],
+            None => &child_windows[..],
+        };
+        shown_after.iter().any(ChildWindow::is_modal)
+    }
+
     /// Returns the scale factor set on the window, as provided by the windowing system.
     pub fn scale_factor(&self) -> f32 {
         self.scale_factor_property().get()
@@ -530,6 +881,40 @@ pub fn default_font_properties(&self) -> crate::graphics::FontRequest {
             .unwrap_or_default()
     }
 
+    /// Returns the size of the window item, in logical pixels.
+    pub fn window_item_size(&self) -> Size {
+        self.try_component()
+            .and_then(|component_rc| {
+                let component = ComponentRc::borrow_pin(&component_rc);
+                let root_item = component.as_ref().get_item_ref(0);
+                ItemRef::downcast_pin(root_item).map(
+                    |window_item: Pin<&crate::items::WindowItem>| {
+                        Size::new(window_item.width(), window_item.height())
+                    },
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Resizes the window item and requests the windowing system to resize the window.
+    /// Size is in logical pixels.
+    pub fn set_size(&self, size: Size) {
+        self.set_window_item_geometry(size.width, size.height);
+        self.platform_window.get().unwrap().set_size(size);
+    }
+
+    /// Sets the title of the root `Window` element, which is shown in the title bar.
+    pub fn set_title(&self, title: &str) {
+        if let Some(component_rc) = self.try_component() {
+            let component = ComponentRc::borrow_pin(&component_rc);
+            let root_item = component.as_ref().get_item_ref(0);
+            if let Some(window_item) = ItemRef::downcast_pin::<crate::items::WindowItem>(root_item)
+            {
+                window_item.title.set(title.into());
+            }
+        }
+    }
+
     /// Sets the size of the window item. This method is typically called in response to receiving a
     /// window resize event from the windowing system.
     /// Size is in logical pixels.
@@ -546,6 +931,15 @@ pub fn set_window_item_geometry(&self, width: f32, height: f32) {
     }
 }
 
+/// Returns true if the item is a clip element that hides all its children, which is how
+/// the `visible` property is implemented.
+pub(crate) fn hides_children(item: Pin<ItemRef>) -> bool {
+    ItemRef::downcast_pin::<crate::items::Clip>(item).map_or(false, |clip| {
+        let geometry = item.as_ref().geometry();
+        clip.clip() && (geometry.width() <= 0. || geometry.height() <= 0.)
+    })
+}
+
 impl core::ops::Deref for Window {
     type Target = dyn PlatformWindow;
 
@@ -567,6 +961,9 @@ pub trait WindowHandleAccess {
 /// Internal module to define the public Window API, for re-export in the regular Rust crate
 /// and the interpreter crate.
 pub mod api {
+    pub use super::WindowState;
+    use crate::graphics::{Point, Size};
+
     /// This type represents a window towards the windowing system, that's used to render the
     /// scene of a component. It provides API to control windowing system specific aspects such
     /// as the position on the screen.
@@ -590,6 +987,44 @@ pub fn show(&self) {
         pub fn hide(&self) {
             self.0.hide();
         }
+
+        /// Returns the position of the window on the screen, in logical pixels.
+        pub fn position(&self) -> Point {
+            self.0.platform_window.get().unwrap().position()
+        }
+
+        /// Requests the windowing system to move the window to the given position on the screen,
+        /// in logical pixels.
+        pub fn set_position(&self, position: Point) {
+            self.0.platform_window.get().unwrap().set_position(position);
+        }
+
+        /// Returns the size of the window, in logical pixels.
+        pub fn size(&self) -> Size {
+            self.0.window_item_size()
+        }
+
+        /// Resizes the window to the given size, in logical pixels. The size is still restricted
+        /// by the layout constraints of the window.
+        pub fn set_size(&self, size: Size) {
+            self.0.set_size(size);
+        }
+
+        /// Returns whether the window is minimized, maximized or fullscreen.
+        pub fn window_state(&self) -> WindowState {
+            self.0.platform_window.get().unwrap().window_state()
+        }
+
+        /// Requests the windowing system to minimize, maximize or restore the window, or to show it
+        /// fullscreen.
+        pub fn set_window_state(&self, state: WindowState) {
+            self.0.platform_window.get().unwrap().set_window_state(state);
+        }
+
+        /// Sets the title of the window. This overrides the `title` property of the `Window` element.
+        pub fn set_title(&self, title: &str) {
+            self.0.set_title(title);
+        }
     }
 }
 
@@ -708,6 +1143,105 @@ pub mod ffi {
         let window = &*(handle as *const WindowRc);
         window.show_popup(popup, position, parent_item);
     }
+
+    /// Returns true if the child window at the given index of the parent item's component is shown.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_is_child_window_shown(
+        handle: *const WindowRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+    ) -> bool {
+        let window = &*(handle as *const WindowRc);
+        window.clone().is_child_window_shown(index, parent_item)
+    }
+
+    /// Show a child window.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_show_child_window(
+        handle: *const WindowRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+        child_window: *const WindowRcOpaque,
+    ) {
+        let window = &*(handle as *const WindowRc);
+        let child_window = &*(child_window as *const WindowRc);
+        window.clone().show_child_window(index, parent_item, child_window);
+    }
+
+    /// Hide a child window.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_hide_child_window(
+        handle: *const WindowRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+    ) {
+        let window = &*(handle as *const WindowRc);
+        window.clone().hide_child_window(index, parent_item);
+    }
+
+    /// Returns the position of the window on the screen.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_position(
+        handle: *const WindowRcOpaque,
+        position: &mut Point,
+    ) {
+        let window = &*(handle as *const WindowRc);
+        *position = window.platform_window.get().unwrap().position();
+    }
+
+    /// Moves the window on the screen.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_set_position(
+        handle: *const WindowRcOpaque,
+        position: Point,
+    ) {
+        let window = &*(handle as *const WindowRc);
+        window.platform_window.get().unwrap().set_position(position);
+    }
+
+    /// Returns the size of the window.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_size(handle: *const WindowRcOpaque) -> Size {
+        let window = &*(handle as *const WindowRc);
+        window.window_item_size()
+    }
+
+    /// Resizes the window.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_set_size(handle: *const WindowRcOpaque, size: Size) {
+        let window = &*(handle as *const WindowRc);
+        window.set_size(size);
+    }
+
+    /// Returns whether the window is minimized, maximized or fullscreen.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_window_state(
+        handle: *const WindowRcOpaque,
+    ) -> WindowState {
+        let window = &*(handle as *const WindowRc);
+        window.platform_window.get().unwrap().window_state()
+    }
+
+    /// Minimizes, maximizes or restores the window, or shows it fullscreen.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_set_window_state(
+        handle: *const WindowRcOpaque,
+        state: WindowState,
+    ) {
+        let window = &*(handle as *const WindowRc);
+        window.platform_window.get().unwrap().set_window_state(state);
+    }
+
+    /// Sets the title of the window.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_windowrc_set_title(
+        handle: *const WindowRcOpaque,
+        title: &crate::SharedString,
+    ) {
+        let window = &*(handle as *const WindowRc);
+        window.set_title(title.as_str());
+    }
+
     /// Close the current popup
     pub unsafe extern "C" fn sixtyfps_windowrc_close_popup(handle: *const WindowRcOpaque) {
         let window = &*(handle as *const WindowRc);
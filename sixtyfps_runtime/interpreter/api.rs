@@ -1,6 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@sixtyfps.io>
 // SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
 
+use crate::dynamic_component::CallbackHandler;
 use core::convert::TryInto;
 use sixtyfps_compilerlib::langtype::Type as LangType;
 use sixtyfps_corelib::graphics::Image;
@@ -15,7 +16,9 @@
 #[doc(inline)]
 pub use sixtyfps_compilerlib::diagnostics::{Diagnostic, DiagnosticLevel};
 
-pub use sixtyfps_corelib::window::api::Window;
+pub use sixtyfps_corelib::animations::register_easing_curve;
+pub use sixtyfps_corelib::translations::{mark_all_translations_dirty, set_translator, Translator};
+pub use sixtyfps_corelib::window::api::{Window, WindowState};
 
 /// This enum represents the different public variants of the [`Value`] enum, without
 /// the contained values.
@@ -103,8 +106,9 @@ pub enum Value {
     #[doc(hidden)]
     /// An easing curve
     EasingCurve(sixtyfps_corelib::animations::EasingCurve),
-    #[doc(hidden)]
-    /// An enumeration, like `TextHorizontalAlignment::align_center`, represented by `("TextHorizontalAlignment", "align_center")`.
+    /// An enumeration value, like `TextHorizontalAlignment.center` or a value of an `enum` declared
+    /// in the .60 file, represented by the name of the enum and the name of the value:
+    /// `("TextHorizontalAlignment", "center")`.
     /// FIXME: consider representing that with a number?
     EnumerationValue(String, String),
     #[doc(hidden)]
@@ -261,7 +265,10 @@ fn try_into(self) -> Result<$name, ()> {
 declare_value_struct_conversion!(struct sixtyfps_corelib::input::KeyEvent { event_type, text, modifiers });
 declare_value_struct_conversion!(struct sixtyfps_corelib::layout::LayoutInfo { min, max, min_percent, max_percent, preferred, stretch });
 declare_value_struct_conversion!(struct sixtyfps_corelib::graphics::Point { x, y, ..Default::default()});
-declare_value_struct_conversion!(struct sixtyfps_corelib::items::PointerEvent { kind, button });
+declare_value_struct_conversion!(struct sixtyfps_corelib::items::PointerEvent { kind, button, modifiers });
+declare_value_struct_conversion!(struct sixtyfps_corelib::items::PointerScrollEvent { delta_x, delta_y, modifiers });
+declare_value_struct_conversion!(struct sixtyfps_corelib::items::DropEvent { mime_type, data, position });
+declare_value_struct_conversion!(struct sixtyfps_corelib::items::KeyFrame { position, value });
 
 /// Implement From / TryInto for Value that convert an `enum` to/from `Value::EnumerationValue`
 ///
@@ -310,13 +317,34 @@ fn try_into(self) -> Result<$ty, ()> {
 declare_value_enum_conversion!(sixtyfps_corelib::items::ImageRendering, ImageRendering);
 declare_value_enum_conversion!(sixtyfps_corelib::input::KeyEventType, KeyEventType);
 declare_value_enum_conversion!(sixtyfps_corelib::items::EventResult, EventResult);
+declare_value_enum_conversion!(sixtyfps_corelib::items::CloseRequestResponse, CloseRequestResponse);
 declare_value_enum_conversion!(sixtyfps_corelib::items::FillRule, FillRule);
 declare_value_enum_conversion!(sixtyfps_corelib::items::MouseCursor, MouseCursor);
 declare_value_enum_conversion!(sixtyfps_corelib::items::StandardButtonKind, StandardButtonKind);
+declare_value_enum_conversion!(sixtyfps_corelib::items::SwipeDirection, SwipeDirection);
 declare_value_enum_conversion!(sixtyfps_corelib::items::PointerEventKind, PointerEventKind);
 declare_value_enum_conversion!(sixtyfps_corelib::items::PointerEventButton, PointerEventButton);
 declare_value_enum_conversion!(sixtyfps_corelib::items::DialogButtonRole, DialogButtonRole);
 declare_value_enum_conversion!(sixtyfps_corelib::graphics::PathEvent, PathEvent);
+declare_value_enum_conversion!(sixtyfps_corelib::items::AnimationDirection, AnimationDirection);
+declare_value_enum_conversion!(sixtyfps_corelib::items::AccessibleRole, AccessibleRole);
+
+impl From<SharedVector<sixtyfps_corelib::items::KeyFrame>> for Value {
+    fn from(keyframes: SharedVector<sixtyfps_corelib::items::KeyFrame>) -> Self {
+        Value::Model(ModelHandle::new(Rc::new(sixtyfps_corelib::model::VecModel::from(
+            keyframes.iter().map(|k| Value::from(*k)).collect::<Vec<_>>(),
+        ))))
+    }
+}
+impl TryInto<SharedVector<sixtyfps_corelib::items::KeyFrame>> for Value {
+    type Error = ();
+    fn try_into(self) -> Result<SharedVector<sixtyfps_corelib::items::KeyFrame>, ()> {
+        match self {
+            Self::Model(model) => model.iter().map(|v| v.try_into()).collect(),
+            _ => Err(()),
+        }
+    }
+}
 
 impl From<sixtyfps_corelib::animations::Instant> for Value {
     fn from(value: sixtyfps_corelib::animations::Instant) -> Self {
@@ -690,6 +718,18 @@ pub fn global_callbacks(&self, global_name: &str) -> Option<impl Iterator<Item =
         })
     }
 
+    /// List of publicly declared properties and callbacks in the exported global singleton
+    /// specified by its name.
+    fn global_properties_and_callbacks(
+        &self,
+        global_name: &str,
+    ) -> Option<impl Iterator<Item = (String, LangType)> + '_> {
+        // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
+        // which is not required, but this is safe because there is only one instance of the unerased type
+        let guard = unsafe { generativity::Guard::new(generativity::Id::new()) };
+        self.inner.unerase(guard).global_properties(global_name)
+    }
+
     /// The name of this Component as written in the .60 file
     pub fn name(&self) -> &str {
         // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
@@ -808,9 +848,16 @@ pub fn set_callback(
     ) -> Result<(), SetCallbackError> {
         generativity::make_guard!(guard);
         let comp = self.inner.unerase(guard);
+        let name = normalize_identifier(name);
+        let callback: CallbackHandler = Rc::new(callback);
         comp.description()
-            .set_callback_handler(comp.borrow(), &normalize_identifier(name), Box::new(callback))
-            .map_err(|()| SetCallbackError::NoSuchCallback)
+            .set_callback_handler(comp.borrow(), &name, forward_to_handler(&callback))
+            .map_err(|()| SetCallbackError::NoSuchCallback)?;
+        comp.borrow_instance()
+            .callback_handlers()
+            .borrow_mut()
+            .insert((None, name.into()), callback);
+        Ok(())
     }
 
     /// Call the given callback with the arguments
@@ -921,12 +968,20 @@ pub fn set_global_callback(
     ) -> Result<(), SetCallbackError> {
         generativity::make_guard!(guard);
         let comp = self.inner.unerase(guard);
+        let global = normalize_identifier(global);
+        let name = normalize_identifier(name);
+        let callback: CallbackHandler = Rc::new(callback);
         comp.description()
-            .get_global(comp.borrow(), &normalize_identifier(global))
+            .get_global(comp.borrow(), &global)
             .map_err(|()| SetCallbackError::NoSuchCallback)? // FIXME: should there be a NoSuchGlobal error?
             .as_ref()
-            .set_callback_handler(&normalize_identifier(name), Box::new(callback))
-            .map_err(|()| SetCallbackError::NoSuchCallback)
+            .set_callback_handler(&name, forward_to_handler(&callback))
+            .map_err(|()| SetCallbackError::NoSuchCallback)?;
+        comp.borrow_instance()
+            .callback_handlers()
+            .borrow_mut()
+            .insert((Some(global.into()), name.into()), callback);
+        Ok(())
     }
 
     /// Call the given callback within a global singleton with the arguments
@@ -1000,6 +1055,327 @@ pub fn as_weak(&self) -> WeakComponentInstance {
     pub fn window(&self) -> &Window {
         self.inner.window()
     }
+
+    /// Creates an instance of `definition`, typically a new build of the .60 file this instance
+    /// was created from, in the window of this instance, and moves the state of this instance
+    /// over to it. This allows reloading a .60 file without losing what was typed or selected
+    /// in the user interface.
+    ///
+    /// The following state is migrated when the name and the type are the same in both
+    /// definitions:
+    ///  * The public properties and the properties of exported global singletons whose value
+    ///    differs from the one given by their declaration in the .60 file, for example because
+    ///    they were set with [`Self::set_property`] or changed in a callback. Properties that
+    ///    still have the value of their declaration take the value declared in the new
+    ///    definition.
+    ///  * The callback handlers set with [`Self::set_callback`] and
+    ///    [`Self::set_global_callback`].
+    ///  * The scroll position of the `Flickable` elements, which are matched by their id in the
+    ///    .60 file. The `Flickable` elements with the same id are matched in drawing order.
+    ///
+    /// The state of other elements that is not exposed through public properties, such as the
+    /// text typed in a `TextInput`, is not migrated.
+    ///
+    /// The returned [`ReloadReport`] lists what was migrated, and what could not be migrated
+    /// because it was removed or changed type. This instance is left unchanged and should be
+    /// dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use sixtyfps_interpreter::{ComponentDefinition, ComponentCompiler, Value, SharedString};
+    /// let mut compiler = ComponentCompiler::default();
+    /// let definition = spin_on::spin_on(compiler.build_from_source(r#"
+    ///     MyWin := Window {
+    ///         property <int> counter: 0;
+    ///         property <string> label: "Hello";
+    ///     }
+    /// "#.into(), Default::default()));
+    /// let instance = definition.unwrap().create();
+    /// instance.set_property("counter", Value::from(42)).unwrap();
+    ///
+    /// let new_definition = spin_on::spin_on(compiler.build_from_source(r#"
+    ///     MyWin := Window {
+    ///         property <int> counter: 0;
+    ///         property <string> label: "World";
+    ///     }
+    /// "#.into(), Default::default()));
+    /// let (instance, report) = instance.reload_from(&new_definition.unwrap());
+    /// assert_eq!(instance.get_property("counter").unwrap(), Value::from(42));
+    /// assert_eq!(instance.get_property("label").unwrap(), Value::from(SharedString::from("World")));
+    /// assert!(report.issues().is_empty());
+    /// ```
+    pub fn reload_from(
+        &self,
+        definition: &ComponentDefinition,
+    ) -> (ComponentInstance, ReloadReport) {
+        let instance = definition.create_with_existing_window(self.window());
+        let mut report = ReloadReport::default();
+        let old_definition = self.definition();
+        for global in old_definition.globals() {
+            let new_properties: HashMap<_, _> = definition
+                .global_properties_and_callbacks(&global)
+                .map(|props| props.collect())
+                .unwrap_or_default();
+            if let Some(old_properties) = old_definition.global_properties_and_callbacks(&global) {
+                self.migrate_to(
+                    &instance,
+                    Some(&global),
+                    old_properties,
+                    &new_properties,
+                    &mut report,
+                );
+            }
+        }
+        let new_properties: HashMap<_, _> = definition.properties_and_callbacks().collect();
+        self.migrate_to(
+            &instance,
+            None,
+            old_definition.properties_and_callbacks(),
+            &new_properties,
+            &mut report,
+        );
+        self.migrate_flickable_viewports_to(&instance, &mut report);
+        (instance, report)
+    }
+
+    /// Moves the scroll position of the scrolled `Flickable` elements of this instance to the
+    /// `Flickable` elements with the same id in `instance`.
+    fn migrate_flickable_viewports_to(
+        &self,
+        instance: &ComponentInstance,
+        report: &mut ReloadReport,
+    ) {
+        use sixtyfps_corelib::items::{Flickable, ItemRef, Rectangle};
+        let viewport_x = Flickable::FIELD_OFFSETS.viewport + Rectangle::FIELD_OFFSETS.x;
+        let viewport_y = Flickable::FIELD_OFFSETS.viewport + Rectangle::FIELD_OFFSETS.y;
+        let mut new_flickables = flickables_by_id(&vtable::VRc::into_dyn(instance.inner.clone()));
+        for (id, old_flickables) in flickables_by_id(&vtable::VRc::into_dyn(self.inner.clone())) {
+            let mut new_flickables = new_flickables.remove(&id).unwrap_or_default().into_iter();
+            for old_flickable in old_flickables {
+                let new_flickable = new_flickables.next();
+                let old_flickable = old_flickable.borrow();
+                let old_flickable = ItemRef::downcast_pin::<Flickable>(old_flickable).unwrap();
+                let (x, y) = (
+                    viewport_x.apply_pin(old_flickable).get(),
+                    viewport_y.apply_pin(old_flickable).get(),
+                );
+                if x == 0. && y == 0. {
+                    continue;
+                }
+                let new_flickable = match &new_flickable {
+                    Some(new_flickable) => new_flickable.borrow(),
+                    None => {
+                        report.issues.push(MigrationIssue::FlickableViewportMismatch(id.clone()));
+                        continue;
+                    }
+                };
+                let new_flickable = ItemRef::downcast_pin::<Flickable>(new_flickable).unwrap();
+                // Setting the position would remove its binding, even if the value is the same
+                if viewport_x.apply_pin(new_flickable).get() != x {
+                    viewport_x.apply_pin(new_flickable).set(x);
+                }
+                if viewport_y.apply_pin(new_flickable).get() != y {
+                    viewport_y.apply_pin(new_flickable).set(y);
+                }
+                report.migrated.push(id.clone());
+            }
+        }
+    }
+
+    /// Moves the modified properties and the callback handlers of this instance, or of one of
+    /// its globals, to `instance`. The properties and callbacks of the new definition are
+    /// given by `new_properties`.
+    fn migrate_to(
+        &self,
+        instance: &ComponentInstance,
+        global: Option<&str>,
+        old_properties: impl Iterator<Item = (String, LangType)>,
+        new_properties: &HashMap<String, LangType>,
+        report: &mut ReloadReport,
+    ) {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let new_properties = new_properties
+            .iter()
+            .map(|(name, ty)| (normalize_identifier(name), ty))
+            .collect::<HashMap<_, _>>();
+        for (name, ty) in old_properties {
+            let normalized_name = normalize_identifier(&name);
+            let display_name = match global {
+                Some(global) => format!("{}.{}", global, name),
+                None => name.clone(),
+            };
+            let new_type = new_properties.get(&normalized_name);
+            if matches!(ty, LangType::Callback { .. }) {
+                let key: (Option<String>, String) =
+                    (global.map(|g| normalize_identifier(g).into()), normalized_name.into());
+                let handler = match comp.borrow_instance().callback_handlers().borrow().get(&key) {
+                    Some(handler) => handler.clone(),
+                    None => continue,
+                };
+                if new_type.map_or(true, |new_type| **new_type != ty) {
+                    report.issues.push(MigrationIssue::CallbackMismatch(display_name));
+                    continue;
+                }
+                let callback = move |args: &[Value]| handler(args);
+                let result = match global {
+                    Some(global) => instance.set_global_callback(global, &name, callback),
+                    None => instance.set_callback(&name, callback),
+                };
+                match result {
+                    Ok(()) => report.migrated.push(display_name),
+                    Err(_) => report.issues.push(MigrationIssue::CallbackMismatch(display_name)),
+                }
+            } else if ty.is_property_type() {
+                let value = match self.modified_property_value(global, &name) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if new_type.map_or(true, |new_type| **new_type != ty) {
+                    report.issues.push(MigrationIssue::PropertyMismatch(display_name));
+                    continue;
+                }
+                let new_value = match global {
+                    Some(global) => instance.get_global_property(global, &name),
+                    None => instance.get_property(&name),
+                };
+                // Setting the property would remove its binding, even if the value is the same
+                if new_value.map_or(false, |new_value| same_content(&new_value, &value)) {
+                    continue;
+                }
+                let result = match global {
+                    Some(global) => instance.set_global_property(global, &name, value),
+                    None => instance.set_property(&name, value),
+                };
+                match result {
+                    Ok(()) => report.migrated.push(display_name),
+                    Err(_) => report.issues.push(MigrationIssue::PropertyMismatch(display_name)),
+                }
+            }
+        }
+    }
+
+    /// Returns the value of a public property, or of a property of an exported global, if it
+    /// differs from the value given by its declaration in the .60 file.
+    fn modified_property_value(&self, global: Option<&str>, name: &str) -> Option<Value> {
+        generativity::make_guard!(guard);
+        let comp = self.inner.unerase(guard);
+        let name = normalize_identifier(name);
+        let (value, declared_value) = match global {
+            Some(global) => {
+                let global = comp
+                    .description()
+                    .get_global(comp.borrow(), &normalize_identifier(global))
+                    .ok()?;
+                (
+                    global.as_ref().get_property(&name).ok()?,
+                    global.as_ref().declared_property_value(&name),
+                )
+            }
+            None => (
+                comp.description().get_property(comp.borrow(), &name).ok()?,
+                comp.description().declared_property_value(comp.borrow(), &name),
+            ),
+        };
+        match declared_value {
+            Some(declared_value) if same_content(&declared_value, &value) => None,
+            _ => Some(value),
+        }
+    }
+}
+
+/// Returns the `Flickable` items of the component and of its repeated sub-components, indexed
+/// by the first of their ids in the .60 source, in drawing order. The `Flickable` elements
+/// without an id are left out.
+fn flickables_by_id(
+    component: &sixtyfps_corelib::component::ComponentRc,
+) -> HashMap<String, Vec<sixtyfps_corelib::items::ItemRc>> {
+    use sixtyfps_corelib::item_tree::{visit_items, ItemVisitorResult, TraversalOrder};
+    use sixtyfps_corelib::items::{Flickable, ItemRc, ItemRef};
+    let mut result: HashMap<String, Vec<ItemRc>> = HashMap::new();
+    visit_items(
+        component,
+        TraversalOrder::BackToFront,
+        |component, item, index, _| {
+            if ItemRef::downcast_pin::<Flickable>(item).is_some() {
+                let item_rc = ItemRc::new(component.clone(), index);
+                if let Some(id) = item_rc.element_ids().iter().next() {
+                    result.entry(id.to_string()).or_default().push(item_rc.clone());
+                }
+            }
+            ItemVisitorResult::Continue(())
+        },
+        (),
+    );
+    result
+}
+
+fn forward_to_handler(handler: &CallbackHandler) -> Box<dyn Fn(&[Value]) -> Value> {
+    let handler = handler.clone();
+    Box::new(move |args| handler(args))
+}
+
+/// Compares two values like `==`, except that models are compared by content instead of
+/// by identity, so that the model evaluated from an array literal in the .60 file is equal
+/// to the model of the property that was initialized from that literal.
+fn same_content(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        (Value::Model(lhs), Value::Model(rhs)) => {
+            lhs.row_count() == rhs.row_count()
+                && (0..lhs.row_count()).all(|row| match (lhs.row_data(row), rhs.row_data(row)) {
+                    (Some(lhs), Some(rhs)) => same_content(&lhs, &rhs),
+                    (lhs, rhs) => lhs.is_none() && rhs.is_none(),
+                })
+        }
+        (Value::Struct(lhs), Value::Struct(rhs)) => {
+            lhs.iter().count() == rhs.iter().count()
+                && lhs.iter().all(|(name, lhs)| {
+                    rhs.get_field(name).map_or(false, |rhs| same_content(lhs, rhs))
+                })
+        }
+        _ => lhs == rhs,
+    }
+}
+
+/// The result of [`ComponentInstance::reload_from`]
+#[derive(Debug, Clone, Default)]
+pub struct ReloadReport {
+    migrated: Vec<String>,
+    issues: Vec<MigrationIssue>,
+}
+
+impl ReloadReport {
+    /// The names of the properties and callbacks that were migrated to the new instance.
+    /// The properties and callbacks of globals are named `Global.name`, and the scroll
+    /// positions of `Flickable` elements are named by the id of the element.
+    pub fn migrated(&self) -> impl Iterator<Item = &str> {
+        self.migrated.iter().map(|name| name.as_str())
+    }
+
+    /// The state that could not be migrated to the new instance.
+    pub fn issues(&self) -> &[MigrationIssue] {
+        &self.issues
+    }
+}
+
+/// State that [`ComponentInstance::reload_from`] could not migrate to the new instance.
+/// The properties and callbacks of globals are named `Global.name`.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum MigrationIssue {
+    /// The property was modified, but it does not exist in the new definition or it has
+    /// a different type
+    #[error("property '{0}' was removed or changed type, its value was not migrated")]
+    PropertyMismatch(String),
+    /// A handler was set for the callback, but the callback does not exist in the new
+    /// definition or it has a different signature
+    #[error("callback '{0}' was removed or changed signature, its handler was not migrated")]
+    CallbackMismatch(String),
+    /// The `Flickable` with this id was scrolled, but there is no `Flickable` with that id in
+    /// the new definition
+    #[error("Flickable '{0}' was removed, its scroll position was not migrated")]
+    FlickableViewportMismatch(String),
 }
 
 /// A Weak references to a dynamic SixtyFPS components.
@@ -1069,6 +1445,26 @@ pub fn run_event_loop() {
         .run_event_loop(sixtyfps_corelib::backend::EventLoopQuitBehavior::QuitOnLastWindowClosed);
 }
 
+/// Processes the pending events and returns, instead of taking over the thread like
+/// [`run_event_loop()`]. If there is no pending event, it waits at most `timeout` for one.
+/// Timers and animations keep advancing across calls.
+///
+/// Returns `false` once the event loop was asked to quit, either explicitly or because the
+/// last window was closed. It also returns `false` without processing anything if the backend
+/// cannot process events without running the event loop.
+pub fn process_events(timeout: core::time::Duration) -> bool {
+    sixtyfps_rendering_backend_default::backend().process_events(
+        timeout,
+        sixtyfps_corelib::backend::EventLoopQuitBehavior::QuitOnLastWindowClosed,
+    )
+}
+
+/// Processes the events that are already pending without waiting for new ones. This is the
+/// same as calling [`process_events()`] with a zero timeout.
+pub fn pump_events() -> bool {
+    process_events(core::time::Duration::ZERO)
+}
+
 /// This module contains a few function use by tests
 pub mod testing {
     use sixtyfps_corelib::window::WindowHandleAccess;
@@ -1093,6 +1489,86 @@ pub fn send_keyboard_string_sequence(
             comp.window().window_handle(),
         );
     }
+    /// Wrapper around [`sixtyfps_corelib::accessibility::accessibility_tree`]
+    pub fn accessibility_tree(
+        comp: &super::ComponentInstance,
+    ) -> sixtyfps_corelib::accessibility::AccessibilityNode {
+        sixtyfps_corelib::accessibility::accessibility_tree(&vtable::VRc::into_dyn(
+            comp.inner.clone(),
+        ))
+    }
+    /// Wrapper around [`sixtyfps_corelib::tests::ElementHandle::find_all_by_id`]
+    pub fn find_all_by_id(comp: &super::ComponentInstance, id: &str) -> Vec<ElementHandle> {
+        ElementHandle::find_all_by_id(
+            &vtable::VRc::into_dyn(comp.inner.clone()),
+            comp.window().window_handle(),
+            id,
+        )
+    }
+    /// Returns the first element of the component, in drawing order, that has the given `id`
+    /// in the .60 source.
+    pub fn find_by_id(comp: &super::ComponentInstance, id: &str) -> Option<ElementHandle> {
+        find_all_by_id(comp, id).into_iter().next()
+    }
+    /// Returns the first element of the component, in drawing order, whose text or
+    /// `accessible-label` is equal to `text`.
+    pub fn find_by_text(comp: &super::ComponentInstance, text: &str) -> Option<ElementHandle> {
+        ElementHandle::find_all_by_text(
+            &vtable::VRc::into_dyn(comp.inner.clone()),
+            comp.window().window_handle(),
+            text,
+        )
+        .into_iter()
+        .next()
+    }
+
+    pub use sixtyfps_corelib::tests::ElementHandle;
+}
+
+#[test]
+fn element_lookup() {
+    use sixtyfps_corelib::graphics::{Point, Rect, Size};
+    sixtyfps_rendering_backend_testing::init();
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export Dummy := Rectangle {
+        width: 300px;
+        height: 300px;
+        property <int> clicked;
+        Rectangle {
+            x: 100px;
+            y: 50px;
+            width: 100px;
+            height: 100px;
+            ok_button := TouchArea {
+                x: 10px;
+                width: 50px;
+                clicked => { root.clicked += 1; }
+            }
+        }
+        Text { text: "Hello"; }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .unwrap();
+    let instance = comp_def.create();
+
+    let button = testing::find_by_id(&instance, "ok-button").unwrap();
+    assert_eq!(button.element_ids().as_slice(), &[SharedString::from("ok-button")]);
+    assert_eq!(button.absolute_geometry(), Rect::new(Point::new(110., 50.), Size::new(50., 100.)));
+    button.click();
+    assert_eq!(instance.get_property("clicked"), Ok(Value::Number(1.)));
+    button.double_click();
+    assert_eq!(instance.get_property("clicked"), Ok(Value::Number(3.)));
+
+    assert!(testing::find_by_id(&instance, "cancel-button").is_none());
+    assert!(testing::find_by_text(&instance, "Hello").is_some());
+    assert!(testing::find_by_text(&instance, "Goodbye").is_none());
 }
 
 #[test]
@@ -1399,6 +1875,188 @@ fn check_model(val: Value, r: &[f64]) {
     check_model(instance.get_property("prop").unwrap(), &[]);
 }
 
+#[test]
+fn component_definition_model_adapters() {
+    use sixtyfps_corelib::model::*;
+    sixtyfps_rendering_backend_testing::init();
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(compiler.build_from_source(
+        "export Dummy := Rectangle { property <[int]> values; property <[int]> even; property <[string]> labels; }"
+            .into(),
+        "".into(),
+    ))
+    .unwrap();
+    let instance = comp_def.create();
+
+    let source =
+        Rc::new(VecModel::from(vec![Value::Number(1.), Value::Number(2.), Value::Number(4.)]));
+    instance.set_property("values", Value::Model(ModelHandle::new(source.clone()))).unwrap();
+    let values: Rc<dyn Model<Data = Value>> = match instance.get_property("values").unwrap() {
+        Value::Model(m) => Rc::new(m),
+        v => panic!("{:?} not a model", v),
+    };
+
+    let even =
+        FilterModel::new(values.clone(), |v| matches!(v, Value::Number(n) if *n as i32 % 2 == 0));
+    instance.set_property("even", Value::Model(ModelHandle::new(Rc::new(even)))).unwrap();
+    let labels = MapModel::new_with_inverse(
+        values,
+        |v| match v {
+            Value::Number(n) => Value::String(n.to_string().into()),
+            v => v,
+        },
+        |v| match v {
+            Value::String(s) => Value::Number(s.parse().unwrap()),
+            v => v,
+        },
+    );
+    instance.set_property("labels", Value::Model(ModelHandle::new(Rc::new(labels)))).unwrap();
+
+    let (even, labels) = match (instance.get_property("even"), instance.get_property("labels")) {
+        (Ok(Value::Model(even)), Ok(Value::Model(labels))) => (even, labels),
+        _ => panic!("not models"),
+    };
+    assert_eq!(even.iter().collect::<Vec<_>>(), vec![Value::Number(2.), Value::Number(4.)]);
+    assert_eq!(labels.row_data(0), Some(Value::String("1".into())));
+
+    labels.set_row_data(0, Value::String("6".into()));
+    assert_eq!(source.row_data(0), Some(Value::Number(6.)));
+    assert_eq!(
+        even.iter().collect::<Vec<_>>(),
+        vec![Value::Number(6.), Value::Number(2.), Value::Number(4.)]
+    );
+}
+
+#[test]
+fn reload_from() {
+    sixtyfps_rendering_backend_testing::init();
+    let build = |source: &str| {
+        let mut compiler = ComponentCompiler::default();
+        compiler.set_style("fluent".into());
+        let definition = spin_on::spin_on(compiler.build_from_source(source.into(), "".into()));
+        assert!(compiler.diagnostics().is_empty(), "{:?}", compiler.diagnostics());
+        definition.unwrap()
+    };
+    let old_definition = build(
+        r#"
+    export global Settings := {
+        property <bool> dark-mode;
+        property <int> volume;
+        callback log(string);
+    }
+    export Dummy := Rectangle {
+        width: 100px;
+        height: 100px;
+        property <int> counter: 0;
+        property <string> label: "Hello";
+        property <string> summary: label + " " + counter;
+        property <[int]> values: [1, 2];
+        property <int> removed;
+        property <int> retyped;
+        callback clicked();
+        callback changed-signature();
+        list := Flickable {
+            width: 50px;
+            viewport-height: 1000px;
+        }
+        other := Flickable {
+            x: 50px;
+            width: 50px;
+            viewport-height: 1000px;
+        }
+    }"#,
+    );
+    let instance = old_definition.create();
+    instance.set_property("counter", Value::from(42)).unwrap();
+    instance.set_property("removed", Value::from(1)).unwrap();
+    instance.set_property("retyped", Value::from(2)).unwrap();
+    instance.set_global_property("Settings", "dark-mode", Value::from(true)).unwrap();
+    instance.set_global_property("Settings", "volume", Value::from(11)).unwrap();
+    testing::find_by_id(&instance, "list").unwrap().scroll(0., -100.);
+    testing::find_by_id(&instance, "other").unwrap().scroll(0., -100.);
+    let logged = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let logged_ = logged.clone();
+    instance
+        .set_global_callback("Settings", "log", move |args| {
+            logged_.borrow_mut().push(args[0].clone());
+            Value::Void
+        })
+        .unwrap();
+    let clicks = Rc::new(std::cell::Cell::new(0));
+    let clicks_ = clicks.clone();
+    instance
+        .set_callback("clicked", move |_| {
+            clicks_.set(clicks_.get() + 1);
+            Value::Void
+        })
+        .unwrap();
+    instance.set_callback("changed-signature", |_| Value::Void).unwrap();
+
+    let new_definition = build(
+        r#"
+    export global Settings := {
+        property <bool> dark-mode;
+        property <string> volume;
+        callback log(string);
+    }
+    export Dummy := Rectangle {
+        width: 100px;
+        height: 100px;
+        property <int> counter: 0;
+        property <string> label: "World";
+        property <string> summary: counter + " " + label;
+        property <[int]> values: [1, 2, 3];
+        property <string> retyped;
+        property <length> list-viewport-y: list.viewport-y;
+        callback clicked();
+        callback changed-signature(int);
+        list := Flickable {
+            width: 50px;
+            viewport-height: 1200px;
+        }
+    }"#,
+    );
+    let (instance, report) = instance.reload_from(&new_definition);
+
+    assert_eq!(instance.get_property("counter"), Ok(Value::from(42)));
+    assert_eq!(instance.get_property("label"), Ok(Value::from(SharedString::from("World"))));
+    assert_eq!(instance.get_property("summary"), Ok(Value::from(SharedString::from("42 World"))));
+    if let Value::Model(values) = instance.get_property("values").unwrap() {
+        assert_eq!(values.row_count(), 3);
+    } else {
+        panic!("values is not a model");
+    }
+    assert_eq!(instance.get_global_property("Settings", "dark-mode"), Ok(Value::from(true)));
+    assert_eq!(
+        instance.get_global_property("Settings", "volume"),
+        Ok(Value::from(SharedString::default()))
+    );
+    instance.invoke_callback("clicked", &[]).unwrap();
+    assert_eq!(clicks.get(), 1);
+    instance
+        .invoke_global_callback("Settings", "log", &[Value::from(SharedString::from("reloaded"))])
+        .unwrap();
+    assert_eq!(logged.borrow().as_slice(), [Value::from(SharedString::from("reloaded"))]);
+    assert_eq!(instance.get_property("list-viewport-y"), Ok(Value::from(-100.)));
+
+    let mut migrated = report.migrated().collect::<Vec<_>>();
+    migrated.sort_unstable();
+    assert_eq!(migrated, ["Settings.dark-mode", "Settings.log", "clicked", "counter", "list"]);
+    let mut issues = report.issues().to_vec();
+    issues.sort_unstable_by_key(|issue| issue.to_string());
+    assert_eq!(
+        issues,
+        [
+            MigrationIssue::FlickableViewportMismatch("other".into()),
+            MigrationIssue::CallbackMismatch("changed-signature".into()),
+            MigrationIssue::PropertyMismatch("Settings.volume".into()),
+            MigrationIssue::PropertyMismatch("removed".into()),
+            MigrationIssue::PropertyMismatch("retyped".into()),
+        ]
+    );
+}
+
 #[cfg(feature = "ffi")]
 #[allow(missing_docs)]
 #[path = "ffi.rs"]
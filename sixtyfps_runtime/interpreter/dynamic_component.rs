@@ -11,17 +11,18 @@
 use sixtyfps_compilerlib::object_tree::ElementRc;
 use sixtyfps_compilerlib::*;
 use sixtyfps_compilerlib::{diagnostics::BuildDiagnostics, object_tree::PropertyDeclaration};
+use sixtyfps_corelib::accessibility::AccessibleStringProperty;
 use sixtyfps_corelib::component::{Component, ComponentRef, ComponentRefPin, ComponentVTable};
 use sixtyfps_corelib::item_tree::{
     ItemTreeNode, ItemVisitorRefMut, ItemVisitorVTable, TraversalOrder, VisitChildrenResult,
 };
 use sixtyfps_corelib::items::{
-    Flickable, ItemRc, ItemRef, ItemVTable, ItemWeak, PropertyAnimation,
+    AccessibleRole, Flickable, ItemRc, ItemRef, ItemVTable, ItemWeak, PropertyAnimation,
 };
 use sixtyfps_corelib::layout::{BoxLayoutCellData, LayoutInfo, Orientation};
 use sixtyfps_corelib::model::RepeatedComponent;
 use sixtyfps_corelib::model::Repeater;
-use sixtyfps_corelib::properties::InterpolatedPropertyValue;
+use sixtyfps_corelib::properties::{ChangeTracker, InterpolatedPropertyValue};
 use sixtyfps_corelib::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use sixtyfps_corelib::window::{api::Window, WindowHandleAccess, WindowRc};
 use sixtyfps_corelib::{Brush, Color, Property, SharedString, SharedVector};
@@ -174,6 +175,20 @@ fn get_item_ref(self: Pin<&Self>, index: usize) -> Pin<ItemRef> {
     fn parent_item(self: Pin<&Self>, index: usize, result: &mut ItemWeak) {
         self.borrow().as_ref().parent_item(index, result)
     }
+    fn accessible_role(self: Pin<&Self>, index: usize) -> AccessibleRole {
+        self.borrow().as_ref().accessible_role(index)
+    }
+    fn accessible_string_property(
+        self: Pin<&Self>,
+        index: usize,
+        what: AccessibleStringProperty,
+        result: &mut SharedString,
+    ) {
+        self.borrow().as_ref().accessible_string_property(index, what, result)
+    }
+    fn element_ids(self: Pin<&Self>, index: usize, result: &mut SharedVector<SharedString>) {
+        self.borrow().as_ref().element_ids(index, result)
+    }
 }
 
 sixtyfps_corelib::ComponentVTable_static!(static COMPONENT_BOX_VT for ErasedComponentBox);
@@ -185,8 +200,16 @@ pub(crate) struct ComponentExtraData {
         once_cell::unsync::OnceCell<vtable::VWeak<ComponentVTable, ErasedComponentBox>>,
     // resource id -> file path
     pub(crate) embedded_file_resources: HashMap<usize, String>,
+    /// The trackers for the `changed` handlers, initialized in run_setup_code
+    pub(crate) change_trackers: once_cell::unsync::OnceCell<Vec<ChangeTracker>>,
+    /// The callback handlers set through the public API, indexed by the normalized name of
+    /// the global (if any) and of the callback. They are moved over by `reload_from`.
+    pub(crate) callback_handlers:
+        std::cell::RefCell<HashMap<(Option<String>, String), CallbackHandler>>,
 }
 
+pub(crate) type CallbackHandler = Rc<dyn Fn(&[Value]) -> Value>;
+
 struct ErasedRepeaterWithinComponent<'id>(RepeaterWithinComponent<'id, 'static>);
 impl<'id, 'sub_id> From<RepeaterWithinComponent<'id, 'sub_id>>
     for ErasedRepeaterWithinComponent<'id>
@@ -266,6 +289,10 @@ pub struct ComponentDescription<'id> {
     dynamic_type: Rc<dynamic_type::TypeInfo<'id>>,
     item_tree: Vec<ItemTreeNode<crate::dynamic_type::Instance<'id>>>,
     pub(crate) items: HashMap<String, ItemWithinComponent>,
+    /// The elements which have accessibility properties, indexed by their index in the item tree
+    accessible_elements: HashMap<usize, ElementRc>,
+    /// The ids of the elements in the .60 source, indexed by their index in the item tree
+    element_ids: HashMap<usize, SharedVector<SharedString>>,
     pub(crate) custom_properties: HashMap<String, PropertiesWithinComponent>,
     pub(crate) custom_callbacks: HashMap<String, FieldOffset<Instance<'id>, Callback>>,
     repeater: Vec<ErasedRepeaterWithinComponent<'id>>,
@@ -297,11 +324,8 @@ fn internal_properties_to_public<'a>(
         let name = v
             .node
             .as_ref()
-            .and_then(|n| {
-                n.as_ref()
-                    .either(|n| n.DeclaredIdentifier(), |n| n.DeclaredIdentifier())
-                    .child_token(parser::SyntaxKind::Identifier)
-            })
+            .and_then(|n| n.child_node(parser::SyntaxKind::DeclaredIdentifier))
+            .and_then(|n| n.child_token(parser::SyntaxKind::Identifier))
             .map(|n| n.to_string())
             .unwrap_or_else(|| s.clone());
         (name, v.property_type.clone())
@@ -556,6 +580,52 @@ pub fn invoke_callback(
     }
 
     // Return the global with the given name
+    /// Evaluates the value that the declaration of a public property in the .60 file gives
+    /// to that property. Two-way bindings are followed to find the declaration.
+    ///
+    /// Returns None if the property does not exist, or if it is only linked to the property of
+    /// a builtin element that does not have a binding, in which case its default value is unknown.
+    pub fn declared_property_value(&self, component: ComponentRefPin, name: &str) -> Option<Value> {
+        if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
+            return None;
+        }
+        generativity::make_guard!(guard);
+        // Safety: we just verified that the component has the right vtable
+        let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
+        let root_element = &self.original.root_element;
+        let declaration = self.public_properties.get(name)?;
+        let mut nr = match &declaration.is_alias {
+            Some(alias) => alias.clone(),
+            None => NamedReference::new(root_element, name),
+        };
+        let mut visited = vec![];
+        loop {
+            let element = nr.element();
+            let next = {
+                let elem = element.borrow();
+                let binding = match elem.bindings.get(nr.name()) {
+                    Some(binding) => binding.borrow(),
+                    None if Rc::ptr_eq(&element, root_element) => {
+                        return Some(eval::default_value_for_type(&declaration.property_type));
+                    }
+                    None => return None,
+                };
+                if !matches!(binding.expression, Expression::Invalid) {
+                    return Some(eval::eval_expression(
+                        &binding.expression,
+                        &mut eval::EvalLocalContext::from_component_instance(c),
+                    ));
+                }
+                binding.two_way_bindings.first()?.clone()
+            };
+            visited.push(nr);
+            if visited.contains(&next) {
+                return None;
+            }
+            nr = next;
+        }
+    }
+
     pub fn get_global(
         &self,
         component: ComponentRefPin,
@@ -727,6 +797,11 @@ pub(crate) fn generate_component<'id>(
                 rtti_for::<BorderRectangle>(),
                 rtti_for::<TouchArea>(),
                 rtti_for::<FocusScope>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
+                rtti_for::<GestureArea>(),
+                rtti_for::<Shortcut>(),
+                rtti_for::<TimerItem>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
                 rtti_for::<WindowItem>(),
@@ -763,6 +838,8 @@ fn push(rtti: &mut HashMap<&str, Rc<ItemRTTI>>) {
     struct TreeBuilder<'id> {
         tree_array: Vec<ItemTreeNode<Instance<'id>>>,
         items_types: HashMap<String, ItemWithinComponent>,
+        accessible_elements: HashMap<usize, ElementRc>,
+        element_ids: HashMap<usize, SharedVector<SharedString>>,
         type_builder: dynamic_type::TypeBuilder<'id>,
         repeater: Vec<ErasedRepeaterWithinComponent<'id>>,
         repeater_names: HashMap<String, usize>,
@@ -817,6 +894,15 @@ fn push_native_item(
             } else {
                 self.type_builder.add_field(rt.type_info)
             };
+            if !item.accessibility_props.is_empty() {
+                self.accessible_elements.insert(self.tree_array.len(), rc_item.clone());
+            }
+            if !item.element_ids.is_empty() {
+                self.element_ids.insert(
+                    self.tree_array.len(),
+                    item.element_ids.iter().map(|id| SharedString::from(id.as_str())).collect(),
+                );
+            }
             self.tree_array.push(ItemTreeNode::Item {
                 item: unsafe { vtable::VOffset::from_raw(rt.vtable, offset) },
                 children_index: child_offset,
@@ -853,6 +939,8 @@ fn enter_component_children(
     let mut builder = TreeBuilder {
         tree_array: vec![],
         items_types: HashMap::new(),
+        accessible_elements: HashMap::new(),
+        element_ids: HashMap::new(),
         type_builder: dynamic_type::TypeBuilder::new(guard),
         repeater: vec![],
         repeater_names: HashMap::new(),
@@ -923,6 +1011,7 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
             Type::Struct { .. } => property_info::<Value>(),
             Type::Array(_) => property_info::<Value>(),
             Type::Percent => property_info::<f32>(),
+            Type::Enumeration(e) if e.node.is_some() => property_info::<Value>(),
             Type::Enumeration(e) => match e.name.as_ref() {
                 "LayoutAlignment" => property_info::<sixtyfps_corelib::layout::LayoutAlignment>(),
                 "TextHorizontalAlignment" => {
@@ -940,10 +1029,15 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
                     property_info::<sixtyfps_corelib::items::StandardButtonKind>()
                 }
                 "DialogButtonRole" => property_info::<sixtyfps_corelib::items::DialogButtonRole>(),
+                "SwipeDirection" => property_info::<sixtyfps_corelib::items::SwipeDirection>(),
                 "PointerEventButton" => {
                     property_info::<sixtyfps_corelib::items::PointerEventButton>()
                 }
                 "PointerEventKind" => property_info::<sixtyfps_corelib::items::PointerEventKind>(),
+                "AnimationDirection" => {
+                    property_info::<sixtyfps_corelib::items::AnimationDirection>()
+                }
+                "AccessibleRole" => property_info::<sixtyfps_corelib::items::AccessibleRole>(),
                 _ => panic!("unknown enum"),
             },
             Type::LayoutCache => property_info::<SharedVector<f32>>(),
@@ -1020,6 +1114,9 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
         layout_info,
         get_item_ref,
         parent_item,
+        accessible_role,
+        accessible_string_property,
+        element_ids,
         drop_in_place,
         dealloc,
     };
@@ -1028,6 +1125,8 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
         dynamic_type: builder.type_builder.build(),
         item_tree: builder.tree_array,
         items: builder.items_types,
+        accessible_elements: builder.accessible_elements,
+        element_ids: builder.element_ids,
         custom_properties,
         custom_callbacks,
         original: component.clone(),
@@ -1149,9 +1248,12 @@ pub fn instantiate(
 
     // Some properties are generated as Value, but for which the default constructed Value must be initialized
     for (prop_name, decl) in &component_type.original.root_element.borrow().property_declarations {
-        if !matches!(decl.property_type, Type::Struct { .. } | Type::Array(_))
-            || decl.is_alias.is_some()
-        {
+        let is_value_type = match &decl.property_type {
+            Type::Struct { .. } | Type::Array(_) => true,
+            Type::Enumeration(e) => e.node.is_some(),
+            _ => false,
+        };
+        if !is_value_type || decl.is_alias.is_some() {
             continue;
         }
         if let Some(b) = component_type.original.root_element.borrow().bindings.get(prop_name) {
@@ -1440,6 +1542,53 @@ pub fn run_setup_code(&self) {
                 &mut eval::EvalLocalContext::from_component_instance(instance_ref),
             );
         }
+
+        let mut change_trackers = vec![];
+        let self_weak = instance_ref.self_weak().get().unwrap();
+        object_tree::recurse_elem(
+            &self.0.component_type.original.root_element,
+            &(),
+            &mut |elem, _| {
+                for (prop, callbacks) in &elem.borrow().change_callbacks {
+                    let tracker = ChangeTracker::default();
+                    tracker.init(
+                        {
+                            let self_weak = self_weak.clone();
+                            let elem = elem.clone();
+                            let prop = prop.clone();
+                            move || {
+                                self_weak.upgrade().map(|c| {
+                                    generativity::make_guard!(guard);
+                                    let c = c.unerase(guard);
+                                    eval::load_property(c.borrow_instance(), &elem, &prop).unwrap()
+                                })
+                            }
+                        },
+                        {
+                            let self_weak = self_weak.clone();
+                            let callbacks = callbacks.borrow().clone();
+                            move || {
+                                if let Some(c) = self_weak.upgrade() {
+                                    generativity::make_guard!(guard);
+                                    let c = c.unerase(guard);
+                                    for expr in &callbacks {
+                                        eval::eval_expression(
+                                            expr,
+                                            &mut eval::EvalLocalContext::from_component_instance(
+                                                c.borrow_instance(),
+                                            ),
+                                        );
+                                    }
+                                }
+                            }
+                        },
+                    );
+                    change_trackers.push(tracker);
+                }
+            },
+        );
+        let extra_data = instance_ref.component_type.extra_data_offset.apply(instance_ref.as_ref());
+        extra_data.change_trackers.set(change_trackers).ok();
     }
 }
 impl<'id> From<ComponentBox<'id>> for ErasedComponentBox {
@@ -1542,6 +1691,60 @@ extern "C" fn layout_info(component: ComponentRefPin, orientation: Orientation)
     *result = ItemRc::new(self_rc, *parent_index as _).downgrade();
 }
 
+/// Returns the property `prop` of the element at `index` in the item tree, if it is set.
+fn accessible_property(instance_ref: InstanceRef, index: usize, prop: &str) -> Option<Value> {
+    let nr = instance_ref
+        .component_type
+        .accessible_elements
+        .get(&index)?
+        .borrow()
+        .accessibility_props
+        .get(prop)?
+        .clone();
+    eval::load_property(instance_ref, &nr.element(), nr.name()).ok()
+}
+
+extern "C" fn accessible_role(component: ComponentRefPin, index: usize) -> AccessibleRole {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    accessible_property(instance_ref, index, "accessible-role")
+        .and_then(|v| v.try_into().ok())
+        .unwrap_or(AccessibleRole::none)
+}
+
+extern "C" fn accessible_string_property(
+    component: ComponentRefPin,
+    index: usize,
+    what: AccessibleStringProperty,
+    result: &mut SharedString,
+) {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let prop = match what {
+        AccessibleStringProperty::Label => "accessible-label",
+        AccessibleStringProperty::Description => "accessible-description",
+        AccessibleStringProperty::Value => "accessible-value",
+        AccessibleStringProperty::Checked => "accessible-checked",
+    };
+    match accessible_property(instance_ref, index, prop) {
+        Some(Value::String(s)) => *result = s,
+        Some(Value::Bool(b)) => *result = if b { "true" } else { "false" }.into(),
+        _ => {}
+    }
+}
+
+extern "C" fn element_ids(
+    component: ComponentRefPin,
+    index: usize,
+    result: &mut SharedVector<SharedString>,
+) {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    if let Some(ids) = instance_ref.component_type.element_ids.get(&index) {
+        *result = ids.clone();
+    }
+}
+
 unsafe extern "C" fn drop_in_place(component: vtable::VRefMut<ComponentVTable>) -> vtable::Layout {
     let instance_ptr = component.as_ptr() as *mut Instance<'static>;
     let layout = (*instance_ptr).type_info().layout();
@@ -1597,6 +1800,13 @@ pub fn self_weak(
         &extra_data.self_weak
     }
 
+    pub fn callback_handlers(
+        &self,
+    ) -> &std::cell::RefCell<HashMap<(Option<String>, String), CallbackHandler>> {
+        let extra_data = self.component_type.extra_data_offset.apply(self.as_ref());
+        &extra_data.callback_handlers
+    }
+
     pub fn window(&self) -> &sixtyfps_corelib::window::api::Window {
         self.component_type.window_offset.apply(self.as_ref()).as_ref().as_ref().unwrap()
     }
@@ -1644,3 +1854,22 @@ pub fn show_popup(
     inst.run_setup_code();
     parent_window.show_popup(&vtable::VRc::into_dyn(inst), pos, parent_item);
 }
+
+pub fn show_child_window(
+    child_window: &object_tree::PopupWindow,
+    index: usize,
+    parent_comp: ComponentRefPin,
+    parent_window: &WindowRc,
+    parent_item: &ItemRc,
+) {
+    if parent_window.clone().is_child_window_shown(index, parent_item) {
+        return;
+    }
+    generativity::make_guard!(guard);
+    let compiled = generate_component(&child_window.component, guard);
+    let window = sixtyfps_rendering_backend_default::backend().create_window();
+    let inst = instantiate(compiled, Some(parent_comp), Some(&window));
+    window.set_component(&vtable::VRc::into_dyn(inst.clone()));
+    inst.run_setup_code();
+    parent_window.clone().show_child_window(index, parent_item, &window);
+}
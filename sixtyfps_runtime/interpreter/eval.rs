@@ -5,7 +5,7 @@
 use crate::dynamic_component::InstanceRef;
 use core::convert::TryInto;
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement};
+use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
 use corelib::items::{ItemRef, PropertyAnimation};
 use corelib::model::{Model, ModelHandle};
 use corelib::rtti::AnimatedBindingKind;
@@ -142,6 +142,7 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
         Expression::NumberLiteral(n, unit) => Value::Number(unit.normalize(*n)),
         Expression::BoolLiteral(b) => Value::Bool(*b),
         Expression::CallbackReference { .. } => panic!("callback in expression"),
+        Expression::FunctionReference { .. } => panic!("function in expression"),
         Expression::BuiltinFunctionReference(..) => panic!(
             "naked builtin function reference not allowed, should be handled by function call"
         ),
@@ -215,6 +216,11 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 let args = arguments.iter().map(|e| eval_expression(e, local_context)).collect::<Vec<_>>();
                 invoke_callback(local_context.component_instance, &nr.element(), nr.name(), &args).unwrap()
             }
+            Expression::FunctionReference(nr) => {
+                let args = arguments.iter().map(|e| eval_expression(e, local_context)).collect::<Vec<_>>();
+                call_function(local_context.component_instance, &nr.element(), nr.name(), args)
+                    .unwrap_or_else(|| panic!("unknown function {}", nr.name()))
+            }
             Expression::BuiltinFunctionReference(BuiltinFunction::GetWindowScaleFactor, _) => {
                 match local_context.component_instance {
                     ComponentInstance::InstanceRef(component) => Value::Number(window_ref(component).unwrap().scale_factor() as _),
@@ -345,6 +351,43 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                     panic!("internal error: argument to SetFocusItem must be an element")
                 }
             }
+            Expression::BuiltinFunctionReference(f @ (BuiltinFunction::ShowChildWindow | BuiltinFunction::HideChildWindow), _) => {
+                if arguments.len() != 1 {
+                    panic!("internal error: incorrect argument count to {:?}", f)
+                }
+                let component = match  local_context.component_instance  {
+                    ComponentInstance::InstanceRef(c) => c,
+                    ComponentInstance::GlobalComponent(_) => panic!("Cannot show a window from a global component")
+                };
+                if let Expression::ElementReference(child_window) = &arguments[0] {
+                    let child_window = child_window.upgrade().unwrap();
+                    let child_comp = child_window.borrow().enclosing_component.upgrade().unwrap();
+                    let parent_component = child_comp.parent_element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap();
+                    let popup_list = parent_component.popup_windows.borrow();
+                    let (index, popup) = popup_list.iter().enumerate().find(|(_, p)| Rc::ptr_eq(&p.component, &child_comp)).unwrap();
+
+                    generativity::make_guard!(guard);
+                    let enclosing_component =
+                        enclosing_component_for_element(&popup.parent_element, component, guard);
+                    let parent_item_info = &enclosing_component.component_type.items[popup.parent_element.borrow().id.as_str()];
+                    let parent_item_comp = enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+                    let parent_item = corelib::items::ItemRc::new(vtable::VRc::into_dyn(parent_item_comp), parent_item_info.item_index());
+
+                    if matches!(f, BuiltinFunction::ShowChildWindow) {
+                        crate::dynamic_component::show_child_window(
+                            popup,
+                            index,
+                            component.borrow(),
+                            window_ref(component).unwrap(),
+                            &parent_item);
+                    } else {
+                        window_ref(component).unwrap().clone().hide_child_window(index, &parent_item);
+                    }
+                    Value::Void
+                } else {
+                    panic!("internal error: argument to {:?} must be an element", f)
+                }
+            }
             Expression::BuiltinFunctionReference(BuiltinFunction::StringIsFloat, _) => {
                 if arguments.len() != 1 {
                     panic!("internal error: incorrect argument count to StringIsFloat")
@@ -467,6 +510,28 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                     panic!("Argument not a string");
                 }
             }
+            Expression::BuiltinFunctionReference(BuiltinFunction::Translate, _) => {
+                let mut args = arguments.iter().map(|a| eval_expression(a, local_context));
+                let mut next_string = || -> SharedString { args.next().unwrap().try_into().unwrap() };
+                let (original, context, domain) = (next_string(), next_string(), next_string());
+                let format_args = match args.next() {
+                    Some(Value::Model(m)) => m.iter().map(|v| v.try_into().unwrap()).collect::<Vec<SharedString>>(),
+                    _ => panic!("internal error: the arguments of Translate must be an array"),
+                };
+                let n: i32 = args.next().unwrap().try_into().unwrap();
+                let plural: SharedString = args.next().unwrap().try_into().unwrap();
+                Value::String(corelib::translations::translate(&original, &context, &domain, &format_args, n, &plural))
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::KeySequenceDisplayText, _) => {
+                if arguments.len() != 1 {
+                    panic!("internal error: incorrect argument count to KeySequenceDisplayText")
+                }
+                if let Value::String(s) = eval_expression(&arguments[0], local_context) {
+                    Value::String(corelib::items::key_sequence_display_text(&s))
+                } else {
+                    panic!("Argument not a string");
+                }
+            }
             _ => panic!("call of something not a callback"),
         }
         Expression::SelfAssignment { lhs, rhs, op } => {
@@ -594,6 +659,11 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::Spring(mass, stiffness, damping) => {
+                corelib::animations::EasingCurve::Spring([*mass, *stiffness, *damping])
+            }
+            EasingCurve::Steps(steps) => corelib::animations::EasingCurve::Steps(*steps),
+            EasingCurve::Custom(name) => corelib::animations::EasingCurve::custom(name),
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -603,6 +673,13 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 GradientStop{ color, position }
             }))))
         }
+        Expression::RadialGradient{stops} => {
+            Value::Brush(Brush::RadialGradient(RadialGradientBrush::new_circle(stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
+            }))))
+        }
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.clone(), value.to_string())
         }
@@ -955,6 +1032,27 @@ pub(crate) fn invoke_callback(
     }
 }
 
+/// Call the function declared with the `function` keyword in the given element
+pub(crate) fn call_function(
+    component_instance: ComponentInstance,
+    element: &ElementRc,
+    function_name: &str,
+    args: Vec<Value>,
+) -> Option<Value> {
+    generativity::make_guard!(guard);
+    match enclosing_component_instance_for_element(element, component_instance, guard) {
+        ComponentInstance::InstanceRef(enclosing_component) => {
+            let element = element.borrow();
+            let code = element.functions.get(function_name)?.code.borrow();
+            let mut ctx = EvalLocalContext::from_function_arguments(enclosing_component, args);
+            Some(eval_expression(&code, &mut ctx))
+        }
+        ComponentInstance::GlobalComponent(global) => {
+            global.as_ref().eval_function(function_name, args).ok()
+        }
+    }
+}
+
 fn root_component_instance<'a, 'old_id, 'new_id>(
     component: InstanceRef<'a, 'old_id>,
     guard: generativity::Guard<'new_id>,
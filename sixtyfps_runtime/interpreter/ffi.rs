@@ -674,6 +674,21 @@ fn as_model_notify(&self) -> &ModelNotify {
     notify.as_model_notify().row_removed(row, count);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_interpreter_model_notify_row_moved(
+    notify: &ModelNotifyOpaque,
+    from: usize,
+    to: usize,
+    count: usize,
+) {
+    notify.as_model_notify().row_moved(from, to, count);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_interpreter_model_notify_reset(notify: &ModelNotifyOpaque) {
+    notify.as_model_notify().reset();
+}
+
 // FIXME: Figure out how to re-export the one from compilerlib
 /// DiagnosticLevel describes the severity of a diagnostic.
 #[derive(Clone)]
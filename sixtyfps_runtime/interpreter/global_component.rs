@@ -87,6 +87,12 @@ fn set_property(
     fn get_property(self: Pin<&Self>, prop_name: &str) -> Result<Value, ()>;
 
     fn get_property_ptr(self: Pin<&Self>, prop_name: &str) -> *const ();
+
+    /// The value given to the property by its declaration in the .60 file, if known
+    fn declared_property_value(self: Pin<&Self>, prop_name: &str) -> Option<Value>;
+
+    /// Call a function declared with the `function` keyword in this global
+    fn eval_function(self: Pin<&Self>, function_name: &str, args: Vec<Value>) -> Result<Value, ()>;
 }
 
 pub fn instantiate(description: &CompiledGlobal) -> (String, Pin<Rc<dyn GlobalComponent>>) {
@@ -161,6 +167,12 @@ fn get_property_ptr(self: Pin<&Self>, prop_name: &str) -> *const () {
         )
     }
 
+    fn declared_property_value(self: Pin<&Self>, prop_name: &str) -> Option<Value> {
+        generativity::make_guard!(guard);
+        let comp = self.0.unerase(guard);
+        comp.description().declared_property_value(comp.borrow(), prop_name)
+    }
+
     fn invoke_callback(self: Pin<&Self>, callback_name: &str, args: &[Value]) -> Result<Value, ()> {
         generativity::make_guard!(guard);
         let comp = self.0.unerase(guard);
@@ -176,6 +188,17 @@ fn set_callback_handler(
         let comp = self.0.unerase(guard);
         comp.description().set_callback_handler(comp.borrow(), callback_name, handler)
     }
+
+    fn eval_function(self: Pin<&Self>, function_name: &str, args: Vec<Value>) -> Result<Value, ()> {
+        generativity::make_guard!(guard);
+        let comp = self.0.unerase(guard);
+        let root_element = comp.description().original.root_element.clone();
+        let root_element = root_element.borrow();
+        let code = root_element.functions.get(function_name).ok_or(())?.code.borrow();
+        let mut ctx =
+            crate::eval::EvalLocalContext::from_function_arguments(comp.borrow_instance(), args);
+        Ok(crate::eval::eval_expression(&code, &mut ctx))
+    }
 }
 
 impl<T: rtti::BuiltinItem + 'static> GlobalComponent for T {
@@ -203,6 +226,10 @@ fn get_property_ptr(self: Pin<&Self>, prop_name: &str) -> *const () {
         unsafe { (self.get_ref() as *const Self as *const u8).add(prop.offset()) as *const () }
     }
 
+    fn declared_property_value(self: Pin<&Self>, _prop_name: &str) -> Option<Value> {
+        None
+    }
+
     fn invoke_callback(self: Pin<&Self>, callback_name: &str, args: &[Value]) -> Result<Value, ()> {
         let cb = Self::callbacks().into_iter().find(|(k, _)| *k == callback_name).ok_or(())?.1;
         cb.call(self, args)
@@ -216,6 +243,14 @@ fn set_callback_handler(
         let cb = Self::callbacks().into_iter().find(|(k, _)| *k == callback_name).ok_or(())?.1;
         cb.set_handler(self, handler)
     }
+
+    fn eval_function(
+        self: Pin<&Self>,
+        _function_name: &str,
+        _args: Vec<Value>,
+    ) -> Result<Value, ()> {
+        Err(())
+    }
 }
 
 pub(crate) fn generate(component: &Rc<Component>) -> CompiledGlobal {
@@ -92,6 +92,13 @@ pub(crate) fn register_font_from_path<P: AsRef<std::path::Path>>(
 #[doc(inline)]
 pub use sixtyfps_corelib::{Brush, Color, SharedString, SharedVector};
 
+/// (Re-export from corelib.)
+///
+/// The model of a [`Value::Model`] can be wrapped by the adapters since [`ModelHandle`]
+/// implements [`Model`].
+#[doc(inline)]
+pub use sixtyfps_corelib::model::{FilterModel, MapModel, Model, ModelHandle, SortModel, VecModel};
+
 /// One need to use at least one function in each module in order to get them
 /// exported in the final binary.
 /// This only use functions from modules which are not otherwise used.
@@ -16,7 +16,9 @@
 use sixtyfps_corelib as corelib;
 
 use corelib::graphics::Point;
-use corelib::input::{KeyEvent, KeyEventType, KeyboardModifiers, MouseEvent};
+use corelib::input::{
+    KeyEvent, KeyEventType, KeyboardModifiers, MouseEvent, TouchEvent, TouchPhase,
+};
 use corelib::SharedString;
 use corelib::{window::*, Color};
 use std::cell::{Cell, RefCell};
@@ -107,6 +109,7 @@ fn apply_window_properties(
         let background = window_item.background();
         let title = window_item.title();
         let no_frame = window_item.no_frame();
+        let transient = window_item.transient();
         let icon = window_item.icon();
         let width = window_item.width();
         let height = window_item.height();
@@ -122,6 +125,8 @@ fn apply_window_properties(
             } else {
                 winit_window.set_decorations(true);
             }
+            // winit has no portable API for transient windows, keep them above the other windows
+            winit_window.set_always_on_top(transient);
             size =
                 winit_window.inner_size().to_logical(self.runtime_window().scale_factor() as f64);
         });
@@ -378,7 +383,11 @@ fn key_event(
             runtime_window.set_window_item_geometry(size.width, size.height);
         }
         WindowEvent::CloseRequested => {
-            window.hide();
+            // Hide through the runtime window, so that a top-level window hides its child windows
+            // and a child window is released. The close-requested callback may keep it shown.
+            if !runtime_window.request_close() {
+                return;
+            }
             match quit_behavior {
                 corelib::backend::EventLoopQuitBehavior::QuitOnLastWindowClosed => {
                     let window_count = ALL_WINDOWS.with(|windows| windows.borrow().len());
@@ -446,6 +455,7 @@ fn key_event(
             let modifiers =
                 KeyboardModifiers { shift: state.shift(), alt: state.alt(), control, meta };
             window.current_keyboard_modifiers().set(modifiers);
+            runtime_window.set_current_keyboard_modifiers(modifiers);
         }
         WindowEvent::CursorMoved { position, .. } => {
             corelib::animations::update_animations();
@@ -496,21 +506,18 @@ fn key_event(
             runtime_window.process_mouse_input(ev);
         }
         WindowEvent::Touch(touch) => {
-            corelib::animations::update_animations();
             let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-            let pos = euclid::point2(location.x, location.y);
-            let ev = match touch.phase {
-                winit::event::TouchPhase::Started => {
-                    *pressed = true;
-                    MouseEvent::MousePressed { pos, button: PointerEventButton::left }
-                }
-                winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                    *pressed = false;
-                    MouseEvent::MouseReleased { pos, button: PointerEventButton::left }
-                }
-                winit::event::TouchPhase::Moved => MouseEvent::MouseMoved { pos },
+            let phase = match touch.phase {
+                winit::event::TouchPhase::Started => TouchPhase::Started,
+                winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
             };
-            runtime_window.process_mouse_input(ev);
+            runtime_window.process_touch_input(TouchEvent {
+                id: touch.id,
+                phase,
+                pos: euclid::point2(location.x, location.y),
+            });
         }
         WindowEvent::ScaleFactorChanged { scale_factor, new_inner_size: size } => {
             if std::env::var("SIXTYFPS_SCALE_FACTOR").is_err() {
@@ -523,28 +530,108 @@ fn key_event(
     }
 }
 
-/// Runs the event loop and renders the items in the provided `component` in its
-/// own window.
-#[allow(unused_mut)] // mut need changes for wasm
-pub fn run(quit_behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior) {
-    use winit::event::Event;
-    use winit::event_loop::{ControlFlow, EventLoopWindowTarget};
+/// Dispatches one event received from winit to the windows, then fires the expired timers
+/// and schedules the next wake up in `control_flow`.
+fn handle_event(
+    event: winit::event::Event<CustomEvent>,
+    event_loop_target: &winit::event_loop::EventLoopWindowTarget<CustomEvent>,
+    event_loop_proxy: &winit::event_loop::EventLoopProxy<CustomEvent>,
+    quit_behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior,
+    control_flow: &mut winit::event_loop::ControlFlow,
+    cursor_pos: &mut Point,
+    pressed: &mut bool,
+) {
+    use winit::event_loop::ControlFlow;
+
+    let running_instance = RunningEventLoop { event_loop_target, event_loop_proxy };
+    CURRENT_WINDOW_TARGET.set(&running_instance, || {
+        *control_flow = ControlFlow::Wait;
+
+        match event {
+            winit::event::Event::WindowEvent { event, window_id } => {
+                if let Some(window) = window_by_id(window_id) {
+                    process_window_event(
+                        window,
+                        event,
+                        quit_behavior,
+                        control_flow,
+                        cursor_pos,
+                        pressed,
+                    );
+                };
+            }
 
+            winit::event::Event::RedrawRequested(id) => {
+                corelib::animations::update_animations();
+                if let Some(window) = window_by_id(id) {
+                    window.draw();
+                }
+            }
+
+            winit::event::Event::UserEvent(CustomEvent::UpdateWindowProperties(window_id)) => {
+                if let Some(window) = window_by_id(window_id) {
+                    window.runtime_window().update_window_properties();
+                }
+            }
+
+            winit::event::Event::UserEvent(CustomEvent::Exit) => {
+                *control_flow = winit::event_loop::ControlFlow::Exit;
+            }
+
+            winit::event::Event::UserEvent(CustomEvent::UserEvent(user)) => {
+                user();
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            winit::event::Event::UserEvent(CustomEvent::RedrawAllWindows) => redraw_all_windows(),
+            _ => (),
+        }
+
+        if *control_flow != winit::event_loop::ControlFlow::Exit
+            && corelib::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.has_active_animations())
+        {
+            *control_flow = ControlFlow::Poll;
+            redraw_all_windows()
+        }
+
+        corelib::timers::TimerList::maybe_activate_timers();
+
+        if *control_flow == winit::event_loop::ControlFlow::Wait {
+            if let Some(next_timer) = corelib::timers::TimerList::next_timeout() {
+                *control_flow = winit::event_loop::ControlFlow::WaitUntil(next_timer.into());
+            }
+        }
+    })
+}
+
+/// Takes the event loop instance out of `MAYBE_LOOP_INSTANCE`, and forwards the events
+/// posted so far to its proxy.
+fn take_loop_instance() -> NotRunningEventLoop {
     let not_running_loop_instance = MAYBE_LOOP_INSTANCE.with(|loop_instance| {
         loop_instance.borrow_mut().take().unwrap_or_else(NotRunningEventLoop::new)
     });
 
-    let event_loop_proxy = not_running_loop_instance.event_loop_proxy;
+    let event_loop_proxy = not_running_loop_instance.event_loop_proxy.clone();
     #[cfg(not(target_arch = "wasm32"))]
-    GLOBAL_PROXY.get_or_init(Default::default).lock().unwrap().set_proxy(event_loop_proxy.clone());
+    GLOBAL_PROXY.get_or_init(Default::default).lock().unwrap().set_proxy(event_loop_proxy);
     #[cfg(target_arch = "wasm32")]
     GLOBAL_PROXY.with(|global_proxy| {
-        global_proxy
-            .borrow_mut()
-            .get_or_insert_with(Default::default)
-            .set_proxy(event_loop_proxy.clone())
+        global_proxy.borrow_mut().get_or_insert_with(Default::default).set_proxy(event_loop_proxy)
     });
 
+    not_running_loop_instance
+}
+
+/// Runs the event loop and renders the items in the provided `component` in its
+/// own window.
+#[allow(unused_mut)] // mut need changes for wasm
+pub fn run(quit_behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior) {
+    use winit::event::Event;
+    use winit::event_loop::{ControlFlow, EventLoopWindowTarget};
+
+    let not_running_loop_instance = take_loop_instance();
+    let event_loop_proxy = not_running_loop_instance.event_loop_proxy;
     let mut winit_loop = not_running_loop_instance.instance;
 
     // last seen cursor position, (physical coordinate)
@@ -553,69 +640,15 @@ pub fn run(quit_behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior) {
     let mut run_fn = move |event: Event<CustomEvent>,
                            event_loop_target: &EventLoopWindowTarget<CustomEvent>,
                            control_flow: &mut ControlFlow| {
-        let running_instance =
-            RunningEventLoop { event_loop_target, event_loop_proxy: &event_loop_proxy };
-        CURRENT_WINDOW_TARGET.set(&running_instance, || {
-            *control_flow = ControlFlow::Wait;
-
-            match event {
-                winit::event::Event::WindowEvent { event, window_id } => {
-                    if let Some(window) = window_by_id(window_id) {
-                        process_window_event(
-                            window,
-                            event,
-                            quit_behavior,
-                            control_flow,
-                            &mut cursor_pos,
-                            &mut pressed,
-                        );
-                    };
-                }
-
-                winit::event::Event::RedrawRequested(id) => {
-                    corelib::animations::update_animations();
-                    if let Some(window) = window_by_id(id) {
-                        window.draw();
-                    }
-                }
-
-                winit::event::Event::UserEvent(CustomEvent::UpdateWindowProperties(window_id)) => {
-                    if let Some(window) = window_by_id(window_id) {
-                        window.runtime_window().update_window_properties();
-                    }
-                }
-
-                winit::event::Event::UserEvent(CustomEvent::Exit) => {
-                    *control_flow = winit::event_loop::ControlFlow::Exit;
-                }
-
-                winit::event::Event::UserEvent(CustomEvent::UserEvent(user)) => {
-                    user();
-                }
-
-                #[cfg(target_arch = "wasm32")]
-                winit::event::Event::UserEvent(CustomEvent::RedrawAllWindows) => {
-                    redraw_all_windows()
-                }
-                _ => (),
-            }
-
-            if *control_flow != winit::event_loop::ControlFlow::Exit
-                && corelib::animations::CURRENT_ANIMATION_DRIVER
-                    .with(|driver| driver.has_active_animations())
-            {
-                *control_flow = ControlFlow::Poll;
-                redraw_all_windows()
-            }
-
-            corelib::timers::TimerList::maybe_activate_timers();
-
-            if *control_flow == winit::event_loop::ControlFlow::Wait {
-                if let Some(next_timer) = corelib::timers::TimerList::next_timeout() {
-                    *control_flow = winit::event_loop::ControlFlow::WaitUntil(next_timer.into());
-                }
-            }
-        })
+        handle_event(
+            event,
+            event_loop_target,
+            &event_loop_proxy,
+            quit_behavior,
+            control_flow,
+            &mut cursor_pos,
+            &mut pressed,
+        )
     };
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -631,6 +664,72 @@ pub fn run(quit_behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior) {
     }
 }
 
+thread_local! {
+    /// The last seen cursor position (physical coordinate) and pressed state, kept between the
+    /// calls to `process_events`.
+    static PUMPED_CURSOR_STATE: Cell<(Point, bool)> = Cell::new((Point::default(), false));
+}
+
+/// Processes the pending events and returns, instead of running the event loop until it quits.
+/// Waits at most `timeout` for an event if there is none. The event loop instance is kept
+/// alive between calls, so that windows stay visible and posted events are not lost.
+///
+/// Returns false if the event loop was asked to quit.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn process_events(
+    timeout: std::time::Duration,
+    quit_behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior,
+) -> bool {
+    use winit::event::Event;
+    use winit::event_loop::ControlFlow;
+
+    let mut not_running_loop_instance = take_loop_instance();
+    let event_loop_proxy = not_running_loop_instance.event_loop_proxy.clone();
+
+    let deadline = std::time::Instant::now() + timeout;
+    let (mut cursor_pos, mut pressed) = PUMPED_CURSOR_STATE.with(Cell::get);
+    let mut processed_events = false;
+    let mut quit = false;
+
+    not_running_loop_instance.instance.run_return(|event, event_loop_target, control_flow| {
+        let done = match &event {
+            Event::NewEvents(_) | Event::MainEventsCleared | Event::LoopDestroyed => false,
+            Event::RedrawEventsCleared => processed_events || std::time::Instant::now() >= deadline,
+            _ => {
+                processed_events = true;
+                false
+            }
+        };
+
+        handle_event(
+            event,
+            event_loop_target,
+            &event_loop_proxy,
+            quit_behavior,
+            control_flow,
+            &mut cursor_pos,
+            &mut pressed,
+        );
+
+        if *control_flow == ControlFlow::Exit {
+            quit = true;
+        } else if done {
+            *control_flow = ControlFlow::Exit;
+        } else if *control_flow == ControlFlow::Wait {
+            *control_flow = ControlFlow::WaitUntil(deadline);
+        } else if let ControlFlow::WaitUntil(next_timer) = *control_flow {
+            *control_flow = ControlFlow::WaitUntil(next_timer.min(deadline));
+        }
+    });
+
+    PUMPED_CURSOR_STATE.with(|state| state.set((cursor_pos, pressed)));
+    MAYBE_LOOP_INSTANCE.with(|loop_instance| {
+        *loop_instance.borrow_mut() = Some(not_running_loop_instance);
+    });
+
+    !quit
+}
+
 // This function is called when we receive a control character via WindowEvent::ReceivedCharacter and
 // instead want to use the last virtual key code. That happens when for example pressing Ctrl+some_key
 // on Windows/X11/Wayland. This function may be missing mappings, it's trying to cover what we may be
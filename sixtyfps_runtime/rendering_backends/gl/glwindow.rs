@@ -17,10 +17,10 @@
 use corelib::input::KeyboardModifiers;
 use corelib::items::{ItemRef, MouseCursor};
 use corelib::layout::Orientation;
-use corelib::window::{PlatformWindow, PopupWindow, PopupWindowLocation};
+use corelib::window::{PlatformWindow, PopupWindow, PopupWindowLocation, WindowState};
 use corelib::Property;
 use sixtyfps_corelib as corelib;
-use winit::dpi::LogicalSize;
+use winit::dpi::{LogicalPosition, LogicalSize};
 
 use crate::CanvasRc;
 
@@ -38,6 +38,11 @@ pub struct GLWindow {
 
     fps_counter: Option<Rc<FPSCounter>>,
 
+    /// The position and size requested while the window is not mapped, applied when it is shown.
+    requested_position: Cell<Option<Point>>,
+    requested_size: Cell<Option<Size>>,
+    window_state: Cell<WindowState>,
+
     #[cfg(target_arch = "wasm32")]
     canvas_id: String,
 }
@@ -61,6 +66,9 @@ pub(crate) fn new(
             graphics_cache: Default::default(),
             texture_cache: Default::default(),
             fps_counter: FPSCounter::new(),
+            requested_position: Default::default(),
+            requested_size: Default::default(),
+            window_state: Default::default(),
             #[cfg(target_arch = "wasm32")]
             canvas_id,
         })
@@ -343,15 +351,25 @@ fn show(self: Rc<Self>) {
             .and_then(|x| x.parse::<f64>().ok())
             .filter(|f| *f > 0.);
 
-        let window_builder = if std::env::var("SIXTYFPS_FULLSCREEN").is_ok() {
+        if std::env::var("SIXTYFPS_FULLSCREEN").is_ok() {
+            self.window_state.set(WindowState::Fullscreen);
+        }
+
+        let window_builder = if self.window_state.get() == WindowState::Fullscreen {
             window_builder.with_fullscreen(Some(winit::window::Fullscreen::Borderless(None)))
         } else {
-            let layout_info_h = component.as_ref().layout_info(Orientation::Horizontal);
-            let layout_info_v = component.as_ref().layout_info(Orientation::Vertical);
-            let s = LogicalSize::new(
-                layout_info_h.preferred_bounded(),
-                layout_info_v.preferred_bounded(),
-            );
+            let window_builder =
+                window_builder.with_maximized(self.window_state.get() == WindowState::Maximized);
+            let s = if let Some(size) = self.requested_size.take() {
+                LogicalSize::new(size.width, size.height)
+            } else {
+                let layout_info_h = component.as_ref().layout_info(Orientation::Horizontal);
+                let layout_info_v = component.as_ref().layout_info(Orientation::Vertical);
+                LogicalSize::new(
+                    layout_info_h.preferred_bounded(),
+                    layout_info_v.preferred_bounded(),
+                )
+            };
 
             if s.width > 0. && s.height > 0. {
                 // Make sure that the window's inner size is in sync with the root window item's
@@ -394,6 +412,13 @@ fn show(self: Rc<Self>) {
         );
         let id = platform_window.id();
 
+        if let Some(position) = self.requested_position.take() {
+            platform_window.set_outer_position(LogicalPosition::new(position.x, position.y));
+        }
+        if self.window_state.get() == WindowState::Minimized {
+            platform_window.set_minimized(true);
+        }
+
         if let Some(fps_counter) = &self.fps_counter {
             cfg_if::cfg_if! {
                 if #[cfg(target_arch = "wasm32")] {
@@ -619,6 +644,72 @@ fn text_input_position_for_byte_offset(
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn position(&self) -> Point {
+        let scale_factor = self.self_weak.upgrade().unwrap().scale_factor() as f64;
+        match self.borrow_mapped_window() {
+            Some(window) => window
+                .opengl_context
+                .window()
+                .outer_position()
+                .map(|position| {
+                    let position: LogicalPosition<f32> = position.to_logical(scale_factor);
+                    Point::new(position.x, position.y)
+                })
+                .unwrap_or_default(),
+            None => self.requested_position.get().unwrap_or_default(),
+        }
+    }
+
+    fn set_position(&self, position: Point) {
+        match self.borrow_mapped_window() {
+            Some(window) => window
+                .opengl_context
+                .window()
+                .set_outer_position(LogicalPosition::new(position.x, position.y)),
+            None => self.requested_position.set(Some(position)),
+        }
+    }
+
+    fn set_size(&self, size: Size) {
+        match self.borrow_mapped_window() {
+            Some(window) => window
+                .opengl_context
+                .window()
+                .set_inner_size(LogicalSize::new(size.width, size.height)),
+            None => self.requested_size.set(Some(size)),
+        }
+    }
+
+    fn window_state(&self) -> WindowState {
+        let mut state = self.window_state.get();
+        // The user can also change the state through the window decorations
+        self.with_window_handle(&mut |winit_window| {
+            state = if winit_window.fullscreen().is_some() {
+                WindowState::Fullscreen
+            } else if winit_window.is_maximized() {
+                WindowState::Maximized
+            } else if state == WindowState::Minimized {
+                // winit cannot tell whether the window is minimized
+                WindowState::Minimized
+            } else {
+                WindowState::Normal
+            };
+        });
+        state
+    }
+
+    fn set_window_state(&self, state: WindowState) {
+        self.window_state.set(state);
+        self.with_window_handle(&mut |winit_window| {
+            winit_window.set_fullscreen(
+                (state == WindowState::Fullscreen)
+                    .then(|| winit::window::Fullscreen::Borderless(None)),
+            );
+            winit_window.set_maximized(state == WindowState::Maximized);
+            winit_window.set_minimized(state == WindowState::Minimized);
+        });
+    }
 }
 
 struct MappedWindow {
@@ -1046,20 +1046,22 @@ fn brush_to_paint(&self, brush: Brush, path: &mut femtovg::Path) -> Option<femto
         if brush.is_transparent() {
             return None;
         }
+        // `canvas.path_bbox()` applies the current transform. However we're not interested in that, since
+        // we operate in item local coordinates with the `path` parameter as well as the resulting
+        // paint.
+        let mut path_bounds = || {
+            let mut canvas = self.canvas.borrow_mut();
+            canvas.save();
+            canvas.reset_transform();
+            let bounding_box = canvas.path_bbox(path);
+            canvas.restore();
+            bounding_box
+        };
+
         Some(match brush {
             Brush::SolidColor(color) => femtovg::Paint::color(to_femtovg_color(&color)),
             Brush::LinearGradient(gradient) => {
-                // `canvas.path_bbox()` applies the current transform. However we're not interested in that, since
-                // we operate in item local coordinates with the `path` parameter as well as the resulting
-                // paint.
-                let path_bounds = {
-                    let mut canvas = self.canvas.borrow_mut();
-                    canvas.save();
-                    canvas.reset_transform();
-                    let bounding_box = canvas.path_bbox(path);
-                    canvas.restore();
-                    bounding_box
-                };
+                let path_bounds = path_bounds();
 
                 let path_width = path_bounds.maxx - path_bounds.minx;
                 let path_height = path_bounds.maxy - path_bounds.miny;
@@ -1078,6 +1080,27 @@ fn brush_to_paint(&self, brush: Brush, path: &mut femtovg::Path) -> Option<femto
                     .collect::<Vec<_>>();
                 femtovg::Paint::linear_gradient_stops(start.x, start.y, end.x, end.y, &stops)
             }
+            Brush::RadialGradient(gradient) => {
+                let path_bounds = path_bounds();
+
+                let path_width = path_bounds.maxx - path_bounds.minx;
+                let path_height = path_bounds.maxy - path_bounds.miny;
+
+                // Like the CSS default, the circle extends to the farthest corner
+                let radius = (path_width * path_width + path_height * path_height).sqrt() / 2.;
+
+                let stops = gradient
+                    .stops()
+                    .map(|stop| (stop.position, to_femtovg_color(&stop.color)))
+                    .collect::<Vec<_>>();
+                femtovg::Paint::radial_gradient_stops(
+                    path_bounds.minx + path_width / 2.,
+                    path_bounds.miny + path_height / 2.,
+                    0.,
+                    radius,
+                    &stops,
+                )
+            }
             _ => return None,
         })
     }
@@ -1206,6 +1229,15 @@ fn quit_event_loop(&'static self) {
         })
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn process_events(
+        &'static self,
+        timeout: core::time::Duration,
+        behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior,
+    ) -> bool {
+        crate::event_loop::process_events(timeout, behavior)
+    }
+
     fn register_font_from_memory(
         &'static self,
         data: &'static [u8],
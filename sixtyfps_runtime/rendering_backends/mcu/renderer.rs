@@ -9,10 +9,12 @@
 use embedded_graphics::pixelcolor::Rgb888;
 use embedded_graphics::prelude::*;
 use sixtyfps_corelib::graphics::{
-    IntRect, PixelFormat, Point as PointF, Rect as RectF, Size as SizeF,
+    GradientStop, IntRect, PixelFormat, Point as PointF, RadialGradientBrush, Rect as RectF,
+    Size as SizeF,
 };
 use sixtyfps_corelib::items::Item;
-use sixtyfps_corelib::{Color, ImageInner};
+use sixtyfps_corelib::properties::InterpolatedPropertyValue;
+use sixtyfps_corelib::{Brush, Color, ImageInner};
 
 use crate::Devices;
 
@@ -147,6 +149,25 @@ pub fn render_window_frame(
                         }
                     }
                 }
+                SceneCommand::RadialGradient { gradient_index } => {
+                    let gradient = &scene.radial_gradients[gradient_index as usize];
+                    let dy = line.line as f32 + 0.5 - gradient.center.y;
+                    for (x, pix) in line_buffer[(span.x) as usize..(span.x + span.width) as usize]
+                        .iter_mut()
+                        .enumerate()
+                    {
+                        let dx = (span.x as usize + x) as f32 + 0.5 - gradient.center.x;
+                        let distance = euclid::vec2::<f32, euclid::UnknownUnit>(dx, dy).length();
+                        let color = gradient_color_at(&gradient.stops, distance / gradient.radius);
+                        let a = (u8::MAX - color.alpha()) as u16;
+                        let b = color.alpha() as u16;
+                        *pix = Rgb888::new(
+                            ((pix.r() as u16 * a + color.red() as u16 * b) >> 8) as u8,
+                            ((pix.g() as u16 * a + color.green() as u16 * b) >> 8) as u8,
+                            ((pix.b() as u16 * a + color.blue() as u16 * b) >> 8) as u8,
+                        );
+                    }
+                }
             }
         }
         devices.fill_region(euclid::rect(0, line.line as i32, size.width as i32, 1), &line_buffer)
@@ -167,12 +188,16 @@ struct Scene {
 
     /// Some staging buffer of scene item
     next_items: VecDeque<SceneItem>,
+
+    /// The gradients referenced by the SceneCommand::RadialGradient
+    radial_gradients: Vec<RadialGradientCommand>,
 }
 
 impl Scene {
-    fn new(mut items: Vec<SceneItem>) -> Self {
+    fn new(mut items: Vec<SceneItem>, radial_gradients: Vec<RadialGradientCommand>) -> Self {
         items.sort_by(|a, b| compare_scene_item(a, b).reverse());
         Self {
+            radial_gradients,
             future_items: items,
             current_line: 0,
             current_items: Default::default(),
@@ -269,6 +294,35 @@ enum SceneCommand {
         source_height: u16,
         color: Color,
     },
+    RadialGradient {
+        /// index in the Scene::radial_gradients
+        gradient_index: u16,
+    },
+}
+
+struct RadialGradientCommand {
+    /// The center of the circle, in screen coordinates
+    center: PointF,
+    radius: f32,
+    stops: Vec<GradientStop>,
+}
+
+/// Returns the color at the normalized position `t` of a gradient
+fn gradient_color_at(stops: &[GradientStop], t: f32) -> Color {
+    let mut previous: Option<&GradientStop> = None;
+    for stop in stops {
+        if t <= stop.position {
+            return match previous {
+                Some(previous) if stop.position > previous.position => previous.color.interpolate(
+                    &stop.color,
+                    (t - previous.position) / (stop.position - previous.position),
+                ),
+                _ => stop.color,
+            };
+        }
+        previous = Some(stop);
+    }
+    previous.map_or(Color::default(), |stop| stop.color)
 }
 
 fn prepare_scene(runtime_window: Rc<sixtyfps_corelib::window::Window>, size: SizeF) -> Scene {
@@ -282,11 +336,12 @@ fn prepare_scene(runtime_window: Rc<sixtyfps_corelib::window::Window>, size: Siz
             );
         }
     });
-    Scene::new(prepare_scene.items)
+    Scene::new(prepare_scene.items, prepare_scene.radial_gradients)
 }
 
 struct PrepareScene {
     items: Vec<SceneItem>,
+    radial_gradients: Vec<RadialGradientCommand>,
     state_stack: Vec<RenderState>,
     current_state: RenderState,
     scale_factor: ScaleFactor,
@@ -296,6 +351,7 @@ impl PrepareScene {
     fn new(size: SizeF, scale_factor: ScaleFactor) -> Self {
         Self {
             items: vec![],
+            radial_gradients: vec![],
             state_stack: vec![],
             current_state: RenderState {
                 alpha: 1.,
@@ -324,6 +380,27 @@ fn new_scene_item(&mut self, geometry: RectF, command: SceneCommand) {
         });
     }
 
+    /// Adds an item filling `geometry` with the gradient, whose circle is centered in `full_geometry`
+    fn new_radial_gradient_item(
+        &mut self,
+        geometry: RectF,
+        full_geometry: RectF,
+        gradient: &RadialGradientBrush,
+    ) {
+        let scale_factor = self.scale_factor.0;
+        let center =
+            (self.current_state.offset + full_geometry.center().to_vector()) * scale_factor;
+        // Like the CSS default, the circle extends to the farthest corner
+        let radius = full_geometry.size.to_vector().length() / 2. * scale_factor;
+        let gradient_index = self.radial_gradients.len() as u16;
+        self.radial_gradients.push(RadialGradientCommand {
+            center,
+            radius,
+            stops: gradient.stops().cloned().collect(),
+        });
+        self.new_scene_item(geometry, SceneCommand::RadialGradient { gradient_index });
+    }
+
     fn draw_image_impl(
         &mut self,
         geom: RectF,
@@ -381,17 +458,22 @@ impl sixtyfps_corelib::item_rendering::ItemRenderer for PrepareScene {
     fn draw_rectangle(&mut self, rect: Pin<&sixtyfps_corelib::items::Rectangle>) {
         let geom = RectF::new(PointF::default(), rect.geometry().size);
         if self.should_draw(&geom) {
-            let geom = match geom.intersection(&self.current_state.clip) {
+            let clipped_geom = match geom.intersection(&self.current_state.clip) {
                 Some(geom) => geom,
                 None => return,
             };
 
-            // FIXME: gradients
-            let color = rect.background().color();
+            let background = rect.background();
+            if let Brush::RadialGradient(gradient) = &background {
+                self.new_radial_gradient_item(clipped_geom, geom, gradient);
+                return;
+            }
+            // FIXME: linear gradients
+            let color = background.color();
             if color.alpha() == 0 {
                 return;
             }
-            self.new_scene_item(geom, SceneCommand::Rectangle { color });
+            self.new_scene_item(clipped_geom, SceneCommand::Rectangle { color });
         }
     }
 
@@ -399,9 +481,17 @@ fn draw_border_rectangle(&mut self, rect: Pin<&sixtyfps_corelib::items::BorderRe
         let geom = RectF::new(PointF::default(), rect.geometry().size);
         if self.should_draw(&geom) {
             let border = rect.border_width();
-            // FIXME: gradients
-            let color = rect.background().color();
-            if color.alpha() > 0 {
+            let background = rect.background();
+            if let Brush::RadialGradient(gradient) = &background {
+                if let Some(r) =
+                    geom.inflate(-border, -border).intersection(&self.current_state.clip)
+                {
+                    self.new_radial_gradient_item(r, geom, gradient);
+                }
+            }
+            // FIXME: linear gradients
+            let color = background.color();
+            if color.alpha() > 0 && !matches!(background, Brush::RadialGradient(_)) {
                 if let Some(r) =
                     geom.inflate(-border, -border).intersection(&self.current_state.clip)
                 {
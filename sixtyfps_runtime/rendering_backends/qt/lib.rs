@@ -155,6 +155,52 @@ fn quit_event_loop(&'static self) {
         };
     }
 
+    fn process_events(
+        &'static self,
+        _timeout: core::time::Duration,
+        _behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior,
+    ) -> bool {
+        #[cfg(not(no_qt))]
+        {
+            let quit_on_last_window_closed = match _behavior {
+                sixtyfps_corelib::backend::EventLoopQuitBehavior::QuitOnLastWindowClosed => true,
+                sixtyfps_corelib::backend::EventLoopQuitBehavior::QuitOnlyExplicitly => false,
+            };
+            let timeout_ms = _timeout.as_millis().min(i32::MAX as u128) as i32;
+            // Schedule any timers with Qt that were set up since the last call.
+            crate::qt_window::timer_event();
+            use cpp::cpp;
+            cpp! {unsafe [quit_on_last_window_closed as "bool", timeout_ms as "int"] -> bool as "bool" {
+                ensure_initialized(true);
+                auto &quit_filter = QuitEventFilter::instance();
+                auto visible_windows = [] {
+                    const auto windows = QGuiApplication::topLevelWindows();
+                    return std::count_if(windows.begin(), windows.end(),
+                                         [](QWindow *window) { return window->isVisible(); });
+                };
+                bool had_visible_windows = visible_windows() > 0;
+
+                QEventLoop::ProcessEventsFlags flags = QEventLoop::AllEvents;
+                // The timer wakes up the dispatcher if no event arrives before the timeout.
+                QTimer wake_up;
+                if (timeout_ms > 0) {
+                    flags |= QEventLoop::WaitForMoreEvents;
+                    wake_up.start(timeout_ms);
+                }
+                QCoreApplication::processEvents(flags);
+                QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+
+                bool quit = std::exchange(quit_filter.quit_requested, false);
+                if (quit_on_last_window_closed && had_visible_windows && visible_windows() == 0) {
+                    quit = true;
+                }
+                return !quit;
+            } }
+        }
+        #[cfg(no_qt)]
+        true
+    }
+
     fn register_font_from_memory(
         &'static self,
         _data: &'static [u8],
@@ -22,7 +22,8 @@
 use cpp::cpp;
 use sixtyfps_corelib::graphics::{Color, Rect};
 use sixtyfps_corelib::input::{
-    FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, MouseEvent,
+    key_codes, FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult,
+    KeyEventType, MouseEvent,
 };
 use sixtyfps_corelib::item_rendering::{CachedRenderingData, ItemRenderer};
 use sixtyfps_corelib::items::{Item, ItemConsts, ItemRc, ItemVTable, VoidArg};
@@ -52,6 +53,20 @@ macro_rules! get_size {
     }};
 }
 
+/// Returns the key of a key press event, or None for a key release.
+fn pressed_key(event: &KeyEvent) -> Option<char> {
+    if event.event_type == KeyEventType::KeyPressed {
+        event.text.chars().next()
+    } else {
+        None
+    }
+}
+
+/// Returns whether a native widget has the keyboard focus after receiving the given event.
+fn has_focus_after(event: &FocusEvent) -> bool {
+    matches!(event, FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus)
+}
+
 macro_rules! fn_render {
     ($this:ident $dpr:ident $size:ident $painter:ident $widget:ident $initial_state:ident => $($tt:tt)*) => {
         fn render(self: Pin<&Self>, backend: &mut &mut dyn ItemRenderer) {
@@ -106,6 +106,8 @@ pub struct NativeButton {
     pub text: Property<SharedString>,
     pub icon: Property<sixtyfps_corelib::graphics::Image>,
     pub enabled: Property<bool>,
+    pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
     pub pressed: Property<bool>,
     pub clicked: Callback<VoidArg>,
     pub standard_button_kind: Property<StandardButtonKind>,
@@ -225,13 +227,16 @@ fn input_event_filter_before_children(
     fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
-        _window: &WindowRc,
-        _self_rc: &sixtyfps_corelib::items::ItemRc,
+        window: &WindowRc,
+        self_rc: &sixtyfps_corelib::items::ItemRc,
     ) -> InputEventResult {
         let enabled = self.enabled();
         if !enabled {
             return InputEventResult::EventIgnored;
         }
+        if matches!(event, MouseEvent::MousePressed { .. }) && !self.has_focus() {
+            window.clone().set_focus_item(self_rc);
+        }
 
         Self::FIELD_OFFSETS.pressed.apply_pin(self).set(match event {
             MouseEvent::MousePressed { .. } => true,
@@ -255,11 +260,27 @@ fn input_event(
         }
     }
 
-    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+    fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        match pressed_key(event) {
+            Some(' ') | Some(key_codes::Return) if self.enabled() => {
+                Self::FIELD_OFFSETS.clicked.apply_pin(self).call(&());
+                KeyEventResult::EventAccepted
+            }
+            _ => KeyEventResult::EventIgnored,
+        }
+    }
+
+    fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &WindowRc) {
+        Self::FIELD_OFFSETS.has_focus.apply_pin(self).set(has_focus_after(event));
     }
 
-    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
 
     fn_render! { this dpr size painter widget initial_state =>
         let down: bool = this.pressed();
@@ -267,6 +288,7 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
         let text: qttypes::QString = this.actual_text(standard_button_kind);
         let icon: qttypes::QPixmap = this.actual_icon(standard_button_kind);
         let enabled = this.enabled();
+        let has_focus = this.has_focus();
 
         cpp!(unsafe [
             painter as "QPainter*",
@@ -274,6 +296,7 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             text as "QString",
             icon as "QPixmap",
             enabled as "bool",
+            has_focus as "bool",
             size as "QSize",
             down as "bool",
             dpr as "float",
@@ -295,6 +318,8 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             } else {
                 option.palette.setCurrentColorGroup(QPalette::Disabled);
             }
+            if (has_focus)
+                option.state |= QStyle::State_HasFocus;
             qApp->style()->drawControl(QStyle::CE_PushButton, &option, painter, widget);
         });
     }
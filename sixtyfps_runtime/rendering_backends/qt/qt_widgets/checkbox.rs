@@ -12,6 +12,8 @@ pub struct NativeCheckBox {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub enabled: Property<bool>,
+    pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
     pub toggled: Callback<VoidArg>,
     pub text: Property<SharedString>,
     pub checked: Property<bool>,
@@ -60,12 +62,15 @@ fn input_event_filter_before_children(
     fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
-        _window: &WindowRc,
-        _self_rc: &sixtyfps_corelib::items::ItemRc,
+        window: &WindowRc,
+        self_rc: &sixtyfps_corelib::items::ItemRc,
     ) -> InputEventResult {
-        if !self.enabled() {
+        if !self.enabled() || matches!(event, MouseEvent::MouseWheel { .. }) {
             return InputEventResult::EventIgnored;
         }
+        if matches!(event, MouseEvent::MousePressed { .. }) && !self.has_focus() {
+            window.clone().set_focus_item(self_rc);
+        }
         if let MouseEvent::MouseReleased { pos, .. } = event {
             if euclid::rect(0., 0., self.width(), self.height()).contains(pos) {
                 Self::FIELD_OFFSETS.checked.apply_pin(self).set(!self.checked());
@@ -75,21 +80,40 @@ fn input_event(
         InputEventResult::EventAccepted
     }
 
-    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+    fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        match pressed_key(event) {
+            Some(' ') if self.enabled() => {
+                Self::FIELD_OFFSETS.checked.apply_pin(self).set(!self.checked());
+                Self::FIELD_OFFSETS.toggled.apply_pin(self).call(&());
+                KeyEventResult::EventAccepted
+            }
+            _ => KeyEventResult::EventIgnored,
+        }
     }
 
-    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+    fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &WindowRc) {
+        Self::FIELD_OFFSETS.has_focus.apply_pin(self).set(has_focus_after(event));
+    }
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
 
     fn_render! { this dpr size painter widget initial_state =>
         let checked: bool = this.checked();
         let enabled = this.enabled();
+        let has_focus = this.has_focus();
         let text: qttypes::QString = this.text().as_str().into();
 
         cpp!(unsafe [
             painter as "QPainter*",
             widget as "QWidget*",
             enabled as "bool",
+            has_focus as "bool",
             text as "QString",
             size as "QSize",
             checked as "bool",
@@ -106,6 +130,8 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             } else {
                 option.palette.setCurrentColorGroup(QPalette::Disabled);
             }
+            if (has_focus)
+                option.state |= QStyle::State_HasFocus;
             qApp->style()->drawControl(QStyle::CE_CheckBox, &option, painter, widget);
         });
     }
@@ -12,6 +12,8 @@ pub struct NativeComboBox {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub enabled: Property<bool>,
+    pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
     pub pressed: Property<bool>,
     pub is_open: Property<bool>,
     pub current_value: Property<SharedString>,
@@ -56,13 +58,16 @@ fn input_event_filter_before_children(
     fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
-        _window: &WindowRc,
-        _self_rc: &sixtyfps_corelib::items::ItemRc,
+        window: &WindowRc,
+        self_rc: &sixtyfps_corelib::items::ItemRc,
     ) -> InputEventResult {
         let enabled = self.enabled();
         if !enabled {
             return InputEventResult::EventIgnored;
         }
+        if matches!(event, MouseEvent::MousePressed { .. }) && !self.has_focus() {
+            window.clone().set_focus_item(self_rc);
+        }
         // FIXME: this is the input event of a button, but we need to do the proper hit test
 
         Self::FIELD_OFFSETS.pressed.apply_pin(self).set(match event {
@@ -86,11 +91,28 @@ fn input_event(
         }
     }
 
-    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+    fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        match pressed_key(event) {
+            Some(' ') | Some(key_codes::DownArrow) if self.enabled() => {
+                Self::FIELD_OFFSETS.is_open.apply_pin(self).set(true);
+                Self::FIELD_OFFSETS.open_popup.apply_pin(self).call(&());
+                KeyEventResult::EventAccepted
+            }
+            _ => KeyEventResult::EventIgnored,
+        }
     }
 
-    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+    fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &WindowRc) {
+        Self::FIELD_OFFSETS.has_focus.apply_pin(self).set(has_focus_after(event));
+    }
+
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
 
     fn_render! { this dpr size painter widget initial_state =>
         let down: bool = this.pressed();
@@ -98,11 +120,13 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
         let text: qttypes::QString =
             this.current_value().as_str().into();
         let enabled = this.enabled();
+        let has_focus = this.has_focus();
         cpp!(unsafe [
             painter as "QPainter*",
             widget as "QWidget*",
             text as "QString",
             enabled as "bool",
+            has_focus as "bool",
             size as "QSize",
             down as "bool",
             is_open as "bool",
@@ -123,6 +147,8 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             } else {
                 option.palette.setCurrentColorGroup(QPalette::Disabled);
             }
+            if (has_focus)
+                option.state |= QStyle::State_HasFocus;
             if (is_open) {
             // FIXME: This is commented out to workaround #456
             //    option.state |= QStyle::State_On;
@@ -189,6 +215,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn_render! { _this dpr size painter widget initial_state =>
         cpp!(unsafe [
             painter as "QPainter*",
@@ -104,6 +104,10 @@ fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResu
 
     fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
 
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        -1
+    }
+
     fn_render! { this dpr size painter widget initial_state =>
         let has_focus: bool = this.has_focus();
         let enabled: bool = this.enabled();
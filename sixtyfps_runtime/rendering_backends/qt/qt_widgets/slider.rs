@@ -27,6 +27,8 @@ pub struct NativeSlider {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub enabled: Property<bool>,
+    pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
     pub value: Property<f32>,
     pub minimum: Property<f32>,
     pub maximum: Property<f32>,
@@ -111,11 +113,14 @@ fn input_event_filter_before_children(
     fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
-        _window: &WindowRc,
-        _self_rc: &sixtyfps_corelib::items::ItemRc,
+        window: &WindowRc,
+        self_rc: &sixtyfps_corelib::items::ItemRc,
     ) -> InputEventResult {
         let size: qttypes::QSize = get_size!(self);
         let enabled = self.enabled();
+        if enabled && matches!(event, MouseEvent::MousePressed { .. }) && !self.has_focus() {
+            window.clone().set_focus_item(self_rc);
+        }
         let value = self.value() as f32;
         let min = self.minimum() as f32;
         let max = self.maximum() as f32;
@@ -189,14 +194,36 @@ fn input_event(
         result
     }
 
-    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+    fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        if !self.enabled() {
+            return KeyEventResult::EventIgnored;
+        }
+        let delta = match pressed_key(event) {
+            Some(key_codes::RightArrow) | Some(key_codes::UpArrow) => 1.,
+            Some(key_codes::LeftArrow) | Some(key_codes::DownArrow) => -1.,
+            _ => return KeyEventResult::EventIgnored,
+        };
+        let new_val = (self.value() + delta).max(self.minimum()).min(self.maximum());
+        self.value.set(new_val);
+        Self::FIELD_OFFSETS.changed.apply_pin(self).call(&(new_val,));
+        KeyEventResult::EventAccepted
+    }
+
+    fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &WindowRc) {
+        Self::FIELD_OFFSETS.has_focus.apply_pin(self).set(has_focus_after(event));
     }
 
-    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
 
     fn_render! { this dpr size painter widget initial_state =>
         let enabled = this.enabled();
+        let has_focus = this.has_focus();
         let value = this.value() as i32;
         let min = this.minimum() as i32;
         let max = this.maximum() as i32;
@@ -208,6 +235,7 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             painter as "QPainter*",
             widget as "QWidget*",
             enabled as "bool",
+            has_focus as "bool",
             value as "int",
             min as "int",
             max as "int",
@@ -221,6 +249,8 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             option.state |= QStyle::State(initial_state);
             option.rect = QRect(QPoint(), size / dpr);
             initQSliderOptions(option, pressed, enabled, active_controls, min, max, value);
+            if (has_focus)
+                option.state |= QStyle::State_HasFocus;
             auto style = qApp->style();
             style->drawComplexControl(QStyle::CC_Slider, &option, painter, widget);
         });
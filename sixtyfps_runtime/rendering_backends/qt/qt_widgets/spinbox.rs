@@ -19,6 +19,8 @@ pub struct NativeSpinBox {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub enabled: Property<bool>,
+    pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
     pub value: Property<i32>,
     pub minimum: Property<i32>,
     pub maximum: Property<i32>,
@@ -110,11 +112,14 @@ fn input_event_filter_before_children(
     fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
-        _window: &WindowRc,
-        _self_rc: &sixtyfps_corelib::items::ItemRc,
+        window: &WindowRc,
+        self_rc: &sixtyfps_corelib::items::ItemRc,
     ) -> InputEventResult {
         let size: qttypes::QSize = get_size!(self);
         let enabled = self.enabled();
+        if enabled && matches!(event, MouseEvent::MousePressed { .. }) && !self.has_focus() {
+            window.clone().set_focus_item(self_rc);
+        }
         let mut data = self.data();
         let active_controls = data.active_controls;
         let pressed = data.pressed;
@@ -176,18 +181,51 @@ fn input_event(
         if changed {
             self.data.set(data);
         }
+        if matches!(event, MouseEvent::MouseWheel { .. }) {
+            // Let the parent Flickable scroll
+            return InputEventResult::EventIgnored;
+        }
         InputEventResult::EventAccepted
     }
 
-    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+    fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &WindowRc) -> KeyEventResult {
+        if !self.enabled() {
+            return KeyEventResult::EventIgnored;
+        }
+        let v = self.value();
+        match pressed_key(event) {
+            Some(key_codes::UpArrow) => {
+                if v < self.maximum() {
+                    self.value.set(v + 1);
+                }
+                KeyEventResult::EventAccepted
+            }
+            Some(key_codes::DownArrow) => {
+                if v > self.minimum() {
+                    self.value.set(v - 1);
+                }
+                KeyEventResult::EventAccepted
+            }
+            _ => KeyEventResult::EventIgnored,
+        }
+    }
+
+    fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &WindowRc) {
+        Self::FIELD_OFFSETS.has_focus.apply_pin(self).set(has_focus_after(event));
     }
 
-    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
+    fn tab_focus_index(self: Pin<&Self>) -> i32 {
+        if self.enabled() {
+            self.tab_index()
+        } else {
+            -1
+        }
+    }
 
     fn_render! { this dpr size painter widget initial_state =>
         let value: i32 = this.value();
         let enabled = this.enabled();
+        let has_focus = this.has_focus();
         let data = this.data();
         let active_controls = data.active_controls;
         let pressed = data.pressed;
@@ -197,6 +235,7 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             widget as "QWidget*",
             value as "int",
             enabled as "bool",
+            has_focus as "bool",
             size as "QSize",
             active_controls as "int",
             pressed as "bool",
@@ -208,6 +247,8 @@ fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &WindowRc) {}
             option.state |= QStyle::State(initial_state);
             option.rect = QRect(QPoint(), size / dpr);
             initQSpinBoxOptions(option, pressed, enabled, active_controls);
+            if (has_focus)
+                option.state |= QStyle::State_HasFocus;
             style->drawComplexControl(QStyle::CC_SpinBox, &option, painter, widget);
 
             QStyleOptionFrame frame;
@@ -18,7 +18,9 @@
     TextWrap,
 };
 use sixtyfps_corelib::layout::Orientation;
-use sixtyfps_corelib::window::{PlatformWindow, PopupWindow, PopupWindowLocation, WindowRc};
+use sixtyfps_corelib::window::{
+    PlatformWindow, PopupWindow, PopupWindowLocation, WindowRc, WindowState,
+};
 use sixtyfps_corelib::{component::ComponentRc, SharedString};
 use sixtyfps_corelib::{ImageInner, PathData, Property};
 
@@ -69,6 +71,27 @@ struct TimerHandler : QObject {
 
     };
 
+    // Records the quit events sent to the application while the events are processed
+    // without QApplication::exec(), as they have no event loop to stop.
+    struct QuitEventFilter : QObject {
+        bool quit_requested = false;
+        static QuitEventFilter& instance() {
+            static QuitEventFilter *instance = [] {
+                auto filter = new QuitEventFilter;
+                qApp->installEventFilter(filter);
+                return filter;
+            }();
+            return *instance;
+        }
+
+        bool eventFilter(QObject *watched, QEvent *event) override {
+            if (watched == qApp && event->type() == QEvent::Quit) {
+                quit_requested = true;
+            }
+            return false;
+        }
+    };
+
     struct SixtyFPSWidget : QWidget {
         void *rust_window;
 
@@ -176,6 +199,15 @@ struct SixtyFPSWidget : QWidget {
             QWidget::changeEvent(event);
         }
 
+        void closeEvent(QCloseEvent *event) override {
+            bool close = rust!(SFPS_closeEvent [rust_window: &QtWindow as "void*"] -> bool as "bool" {
+                rust_window.self_weak.upgrade().map_or(true, |window| window.request_close())
+            });
+            if (!close) {
+                event->ignore();
+            }
+        }
+
         QSize sizeHint() const override {
             auto preferred_size = rust!(SFPS_sizeHint [rust_window: &QtWindow as "void*"] -> qttypes::QSize as "QSize" {
                 let component_rc = rust_window.self_weak.upgrade().unwrap().component();
@@ -311,6 +343,28 @@ fn into_qbrush(brush: sixtyfps_corelib::Brush) -> qttypes::QBrush {
                 return QBrush(qlg);
             }}
         }
+        sixtyfps_corelib::Brush::RadialGradient(g) => {
+            cpp_class!(unsafe struct QRadialGradient as "QRadialGradient");
+            let mut qrg = cpp! {
+                unsafe [] -> QRadialGradient as "QRadialGradient" {
+                    // The radius reaches the corners of the bounding rect. Since the object mode
+                    // stretches the gradient, it is only a circle for square shapes.
+                    QRadialGradient qrg(QPointF(0.5, 0.5), 0.70710678);
+                    qrg.setCoordinateMode(QGradient::ObjectMode);
+                    return qrg;
+                }
+            };
+            for s in g.stops() {
+                let pos: f32 = s.position;
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qrg as "QRadialGradient", pos as "float", color as "QRgb"] {
+                    qrg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qrg as "QRadialGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qrg);
+            }}
+        }
         _ => qttypes::QBrush::default(),
     }
 }
@@ -1241,6 +1295,61 @@ fn hide(self: Rc<Self>) {
         }};
     }
 
+    fn position(&self) -> Point {
+        let widget_ptr = self.widget_ptr();
+        let pos = cpp! {unsafe [widget_ptr as "QWidget*"] -> qttypes::QPoint as "QPoint" {
+            return widget_ptr->pos();
+        }};
+        Point::new(pos.x as _, pos.y as _)
+    }
+
+    fn set_position(&self, position: Point) {
+        let widget_ptr = self.widget_ptr();
+        let pos = qttypes::QPoint { x: position.x as _, y: position.y as _ };
+        cpp! {unsafe [widget_ptr as "QWidget*", pos as "QPoint"] {
+            widget_ptr->move(pos);
+        }};
+    }
+
+    fn window_state(&self) -> WindowState {
+        let widget_ptr = self.widget_ptr();
+        let state = cpp! {unsafe [widget_ptr as "QWidget*"] -> i32 as "int" {
+            auto state = widget_ptr->windowState();
+            if (state & Qt::WindowFullScreen)
+                return 3;
+            if (state & Qt::WindowMaximized)
+                return 2;
+            if (state & Qt::WindowMinimized)
+                return 1;
+            return 0;
+        }};
+        match state {
+            1 => WindowState::Minimized,
+            2 => WindowState::Maximized,
+            3 => WindowState::Fullscreen,
+            _ => WindowState::Normal,
+        }
+    }
+
+    fn set_window_state(&self, state: WindowState) {
+        let widget_ptr = self.widget_ptr();
+        let (minimized, maximized, fullscreen) = (
+            state == WindowState::Minimized,
+            state == WindowState::Maximized,
+            state == WindowState::Fullscreen,
+        );
+        cpp! {unsafe [widget_ptr as "QWidget*", minimized as "bool", maximized as "bool", fullscreen as "bool"] {
+            Qt::WindowStates state = Qt::WindowNoState;
+            if (fullscreen)
+                state = Qt::WindowFullScreen;
+            else if (maximized)
+                state = Qt::WindowMaximized;
+            else if (minimized)
+                state = Qt::WindowMinimized;
+            widget_ptr->setWindowState(state);
+        }};
+    }
+
     fn request_redraw(&self) {
         let widget_ptr = self.widget_ptr();
         cpp! {unsafe [widget_ptr as "QWidget*"] {
@@ -0,0 +1,429 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+use sixtyfps_corelib::graphics::{FontRequest, Point, Size};
+use sixtyfps_corelib::items::{
+    TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap,
+};
+use sixtyfps_corelib::SharedString;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub const DEFAULT_FONT_SIZE: f32 = 12.;
+pub const DEFAULT_FONT_WEIGHT: i32 = 400; // CSS normal
+
+/// The family of the font that is bundled with the backend. It is used when no family is requested,
+/// so that the rendering doesn't depend on the fonts installed on the system, and for the glyphs
+/// that are missing in the requested font.
+const BUNDLED_FAMILY: &str = "DejaVu Sans";
+
+/// This function can be used to register a custom TrueType font with SixtyFPS,
+/// for use with the `font-family` property. The provided slice must be a valid TrueType
+/// font.
+pub fn register_font_from_memory(data: &'static [u8]) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.available_fonts.load_font_source(fontdb::Source::Binary(std::sync::Arc::new(data)));
+        cache.loaded_faces.clear();
+    });
+    Ok(())
+}
+
+pub fn register_font_from_path(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    FONT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.loaded_faces.clear();
+        cache.available_fonts.load_font_file(path).map_err(|e| e.into())
+    })
+}
+
+/// A font face loaded from the font database, with the outlines of the glyphs that were
+/// drawn so far.
+pub struct FontFace {
+    data: Vec<u8>,
+    index: u32,
+    /// The outlines in font units, indexed by glyph id. None for glyphs without outline, like spaces.
+    glyph_paths: RefCell<HashMap<u16, Option<tiny_skia::Path>>>,
+}
+
+impl FontFace {
+    fn new(data: Vec<u8>, index: u32) -> Option<Self> {
+        ttf_parser::Face::from_slice(&data, index).ok()?;
+        Some(Self { data, index, glyph_paths: Default::default() })
+    }
+
+    fn face(&self) -> ttf_parser::Face<'_> {
+        // The data was validated in new()
+        ttf_parser::Face::from_slice(&self.data, self.index).unwrap()
+    }
+
+    fn glyph_path(&self, glyph: ttf_parser::GlyphId) -> Option<tiny_skia::Path> {
+        self.glyph_paths
+            .borrow_mut()
+            .entry(glyph.0)
+            .or_insert_with(|| {
+                let mut builder = GlyphPathBuilder(tiny_skia::PathBuilder::new());
+                self.face().outline_glyph(glyph, &mut builder)?;
+                builder.0.finish()
+            })
+            .clone()
+    }
+}
+
+struct GlyphPathBuilder(tiny_skia::PathBuilder);
+
+impl ttf_parser::OutlineBuilder for GlyphPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.move_to(x, y);
+    }
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.line_to(x, y);
+    }
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.quad_to(x1, y1, x, y);
+    }
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.cubic_to(x1, y1, x2, y2, x, y);
+    }
+    fn close(&mut self) {
+        self.0.close();
+    }
+}
+
+/// A glyph of a line of text, positioned relative to the start of the line.
+pub struct PositionedGlyph {
+    /// The byte index of the character in the line
+    pub byte_index: usize,
+    pub x: f32,
+    pub advance: f32,
+    glyph_id: Option<ttf_parser::GlyphId>,
+    from_fallback: bool,
+}
+
+/// A font face at a given size, in physical pixels.
+#[derive(Clone)]
+pub struct Font {
+    face: Rc<FontFace>,
+    fallback: Option<Rc<FontFace>>,
+    pixel_size: f32,
+    letter_spacing: f32,
+}
+
+impl Font {
+    fn scale(face: &ttf_parser::Face) -> f32 {
+        1. / face.units_per_em() as f32
+    }
+
+    /// The distance between the top of a line and its baseline
+    pub fn ascent(&self) -> f32 {
+        let face = self.face.face();
+        face.ascender() as f32 * Self::scale(&face) * self.pixel_size
+    }
+
+    pub fn height(&self) -> f32 {
+        let face = self.face.face();
+        (face.ascender() - face.descender()) as f32 * Self::scale(&face) * self.pixel_size
+    }
+
+    /// Maps the characters of the line to glyphs, using the fallback font for the characters
+    /// that the font doesn't have.
+    pub fn shape(&self, line: &str) -> Vec<PositionedGlyph> {
+        let face = self.face.face();
+        let fallback_face = self.fallback.as_ref().map(|fallback| fallback.face());
+        let mut x = 0.;
+        line.char_indices()
+            .map(|(byte_index, ch)| {
+                let (glyph_id, from_fallback) = if ch.is_control() {
+                    (None, false)
+                } else if let Some(glyph_id) = face.glyph_index(ch) {
+                    (Some(glyph_id), false)
+                } else if let Some(glyph_id) =
+                    fallback_face.as_ref().and_then(|fallback| fallback.glyph_index(ch))
+                {
+                    (Some(glyph_id), true)
+                } else {
+                    (Some(ttf_parser::GlyphId(0)), false)
+                };
+                let advance = glyph_id.map_or(0., |glyph_id| {
+                    let face = if from_fallback { fallback_face.as_ref().unwrap() } else { &face };
+                    face.glyph_hor_advance(glyph_id).unwrap_or_default() as f32
+                        * Self::scale(face)
+                        * self.pixel_size
+                        + self.letter_spacing
+                });
+                let glyph = PositionedGlyph { byte_index, x, advance, glyph_id, from_fallback };
+                x += advance;
+                glyph
+            })
+            .collect()
+    }
+
+    pub fn text_width(&self, line: &str) -> f32 {
+        self.shape(line).last().map_or(0., |last| last.x + last.advance)
+    }
+
+    /// Returns the x position of the character at the given byte offset of the line, or the
+    /// end of the line if the offset is past the last character.
+    pub fn x_for_byte_offset(&self, line: &str, byte_offset: usize) -> f32 {
+        let glyphs = self.shape(line);
+        glyphs
+            .iter()
+            .find(|glyph| glyph.byte_index >= byte_offset)
+            .map(|glyph| glyph.x)
+            .unwrap_or_else(|| glyphs.last().map_or(0., |last| last.x + last.advance))
+    }
+
+    /// Returns the length in bytes of the first line of the text when it is wrapped at max_width.
+    /// The line ends after a new line character, or at the last white space that fits. A line
+    /// contains at least one character.
+    pub fn break_text(&self, max_width: f32, text: &str) -> usize {
+        let mut last_break = None;
+        for glyph in self.shape(text) {
+            let ch = text[glyph.byte_index..].chars().next().unwrap();
+            let end = glyph.byte_index + ch.len_utf8();
+            if ch == '\n' {
+                return end;
+            }
+            if glyph.x + glyph.advance > max_width && !ch.is_whitespace() {
+                return last_break.unwrap_or_else(|| {
+                    if glyph.byte_index == 0 {
+                        end
+                    } else {
+                        glyph.byte_index
+                    }
+                });
+            }
+            if ch.is_whitespace() {
+                last_break = Some(end);
+            }
+        }
+        text.len()
+    }
+
+    pub fn text_size(&self, text: &str, max_width: Option<f32>) -> Size {
+        let mut lines = 0;
+        let mut width: f32 = 0.;
+        if let Some(max_width) = max_width {
+            let mut start = 0;
+            while start < text.len() {
+                let index = start + self.break_text(max_width, &text[start..]);
+                width = width.max(self.text_width(text[start..index].trim_end()));
+                lines += 1;
+                start = index;
+            }
+        } else {
+            for line in text.lines() {
+                width = width.max(self.text_width(line));
+                lines += 1;
+            }
+        }
+        Size::new(width, lines as f32 * self.height())
+    }
+
+    /// Fills the glyphs of the line, with `pos` being the top left corner of the line.
+    pub fn draw_line(
+        &self,
+        pixmap: &mut tiny_skia::PixmapMut,
+        line: &str,
+        pos: Point,
+        paint: &tiny_skia::Paint,
+        transform: tiny_skia::Transform,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        let ascent = self.ascent();
+        for glyph in self.shape(line) {
+            let glyph_id = match glyph.glyph_id {
+                Some(glyph_id) => glyph_id,
+                None => continue,
+            };
+            let face =
+                if glyph.from_fallback { self.fallback.as_ref().unwrap() } else { &self.face };
+            let path = match face.glyph_path(glyph_id) {
+                Some(path) => path,
+                None => continue,
+            };
+            let scale = Self::scale(&face.face()) * self.pixel_size;
+            // Glyph outlines are in font units, with the y axis pointing up
+            let glyph_transform =
+                transform.pre_translate(pos.x + glyph.x, pos.y + ascent).pre_scale(scale, -scale);
+            pixmap.fill_path(
+                &path,
+                paint,
+                tiny_skia::FillRule::Winding,
+                glyph_transform,
+                clip_mask,
+            );
+        }
+    }
+}
+
+pub(crate) fn text_size(
+    font_request: &FontRequest,
+    scale_factor: f32,
+    text: &str,
+    max_width: Option<f32>,
+) -> Size {
+    let font = FONT_CACHE.with(|cache| cache.borrow_mut().font(font_request.clone(), scale_factor));
+    font.text_size(text, max_width.map(|x| x * scale_factor)) / scale_factor
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct FontCacheKey {
+    family: Option<SharedString>,
+    weight: i32,
+}
+
+pub struct FontCache {
+    loaded_faces: HashMap<FontCacheKey, Rc<FontFace>>,
+    pub(crate) available_fonts: fontdb::Database,
+}
+
+impl Default for FontCache {
+    fn default() -> Self {
+        let mut font_db = fontdb::Database::new();
+        font_db.load_system_fonts();
+        // Same bundled font as the GL backend, so that both render text the same way
+        font_db.load_font_data(include_bytes!("../gl/fonts/DejaVuSans.ttf").to_vec());
+        font_db.set_sans_serif_family(BUNDLED_FAMILY);
+        Self { loaded_faces: HashMap::new(), available_fonts: font_db }
+    }
+}
+
+thread_local! {
+    pub static FONT_CACHE: RefCell<FontCache> = RefCell::new(Default::default())
+}
+
+impl FontCache {
+    fn load_face(&mut self, family: Option<SharedString>, weight: i32) -> Rc<FontFace> {
+        let cache_key = FontCacheKey { family, weight };
+        if let Some(face) = self.loaded_faces.get(&cache_key) {
+            return face.clone();
+        }
+
+        let available_fonts = &self.available_fonts;
+        let load = |family: fontdb::Family| {
+            let query = fontdb::Query {
+                families: &[family],
+                weight: fontdb::Weight(weight as u16),
+                ..Default::default()
+            };
+            let id = available_fonts.query(&query)?;
+            available_fonts.with_face_data(id, |data, index| FontFace::new(data.to_vec(), index))?
+        };
+
+        let face = cache_key
+            .family
+            .as_ref()
+            .and_then(|family| load(fontdb::Family::Name(family.as_str())))
+            // If the requested family could not be found, fall back to the bundled font
+            .or_else(|| load(fontdb::Family::SansSerif))
+            .expect("the bundled font must be available");
+
+        let face = Rc::new(face);
+        self.loaded_faces.insert(cache_key, face.clone());
+        face
+    }
+
+    pub fn font(&mut self, request: FontRequest, scale_factor: f32) -> Font {
+        let weight = request.weight.unwrap_or(DEFAULT_FONT_WEIGHT);
+        let face = self.load_face(request.family, weight);
+        let bundled_face = self.load_face(None, weight);
+        Font {
+            fallback: (!Rc::ptr_eq(&face, &bundled_face)).then(|| bundled_face),
+            face,
+            pixel_size: request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor,
+            letter_spacing: request.letter_spacing.unwrap_or_default() * scale_factor,
+        }
+    }
+}
+
+/// Layout the given string in lines, and call the `layout_line` callback with the line to draw at position y.
+/// The signature of the `layout_line` function is: `(text, pos, start_index)`.
+/// start index is the starting byte of the text in the string.
+/// Returns the baseline y coordinate.
+pub(crate) fn layout_text_lines(
+    string: &str,
+    font: &Font,
+    Size { width: max_width, height: max_height, .. }: Size,
+    (horizontal_alignment, vertical_alignment): (TextHorizontalAlignment, TextVerticalAlignment),
+    wrap: TextWrap,
+    overflow: TextOverflow,
+    single_line: bool,
+    mut layout_line: impl FnMut(&str, Point, usize),
+) -> f32 {
+    let wrap = wrap == TextWrap::word_wrap;
+    let elide = overflow == TextOverflow::elide;
+    let font_height = font.height();
+
+    let text_height = || {
+        if single_line {
+            font_height
+        } else {
+            font.text_size(string, if wrap { Some(max_width) } else { None }).height
+        }
+    };
+
+    let mut process_line = |text: &str, y: f32, start: usize| {
+        let line_width = font.text_width(text.trim_end());
+        let x = match horizontal_alignment {
+            TextHorizontalAlignment::left => 0.,
+            TextHorizontalAlignment::center => {
+                max_width / 2. - f32::min(max_width, line_width) / 2.
+            }
+            TextHorizontalAlignment::right => max_width - f32::min(max_width, line_width),
+        };
+        layout_line(text, Point::new(x, y), start);
+    };
+
+    let baseline_y = match vertical_alignment {
+        TextVerticalAlignment::top => 0.,
+        TextVerticalAlignment::center => max_height / 2. - text_height() / 2.,
+        TextVerticalAlignment::bottom => max_height - text_height(),
+    };
+    let mut y = baseline_y;
+    let mut start = 0;
+    'lines: while start < string.len() && y + font_height <= max_height {
+        if wrap && (!elide || y + 2. * font_height <= max_height) {
+            let index = start + font.break_text(max_width, &string[start..]);
+            process_line(&string[start..index], y, start);
+            y += font_height;
+            start = index;
+        } else {
+            let index = if single_line {
+                string.len()
+            } else {
+                string[start..].find('\n').map_or(string.len(), |i| start + i + 1)
+            };
+            let line = &string[start..index];
+            let elide_last_line =
+                elide && index < string.len() && y + 2. * font_height > max_height;
+            if font.text_width(line.trim_end()) > max_width || elide_last_line {
+                let w = max_width - if elide { font.text_width("…") } else { 0. };
+                for glyph in font.shape(line) {
+                    if glyph.x + glyph.advance >= w {
+                        let txt = &line[..glyph.byte_index];
+                        if elide {
+                            process_line(&format!("{}…", txt), y, start);
+                        } else {
+                            process_line(txt, y, start);
+                        }
+                        y += font_height;
+                        start = index;
+                        continue 'lines;
+                    }
+                }
+                if elide_last_line {
+                    process_line(&format!("{}…", line.trim_end()), y, start);
+                    y += font_height;
+                    start = index;
+                    continue 'lines;
+                }
+            }
+            process_line(line, y, start);
+            y += font_height;
+            start = index;
+        }
+    }
+    baseline_y
+}
@@ -0,0 +1,151 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+use sixtyfps_corelib::graphics::{ImageInner, SharedImageBuffer, Size};
+use sixtyfps_corelib::SharedString;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The images decoded from files or from embedded data, so that they are decoded only once.
+/// Scalable images are cached once for each size they are rendered at.
+#[derive(PartialEq, Eq, Hash)]
+enum ImageCacheKey {
+    Path(SharedString, Option<[u32; 2]>),
+    EmbeddedData(usize, Option<[u32; 2]>),
+}
+
+thread_local!(static IMAGE_CACHE: RefCell<HashMap<ImageCacheKey, Option<Rc<tiny_skia::Pixmap>>>> = Default::default());
+
+/// Returns the pixels of the image, premultiplied. `target_size` is the size in physical pixels
+/// at which scalable images are rendered.
+pub fn load_image(image: &ImageInner, target_size: [u32; 2]) -> Option<Rc<tiny_skia::Pixmap>> {
+    let svg_size = image.is_svg().then(|| target_size);
+    let cache_key = match image {
+        ImageInner::None | ImageInner::StaticTextures { .. } => return None,
+        ImageInner::EmbeddedImage(buffer) => return pixmap_from_buffer(buffer).map(Rc::new),
+        ImageInner::AbsoluteFilePath(path) => ImageCacheKey::Path(path.clone(), svg_size),
+        ImageInner::EmbeddedData { data, .. } => {
+            ImageCacheKey::EmbeddedData(data.as_ptr() as usize, svg_size)
+        }
+    };
+    IMAGE_CACHE.with(|cache| {
+        cache.borrow_mut().entry(cache_key).or_insert_with(|| decode_image(image, svg_size)).clone()
+    })
+}
+
+/// Returns the size of the image in pixels, as it would be decoded by load_image.
+pub fn image_size(image: &ImageInner) -> Size {
+    match image {
+        ImageInner::StaticTextures { size, .. } => size.cast(),
+        ImageInner::EmbeddedImage(buffer) => Size::new(buffer.width() as _, buffer.height() as _),
+        #[cfg(feature = "svg")]
+        _ if image.is_svg() => load_svg(image).map_or_else(Default::default, |tree| {
+            let size = tree.svg_node().size.to_screen_size();
+            Size::new(size.width() as _, size.height() as _)
+        }),
+        _ => load_image(image, [0, 0]).map_or_else(Default::default, |pixmap| {
+            Size::new(pixmap.width() as _, pixmap.height() as _)
+        }),
+    }
+}
+
+fn decode_image(image: &ImageInner, svg_size: Option<[u32; 2]>) -> Option<Rc<tiny_skia::Pixmap>> {
+    #[cfg(feature = "svg")]
+    if let Some(size) = svg_size {
+        return render_svg(&load_svg(image)?, size).map(Rc::new);
+    }
+    #[cfg(not(feature = "svg"))]
+    if svg_size.is_some() {
+        eprintln!("SVG images are not supported without the svg feature of the software backend");
+        return None;
+    }
+
+    let decoded = match image {
+        ImageInner::AbsoluteFilePath(path) => image::open(std::path::Path::new(path.as_str())),
+        ImageInner::EmbeddedData { data, format } => {
+            let format = std::str::from_utf8(format.as_slice())
+                .ok()
+                .and_then(image::ImageFormat::from_extension);
+            if let Some(format) = format {
+                image::load_from_memory_with_format(data.as_slice(), format)
+            } else {
+                image::load_from_memory(data.as_slice())
+            }
+        }
+        _ => return None,
+    };
+    let rgba = match decoded {
+        Ok(decoded) => decoded.into_rgba8(),
+        Err(decode_err) => {
+            eprintln!("Error decoding image: {}", decode_err);
+            return None;
+        }
+    };
+    let mut pixmap = tiny_skia::Pixmap::new(rgba.width(), rgba.height())?;
+    for (dst, src) in pixmap.pixels_mut().iter_mut().zip(rgba.pixels()) {
+        *dst = tiny_skia::ColorU8::from_rgba(src[0], src[1], src[2], src[3]).premultiply();
+    }
+    Some(Rc::new(pixmap))
+}
+
+fn pixmap_from_buffer(buffer: &SharedImageBuffer) -> Option<tiny_skia::Pixmap> {
+    let mut pixmap = tiny_skia::Pixmap::new(buffer.width() as u32, buffer.height() as u32)?;
+    let width = buffer.width();
+    let pixels = pixmap.pixels_mut();
+    let mut copy_rows =
+        |stride: usize, convert: &dyn Fn(usize) -> tiny_skia::PremultipliedColorU8| {
+            for (y, row) in pixels.chunks_mut(width).enumerate() {
+                for (x, pixel) in row.iter_mut().enumerate() {
+                    *pixel = convert(y * stride + x);
+                }
+            }
+        };
+    match buffer {
+        SharedImageBuffer::RGB8(buffer) => copy_rows(buffer.stride(), &|i| {
+            let p = buffer.as_slice()[i];
+            tiny_skia::ColorU8::from_rgba(p.r, p.g, p.b, 255).premultiply()
+        }),
+        SharedImageBuffer::RGBA8(buffer) => copy_rows(buffer.stride(), &|i| {
+            let p = buffer.as_slice()[i];
+            tiny_skia::ColorU8::from_rgba(p.r, p.g, p.b, p.a).premultiply()
+        }),
+        SharedImageBuffer::RGBA8Premultiplied(buffer) => copy_rows(buffer.stride(), &|i| {
+            let p = buffer.as_slice()[i];
+            tiny_skia::PremultipliedColorU8::from_rgba(p.r, p.g, p.b, p.a)
+                .unwrap_or(tiny_skia::PremultipliedColorU8::TRANSPARENT)
+        }),
+    }
+    Some(pixmap)
+}
+
+#[cfg(feature = "svg")]
+fn load_svg(image: &ImageInner) -> Option<usvg::Tree> {
+    let data = match image {
+        ImageInner::AbsoluteFilePath(path) => match std::fs::read(path.as_str()) {
+            Ok(data) => std::borrow::Cow::Owned(data),
+            Err(err) => {
+                eprintln!("Error loading SVG from {}: {}", &path, err);
+                return None;
+            }
+        },
+        ImageInner::EmbeddedData { data, .. } => std::borrow::Cow::Borrowed(data.as_slice()),
+        _ => return None,
+    };
+    crate::fonts::FONT_CACHE.with(|cache| {
+        let options = usvg::Options::default();
+        let mut options_ref = options.to_ref();
+        let cache = cache.borrow();
+        options_ref.fontdb = &cache.available_fonts;
+        usvg::Tree::from_data(&data, &options_ref)
+            .map_err(|svg_err| eprintln!("Error loading SVG: {}", svg_err))
+            .ok()
+    })
+}
+
+#[cfg(feature = "svg")]
+fn render_svg(tree: &usvg::Tree, [width, height]: [u32; 2]) -> Option<tiny_skia::Pixmap> {
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+    resvg::render(tree, usvg::FitTo::Size(width, height), Default::default(), pixmap.as_mut())?;
+    Some(pixmap)
+}
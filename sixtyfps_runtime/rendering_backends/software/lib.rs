@@ -0,0 +1,392 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+/*!
+
+*NOTE*: This library is an internal crate for the [SixtyFPS project](https://sixtyfps.io).
+This crate should not be used directly by application using SixtyFPS.
+You should use the `sixtyfps` crate instead.
+
+This backend rasterizes the items on the CPU with [tiny-skia](https://docs.rs/tiny-skia), without
+any windowing system. Windows are never shown on screen, instead their content can be rendered
+into a pixel buffer with [`render_to_buffer`], for example to take screenshots in tests or to
+render thumbnails on a server.
+
+*/
+#![doc(html_logo_url = "https://sixtyfps.io/resources/logo.drawio.svg")]
+
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex};
+
+use sixtyfps_corelib::component::ComponentRc;
+use sixtyfps_corelib::graphics::{
+    Image, Point, RenderingCache, Rgba8Pixel, SharedPixelBuffer, Size,
+};
+use sixtyfps_corelib::items::ItemRef;
+use sixtyfps_corelib::layout::Orientation;
+use sixtyfps_corelib::window::{
+    PlatformWindow, PopupWindow, PopupWindowLocation, Window, WindowHandleAccess,
+};
+
+mod fonts;
+mod images;
+mod renderer;
+
+type ItemGraphicsCache = RenderingCache<Option<Rc<tiny_skia::Pixmap>>>;
+
+type PostedEvent = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+pub struct SoftwareBackend {
+    clipboard: Mutex<Option<String>>,
+    /// The events queued with `post_event`, invoked by the thread processing the events.
+    posted_events: Mutex<Vec<PostedEvent>>,
+    /// Notified when an event is posted, to wake up `process_events` while it waits.
+    event_posted: Condvar,
+    quit_requested: AtomicBool,
+}
+
+impl SoftwareBackend {
+    /// Invokes the events posted so far, and the expired timers. Returns true if there was any.
+    fn process_pending_events(&self) -> bool {
+        let events = std::mem::take(&mut *self.posted_events.lock().unwrap());
+        let any_event = !events.is_empty();
+        for event in events {
+            event();
+        }
+        let any_timer = sixtyfps_corelib::timers::TimerList::maybe_activate_timers();
+        sixtyfps_corelib::animations::update_animations();
+        any_event || any_timer
+    }
+
+    /// Waits until an event is posted, or until `deadline` at the latest.
+    fn wait_for_event(&self, deadline: std::time::Instant) {
+        let posted_events = self.posted_events.lock().unwrap();
+        let timeout = deadline.saturating_duration_since(std::time::Instant::now());
+        if posted_events.is_empty() && !timeout.is_zero() {
+            drop(self.event_posted.wait_timeout(posted_events, timeout).unwrap());
+        }
+    }
+
+    /// Returns the time until the next timer expires, if any timer is running.
+    fn next_timer_delay() -> Option<core::time::Duration> {
+        sixtyfps_corelib::timers::TimerList::next_timeout().map(|timeout| {
+            let now = sixtyfps_corelib::animations::Instant::now();
+            if timeout > now {
+                timeout - now
+            } else {
+                core::time::Duration::ZERO
+            }
+        })
+    }
+}
+
+impl sixtyfps_corelib::backend::Backend for SoftwareBackend {
+    fn create_window(&'static self) -> Rc<Window> {
+        Window::new(|window| {
+            Rc::new(SoftwareWindow {
+                self_weak: window.clone(),
+                graphics_cache: Default::default(),
+            })
+        })
+    }
+
+    /// There are no windows on screen to wait for, so the event loop only runs until it is
+    /// idle: the posted events are invoked, including the ones posted meanwhile, and the single
+    /// shot timers are waited for. Repeated timers keep firing while it runs, but they are not
+    /// waited for since they never expire for good.
+    fn run_event_loop(&'static self, _behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior) {
+        while !self.quit_requested.swap(false, Ordering::Relaxed) {
+            if self.process_pending_events() {
+                continue;
+            }
+            let next_single_shot_timeout =
+                sixtyfps_corelib::timers::TimerList::next_single_shot_timeout();
+            if next_single_shot_timeout.is_none() {
+                break;
+            }
+            let delay = Self::next_timer_delay().unwrap_or_default();
+            self.wait_for_event(std::time::Instant::now() + delay);
+        }
+    }
+
+    fn quit_event_loop(&'static self) {
+        self.quit_requested.store(true, Ordering::Relaxed);
+        self.event_posted.notify_all();
+    }
+
+    fn process_events(
+        &'static self,
+        timeout: core::time::Duration,
+        _behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior,
+    ) -> bool {
+        let deadline = std::time::Instant::now() + timeout;
+        while !self.process_pending_events()
+            && !self.quit_requested.load(Ordering::Relaxed)
+            && std::time::Instant::now() < deadline
+        {
+            let deadline = match Self::next_timer_delay() {
+                Some(delay) => deadline.min(std::time::Instant::now() + delay),
+                None => deadline,
+            };
+            self.wait_for_event(deadline);
+        }
+        !self.quit_requested.swap(false, Ordering::Relaxed)
+    }
+
+    fn register_font_from_memory(
+        &'static self,
+        data: &'static [u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        fonts::register_font_from_memory(data)
+    }
+
+    fn register_font_from_path(
+        &'static self,
+        path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        fonts::register_font_from_path(path)
+    }
+
+    fn set_clipboard_text(&'static self, text: String) {
+        *self.clipboard.lock().unwrap() = Some(text);
+    }
+
+    fn clipboard_text(&'static self) -> Option<String> {
+        self.clipboard.lock().unwrap().clone()
+    }
+
+    fn post_event(&'static self, event: Box<dyn FnOnce() + Send>) {
+        self.posted_events.lock().unwrap().push(event);
+        self.event_posted.notify_all();
+    }
+
+    fn image_size(&'static self, image: &Image) -> Size {
+        images::image_size(image.into())
+    }
+}
+
+/// A window of the software backend. It is never mapped on screen, its content is only
+/// rendered on demand with [`render_to_buffer`].
+pub struct SoftwareWindow {
+    self_weak: Weak<Window>,
+    graphics_cache: RefCell<ItemGraphicsCache>,
+}
+
+impl SoftwareWindow {
+    fn runtime_window(&self) -> Rc<Window> {
+        self.self_weak.upgrade().unwrap()
+    }
+
+    /// Lays out the text of the text input in lines, and calls `line_callback` with the font,
+    /// each line, its position in physical pixels and its start byte offset.
+    fn layout_text_input_lines(
+        &self,
+        text_input: Pin<&sixtyfps_corelib::items::TextInput>,
+        mut line_callback: impl FnMut(&fonts::Font, &str, Point, usize),
+    ) {
+        let scale_factor = self.runtime_window().scale_factor();
+        let width = text_input.width() * scale_factor;
+        let height = text_input.height() * scale_factor;
+        if width <= 0. || height <= 0. {
+            return;
+        }
+
+        let font = fonts::FONT_CACHE.with(|cache| {
+            cache.borrow_mut().font(
+                text_input
+                    .unresolved_font_request()
+                    .merge(&self.runtime_window().default_font_properties()),
+                scale_factor,
+            )
+        });
+
+        let text = text_input.text();
+        fonts::layout_text_lines(
+            text.as_str(),
+            &font,
+            Size::new(width, height),
+            (text_input.horizontal_alignment(), text_input.vertical_alignment()),
+            text_input.wrap(),
+            sixtyfps_corelib::items::TextOverflow::clip,
+            text_input.single_line(),
+            |line_text, line_pos, start| line_callback(&font, line_text, line_pos, start),
+        );
+    }
+}
+
+impl PlatformWindow for SoftwareWindow {
+    fn show(self: Rc<Self>) {}
+
+    fn hide(self: Rc<Self>) {}
+
+    fn request_redraw(&self) {}
+
+    fn free_graphics_resources<'a>(
+        &self,
+        items: &mut dyn Iterator<Item = Pin<sixtyfps_corelib::items::ItemRef<'a>>>,
+    ) {
+        let mut cache = self.graphics_cache.borrow_mut();
+        for item in items {
+            item.cached_rendering_data_offset().release(&mut *cache);
+        }
+    }
+
+    fn show_popup(&self, popup: &ComponentRc, position: Point) {
+        let runtime_window = self.runtime_window();
+        let size = runtime_window.set_active_popup(PopupWindow {
+            location: PopupWindowLocation::ChildWindow(position),
+            component: popup.clone(),
+        });
+
+        let popup = ComponentRc::borrow_pin(popup);
+        let popup_root = popup.as_ref().get_item_ref(0);
+        if let Some(window_item) = ItemRef::downcast_pin(popup_root) {
+            let width_property =
+                sixtyfps_corelib::items::WindowItem::FIELD_OFFSETS.width.apply_pin(window_item);
+            let height_property =
+                sixtyfps_corelib::items::WindowItem::FIELD_OFFSETS.height.apply_pin(window_item);
+            width_property.set(size.width);
+            height_property.set(size.height);
+        }
+    }
+
+    fn request_window_properties_update(&self) {}
+
+    fn apply_window_properties(&self, _window_item: Pin<&sixtyfps_corelib::items::WindowItem>) {}
+
+    fn apply_geometry_constraint(
+        &self,
+        _constraints_horizontal: sixtyfps_corelib::layout::LayoutInfo,
+        _constraints_vertical: sixtyfps_corelib::layout::LayoutInfo,
+    ) {
+    }
+
+    fn set_mouse_cursor(&self, _cursor: sixtyfps_corelib::items::MouseCursor) {}
+
+    fn text_size(
+        &self,
+        font_request: sixtyfps_corelib::graphics::FontRequest,
+        text: &str,
+        max_width: Option<f32>,
+    ) -> Size {
+        let runtime_window = self.runtime_window();
+        let font_request = font_request.merge(&runtime_window.default_font_properties());
+        fonts::text_size(&font_request, runtime_window.scale_factor(), text, max_width)
+    }
+
+    fn text_input_byte_offset_for_position(
+        &self,
+        text_input: Pin<&sixtyfps_corelib::items::TextInput>,
+        pos: Point,
+    ) -> usize {
+        let pos = pos * self.runtime_window().scale_factor();
+        let text = text_input.text();
+        if text_input.width() <= 0. || text_input.height() <= 0. {
+            return 0;
+        }
+
+        let mut result = text.len();
+        self.layout_text_input_lines(text_input, |font, line_text, line_pos, start| {
+            if (line_pos.y..(line_pos.y + font.height())).contains(&pos.y) {
+                for glyph in font.shape(line_text) {
+                    if line_pos.x + glyph.x + glyph.advance / 2. >= pos.x {
+                        result = start + glyph.byte_index;
+                        return;
+                    }
+                }
+                result = start + line_text.trim_end().len();
+            }
+        });
+        result
+    }
+
+    fn text_input_position_for_byte_offset(
+        &self,
+        text_input: Pin<&sixtyfps_corelib::items::TextInput>,
+        byte_offset: usize,
+    ) -> Point {
+        let mut result = Point::default();
+        self.layout_text_input_lines(text_input, |font, line_text, line_pos, start| {
+            if (start..=(start + line_text.len())).contains(&byte_offset) {
+                result = line_pos
+                    + euclid::vec2(font.x_for_byte_offset(line_text, byte_offset - start), 0.);
+            }
+        });
+        result / self.runtime_window().scale_factor()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Renders the content of the window into a new pixel buffer, with straight (not premultiplied)
+/// alpha.
+///
+/// The size of the buffer is the size of the window in physical pixels, using the window's scale
+/// factor. If the window has no size yet, it is resized to the preferred size of its component.
+///
+/// Panics if the window was not created by the software backend.
+pub fn render_to_buffer(window: &impl WindowHandleAccess) -> SharedPixelBuffer<Rgba8Pixel> {
+    let runtime_window = window.window_handle().clone();
+    let software_window = runtime_window
+        .as_any()
+        .downcast_ref::<SoftwareWindow>()
+        .expect("render_to_buffer requires a window of the software backend");
+
+    let component_rc = runtime_window.component();
+    let component = ComponentRc::borrow_pin(&component_rc);
+    let root_item = component.as_ref().get_item_ref(0);
+    let window_item = ItemRef::downcast_pin::<sixtyfps_corelib::items::WindowItem>(root_item);
+
+    let mut size = window_item.map_or_else(Size::default, |window_item| {
+        Size::new(window_item.width(), window_item.height())
+    });
+    if size.width <= 0. || size.height <= 0. {
+        size = Size::new(
+            component.as_ref().layout_info(Orientation::Horizontal).preferred,
+            component.as_ref().layout_info(Orientation::Vertical).preferred,
+        );
+        runtime_window.set_window_item_geometry(size.width, size.height);
+    }
+
+    let scale_factor = runtime_window.scale_factor();
+    let width = (size.width * scale_factor).ceil().max(1.) as u32;
+    let height = (size.height * scale_factor).ceil().max(1.) as u32;
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+
+    {
+        let mut renderer =
+            renderer::SoftwareItemRenderer::new(pixmap.as_mut(), software_window, scale_factor);
+        if let Some(window_item) = window_item {
+            renderer.fill_background(window_item.background().into());
+        }
+        runtime_window.clone().draw_contents(|components| {
+            for (component, origin) in components {
+                sixtyfps_corelib::item_rendering::render_component_items(
+                    component,
+                    &mut renderer,
+                    *origin,
+                );
+            }
+        });
+    }
+
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(width as usize, height as usize);
+    for (dst, src) in buffer.make_mut_slice().iter_mut().zip(pixmap.pixels()) {
+        let color = src.demultiply();
+        *dst = Rgba8Pixel::new(color.red(), color.green(), color.blue(), color.alpha());
+    }
+    buffer
+}
+
+/// Initialize the software backend.
+/// Must be called before any call that would otherwise initialize the rendering backend.
+/// Calling it when the rendering backend is already initialized will have no effects
+pub fn init() {
+    sixtyfps_corelib::backend::instance_or_init(|| Box::new(SoftwareBackend::default()));
+}
@@ -0,0 +1,900 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+use std::pin::Pin;
+use std::rc::Rc;
+
+use sixtyfps_corelib::graphics::{
+    Brush, Color, GradientStop, Image, ImageInner, IntRect, Point, Rect, Size,
+};
+use sixtyfps_corelib::item_rendering::{CachedRenderingData, ItemRenderer};
+use sixtyfps_corelib::items::{self, FillRule, ImageFit, ImageRendering};
+use sixtyfps_corelib::properties::Property;
+use sixtyfps_corelib::window::WindowRc;
+
+use crate::fonts::{self, Font};
+use crate::SoftwareWindow;
+
+const KAPPA90: f32 = 0.55228;
+
+#[derive(Clone)]
+struct State {
+    /// Maps the coordinates of the current item, in physical pixels, to the pixmap
+    transform: tiny_skia::Transform,
+    /// The clip rectangle in the logical coordinates of the current item
+    clip: Rect,
+    clip_mask: Option<Rc<tiny_skia::ClipMask>>,
+    alpha: f32,
+}
+
+/// Renders the items into a tiny-skia pixmap.
+pub struct SoftwareItemRenderer<'a> {
+    pixmap: tiny_skia::PixmapMut<'a>,
+    window: &'a SoftwareWindow,
+    scale_factor: f32,
+    state: Vec<State>,
+}
+
+fn rect_with_radius_to_path(rect: Rect, border_radius: f32) -> Option<tiny_skia::Path> {
+    let (x0, y0, x1, y1) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+    let r = border_radius.min(rect.width() / 2.).min(rect.height() / 2.).max(0.);
+    let mut builder = tiny_skia::PathBuilder::new();
+    if r > 0. {
+        // distance between the end of an arc and its control point
+        let k = r * (1. - KAPPA90);
+        builder.move_to(x0 + r, y0);
+        builder.line_to(x1 - r, y0);
+        builder.cubic_to(x1 - k, y0, x1, y0 + k, x1, y0 + r);
+        builder.line_to(x1, y1 - r);
+        builder.cubic_to(x1, y1 - k, x1 - k, y1, x1 - r, y1);
+        builder.line_to(x0 + r, y1);
+        builder.cubic_to(x0 + k, y1, x0, y1 - k, x0, y1 - r);
+        builder.line_to(x0, y0 + r);
+        builder.cubic_to(x0, y0 + k, x0 + k, y0, x0 + r, y0);
+    } else {
+        builder.move_to(x0, y0);
+        builder.line_to(x1, y0);
+        builder.line_to(x1, y1);
+        builder.line_to(x0, y1);
+    }
+    builder.close();
+    builder.finish()
+}
+
+fn rect_to_path(rect: Rect) -> Option<tiny_skia::Path> {
+    rect_with_radius_to_path(rect, 0.)
+}
+
+fn adjust_rect_and_border_for_inner_drawing(rect: &mut Rect, border_width: &mut f32) {
+    // If the border width exceeds the width, just fill the rectangle.
+    *border_width = border_width.min((rect.size.width as f32) / 2.);
+    // adjust the size so that the border is drawn within the geometry
+    rect.origin.x += *border_width / 2.;
+    rect.origin.y += *border_width / 2.;
+    rect.size.width -= *border_width;
+    rect.size.height -= *border_width;
+}
+
+fn item_rect<Item: items::Item>(item: Pin<&Item>, scale_factor: f32) -> Rect {
+    let geometry = item.geometry();
+    euclid::rect(0., 0., geometry.width() * scale_factor, geometry.height() * scale_factor)
+}
+
+fn to_skia_stops<'a>(
+    stops: impl Iterator<Item = &'a GradientStop>,
+    alpha: f32,
+) -> Vec<tiny_skia::GradientStop> {
+    stops
+        .map(|stop| tiny_skia::GradientStop::new(stop.position, to_skia_color(&stop.color, alpha)))
+        .collect()
+}
+
+fn to_skia_color(color: &Color, alpha: f32) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba8(
+        color.red(),
+        color.green(),
+        color.blue(),
+        (color.alpha() as f32 * alpha).round() as u8,
+    )
+}
+
+impl<'a> SoftwareItemRenderer<'a> {
+    pub fn new(
+        pixmap: tiny_skia::PixmapMut<'a>,
+        window: &'a SoftwareWindow,
+        scale_factor: f32,
+    ) -> Self {
+        let clip = Rect::new(
+            Point::default(),
+            Size::new(pixmap.width() as f32, pixmap.height() as f32) / scale_factor,
+        );
+        Self {
+            pixmap,
+            window,
+            scale_factor,
+            state: vec![State {
+                transform: tiny_skia::Transform::identity(),
+                clip,
+                clip_mask: None,
+                alpha: 1.,
+            }],
+        }
+    }
+
+    fn current_state(&self) -> &State {
+        self.state.last().unwrap()
+    }
+
+    fn current_state_mut(&mut self) -> &mut State {
+        self.state.last_mut().unwrap()
+    }
+
+    /// Fills the whole pixmap with the brush, ignoring the current transform and clip.
+    pub fn fill_background(&mut self, brush: Brush) {
+        let rect = Rect::new(
+            Point::default(),
+            Size::new(self.pixmap.width() as f32, self.pixmap.height() as f32),
+        );
+        if let (Some(path), Some(paint)) = (rect_to_path(rect), self.brush_to_paint(brush, rect)) {
+            self.pixmap.fill_path(
+                &path,
+                &paint,
+                tiny_skia::FillRule::Winding,
+                tiny_skia::Transform::identity(),
+                None,
+            );
+        }
+    }
+
+    /// Returns the paint for the brush, where gradients cover the given bounds. The opacity of
+    /// the current state is applied to the colors.
+    fn brush_to_paint(&self, brush: Brush, bounds: Rect) -> Option<tiny_skia::Paint<'static>> {
+        if brush.is_transparent() {
+            return None;
+        }
+        let alpha = self.current_state().alpha;
+        let shader = match brush {
+            Brush::SolidColor(color) => tiny_skia::Shader::SolidColor(to_skia_color(&color, alpha)),
+            Brush::LinearGradient(gradient) => {
+                let transform = euclid::Transform2D::scale(bounds.width(), bounds.height())
+                    .then_translate(bounds.origin.to_vector());
+                let (start, end) = sixtyfps_corelib::graphics::line_for_angle(gradient.angle());
+                let start: Point = transform.transform_point(start);
+                let end: Point = transform.transform_point(end);
+                tiny_skia::LinearGradient::new(
+                    tiny_skia::Point::from_xy(start.x, start.y),
+                    tiny_skia::Point::from_xy(end.x, end.y),
+                    to_skia_stops(gradient.stops(), alpha),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                )?
+            }
+            Brush::RadialGradient(gradient) => {
+                let center = bounds.center();
+                // Like the CSS default, the circle extends to the farthest corner
+                let radius = (bounds.width() * bounds.width() + bounds.height() * bounds.height())
+                    .sqrt()
+                    / 2.;
+                tiny_skia::RadialGradient::new(
+                    tiny_skia::Point::from_xy(center.x, center.y),
+                    tiny_skia::Point::from_xy(center.x, center.y),
+                    radius,
+                    to_skia_stops(gradient.stops(), alpha),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                )?
+            }
+            _ => return None,
+        };
+        Some(tiny_skia::Paint { shader, anti_alias: true, ..Default::default() })
+    }
+
+    fn fill_path(&mut self, path: &tiny_skia::Path, paint: &tiny_skia::Paint, rule: FillRule) {
+        let state = self.state.last().unwrap();
+        if state.clip.is_empty() {
+            return;
+        }
+        let rule = match rule {
+            FillRule::nonzero => tiny_skia::FillRule::Winding,
+            FillRule::evenodd => tiny_skia::FillRule::EvenOdd,
+        };
+        self.pixmap.fill_path(path, paint, rule, state.transform, state.clip_mask.as_deref());
+    }
+
+    fn stroke_path(&mut self, path: &tiny_skia::Path, paint: &tiny_skia::Paint, width: f32) {
+        let state = self.state.last().unwrap();
+        if state.clip.is_empty() {
+            return;
+        }
+        let stroke = tiny_skia::Stroke { width, ..Default::default() };
+        self.pixmap.stroke_path(path, paint, &stroke, state.transform, state.clip_mask.as_deref());
+    }
+
+    fn draw_pixmap(&mut self, pixmap: &tiny_skia::Pixmap, x: f32, y: f32) {
+        let state = self.state.last().unwrap();
+        if state.clip.is_empty() {
+            return;
+        }
+        let paint = tiny_skia::PixmapPaint { opacity: state.alpha, ..Default::default() };
+        self.pixmap.draw_pixmap(
+            0,
+            0,
+            pixmap.as_ref(),
+            &paint,
+            state.transform.pre_translate(x, y),
+            state.clip_mask.as_deref(),
+        );
+    }
+
+    fn font(&self, request: sixtyfps_corelib::graphics::FontRequest) -> Font {
+        let request = request.merge(&self.window.runtime_window().default_font_properties());
+        fonts::FONT_CACHE.with(|cache| cache.borrow_mut().font(request, self.scale_factor))
+    }
+
+    fn draw_text_line(&mut self, font: &Font, line: &str, pos: Point, paint: &tiny_skia::Paint) {
+        let state = self.state.last().unwrap();
+        if state.clip.is_empty() {
+            return;
+        }
+        font.draw_line(
+            &mut self.pixmap,
+            line,
+            pos,
+            paint,
+            state.transform,
+            state.clip_mask.as_deref(),
+        );
+    }
+
+    fn draw_image_impl(
+        &mut self,
+        source_property: Pin<&Property<Image>>,
+        source_clip_rect: IntRect,
+        target_width: Pin<&Property<f32>>,
+        target_height: Pin<&Property<f32>>,
+        image_fit: ImageFit,
+        colorize_property: Option<Pin<&Property<Brush>>>,
+        image_rendering: ImageRendering,
+    ) {
+        let target_w = target_width.get() * self.scale_factor;
+        let target_h = target_height.get() * self.scale_factor;
+
+        if target_w <= 0. || target_h <= 0. {
+            return;
+        }
+
+        let image = source_property.get();
+        let image_inner: &ImageInner = (&image).into();
+        let image = match crate::images::load_image(
+            image_inner,
+            [target_w.ceil() as u32, target_h.ceil() as u32],
+        ) {
+            Some(image) => image,
+            None => return,
+        };
+
+        let colorize_brush = colorize_property.map_or(Brush::default(), |prop| prop.get());
+        let image = if colorize_brush.is_transparent() {
+            image
+        } else {
+            match self.colorize_image(&image, colorize_brush) {
+                Some(colorized) => Rc::new(colorized),
+                None => return,
+            }
+        };
+
+        let (source_width, source_height) = if source_clip_rect.is_empty() {
+            (image.width() as f32, image.height() as f32)
+        } else {
+            (source_clip_rect.width() as _, source_clip_rect.height() as _)
+        };
+
+        let mut source_x = source_clip_rect.min_x() as f32;
+        let mut source_y = source_clip_rect.min_y() as f32;
+
+        let mut image_fit_offset = Point::default();
+
+        let (source_to_target_scale_x, source_to_target_scale_y) = match image_fit {
+            ImageFit::fill => (target_w / source_width, target_h / source_height),
+            ImageFit::cover => {
+                let ratio = f32::max(target_w / source_width, target_h / source_height);
+
+                if source_width > target_w / ratio {
+                    source_x += (source_width - target_w / ratio) / 2.;
+                }
+                if source_height > target_h / ratio {
+                    source_y += (source_height - target_h / ratio) / 2.
+                }
+
+                (ratio, ratio)
+            }
+            ImageFit::contain => {
+                let ratio = f32::min(target_w / source_width, target_h / source_height);
+
+                if source_width < target_w / ratio {
+                    image_fit_offset.x = (target_w - source_width * ratio) / 2.;
+                }
+                if source_height < target_h / ratio {
+                    image_fit_offset.y = (target_h - source_height * ratio) / 2.
+                }
+
+                (ratio, ratio)
+            }
+        };
+
+        let quality = match image_rendering {
+            ImageRendering::smooth => tiny_skia::FilterQuality::Bilinear,
+            ImageRendering::pixelated => tiny_skia::FilterQuality::Nearest,
+        };
+        let paint = tiny_skia::Paint {
+            shader: tiny_skia::Pattern::new(
+                image.as_ref().as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                quality,
+                self.current_state().alpha,
+                tiny_skia::Transform::from_translate(-source_x, -source_y),
+            ),
+            anti_alias: true,
+            ..Default::default()
+        };
+
+        let path = match rect_to_path(euclid::rect(0., 0., source_width, source_height)) {
+            Some(path) => path,
+            None => return,
+        };
+        let state = self.state.last().unwrap();
+        if state.clip.is_empty() {
+            return;
+        }
+        let transform = state
+            .transform
+            .pre_translate(image_fit_offset.x, image_fit_offset.y)
+            .pre_scale(source_to_target_scale_x, source_to_target_scale_y);
+        self.pixmap.fill_path(
+            &path,
+            &paint,
+            tiny_skia::FillRule::Winding,
+            transform,
+            state.clip_mask.as_deref(),
+        );
+    }
+
+    /// Returns a copy of the image where the color of every pixel is replaced by the brush,
+    /// keeping the alpha channel.
+    fn colorize_image(&self, image: &tiny_skia::Pixmap, brush: Brush) -> Option<tiny_skia::Pixmap> {
+        let mut colorized = image.clone();
+        let rect = euclid::rect(0., 0., image.width() as f32, image.height() as f32);
+        let mut paint = self.brush_to_paint(brush, rect)?;
+        paint.blend_mode = tiny_skia::BlendMode::SourceIn;
+        colorized.fill_path(
+            &rect_to_path(rect)?,
+            &paint,
+            tiny_skia::FillRule::Winding,
+            tiny_skia::Transform::identity(),
+            None,
+        );
+        Some(colorized)
+    }
+}
+
+impl<'a> ItemRenderer for SoftwareItemRenderer<'a> {
+    fn draw_rectangle(&mut self, rect: Pin<&items::Rectangle>) {
+        let geometry = item_rect(rect, self.scale_factor);
+        if geometry.is_empty() {
+            return;
+        }
+        if let (Some(path), Some(paint)) =
+            (rect_to_path(geometry), self.brush_to_paint(rect.background(), geometry))
+        {
+            self.fill_path(&path, &paint, FillRule::nonzero);
+        }
+    }
+
+    fn draw_border_rectangle(&mut self, rect: Pin<&items::BorderRectangle>) {
+        let mut geometry = item_rect(rect, self.scale_factor);
+        if geometry.is_empty() {
+            return;
+        }
+
+        let mut border_width = rect.border_width() * self.scale_factor;
+        // In CSS the border is entirely towards the inside of the boundary
+        // geometry, while the line width of a stroke is 50% in- and 50% outwards.
+        // We choose the CSS model, so the inner rectangle is adjusted accordingly.
+        adjust_rect_and_border_for_inner_drawing(&mut geometry, &mut border_width);
+
+        let path =
+            match rect_with_radius_to_path(geometry, rect.border_radius() * self.scale_factor) {
+                Some(path) => path,
+                None => return,
+            };
+
+        if let Some(paint) = self.brush_to_paint(rect.background(), geometry) {
+            self.fill_path(&path, &paint, FillRule::nonzero);
+        }
+        if border_width > 0. {
+            if let Some(paint) = self.brush_to_paint(rect.border_color(), geometry) {
+                self.stroke_path(&path, &paint, border_width);
+            }
+        }
+    }
+
+    fn draw_image(&mut self, image: Pin<&items::ImageItem>) {
+        self.draw_image_impl(
+            items::ImageItem::FIELD_OFFSETS.source.apply_pin(image),
+            IntRect::default(),
+            items::ImageItem::FIELD_OFFSETS.width.apply_pin(image),
+            items::ImageItem::FIELD_OFFSETS.height.apply_pin(image),
+            image.image_fit(),
+            None,
+            image.image_rendering(),
+        );
+    }
+
+    fn draw_clipped_image(&mut self, clipped_image: Pin<&items::ClippedImage>) {
+        let source_clip_rect = IntRect::new(
+            [clipped_image.source_clip_x(), clipped_image.source_clip_y()].into(),
+            [clipped_image.source_clip_width(), clipped_image.source_clip_height()].into(),
+        );
+
+        self.draw_image_impl(
+            items::ClippedImage::FIELD_OFFSETS.source.apply_pin(clipped_image),
+            source_clip_rect,
+            items::ClippedImage::FIELD_OFFSETS.width.apply_pin(clipped_image),
+            items::ClippedImage::FIELD_OFFSETS.height.apply_pin(clipped_image),
+            clipped_image.image_fit(),
+            Some(items::ClippedImage::FIELD_OFFSETS.colorize.apply_pin(clipped_image)),
+            clipped_image.image_rendering(),
+        );
+    }
+
+    fn draw_text(&mut self, text: Pin<&items::Text>) {
+        let geometry = item_rect(text, self.scale_factor);
+        if geometry.is_empty() {
+            return;
+        }
+
+        let font = self.font(text.unresolved_font_request());
+        let paint = match self.brush_to_paint(text.color(), geometry) {
+            Some(paint) => paint,
+            None => return,
+        };
+
+        let string = text.text();
+        let mut lines = Vec::new();
+        fonts::layout_text_lines(
+            string.as_str(),
+            &font,
+            geometry.size,
+            (text.horizontal_alignment(), text.vertical_alignment()),
+            text.wrap(),
+            text.overflow(),
+            false,
+            |to_draw, pos, _| lines.push((to_draw.trim_end().to_owned(), pos)),
+        );
+        for (line, pos) in lines {
+            self.draw_text_line(&font, &line, pos, &paint);
+        }
+    }
+
+    fn draw_text_input(&mut self, text_input: Pin<&items::TextInput>) {
+        let geometry = item_rect(text_input, self.scale_factor);
+        if geometry.is_empty() {
+            return;
+        }
+
+        let font = self.font(text_input.unresolved_font_request());
+        let paint = match self.brush_to_paint(text_input.color(), geometry) {
+            Some(paint) => paint,
+            None => return,
+        };
+        let selection_paint = |color: Color| {
+            self.brush_to_paint(Brush::SolidColor(color), geometry).unwrap_or_else(|| {
+                let mut transparent = tiny_skia::Paint::default();
+                transparent.set_color(tiny_skia::Color::TRANSPARENT);
+                transparent
+            })
+        };
+        let selected_paint = selection_paint(text_input.selection_foreground_color());
+        let selection_background_paint = selection_paint(text_input.selection_background_color());
+
+        let (min_select, max_select) = text_input.selection_anchor_and_cursor();
+        let cursor_pos = text_input.cursor_position();
+        let cursor_visible = cursor_pos >= 0 && text_input.cursor_visible() && text_input.enabled();
+        let cursor_pos = cursor_pos.max(0) as usize;
+        let font_height = font.height();
+        let text = text_input.text();
+
+        let mut lines = Vec::new();
+        let baseline_y = fonts::layout_text_lines(
+            text.as_str(),
+            &font,
+            geometry.size,
+            (text_input.horizontal_alignment(), text_input.vertical_alignment()),
+            text_input.wrap(),
+            items::TextOverflow::clip,
+            text_input.single_line(),
+            |to_draw, pos, start| lines.push((to_draw.to_owned(), pos, start)),
+        );
+
+        let mut cursor_point: Option<Point> = None;
+        for (line, pos, start) in lines {
+            let range = start..(start + line.len());
+            if min_select != max_select
+                && (range.contains(&min_select)
+                    || range.contains(&max_select)
+                    || (min_select..max_select).contains(&start))
+            {
+                let selection_start = min_select.saturating_sub(start).min(line.len());
+                let selection_end = (max_select - start).min(line.len());
+                let selection_start_x = font.x_for_byte_offset(&line, selection_start);
+                let selection_end_x = font.x_for_byte_offset(&line, selection_end);
+                if let Some(selection_rect) = rect_to_path(Rect::new(
+                    pos + euclid::vec2(selection_start_x, 0.),
+                    Size::new(selection_end_x - selection_start_x, font_height),
+                )) {
+                    self.fill_path(&selection_rect, &selection_background_paint, FillRule::nonzero);
+                }
+                self.draw_text_line(&font, line[..selection_start].trim_end(), pos, &paint);
+                self.draw_text_line(
+                    &font,
+                    line[selection_start..selection_end].trim_end(),
+                    pos + euclid::vec2(selection_start_x, 0.),
+                    &selected_paint,
+                );
+                self.draw_text_line(
+                    &font,
+                    line[selection_end..].trim_end(),
+                    pos + euclid::vec2(selection_end_x, 0.),
+                    &paint,
+                );
+            } else {
+                // no selection on this line
+                self.draw_text_line(&font, line.trim_end(), pos, &paint);
+            }
+            if cursor_visible
+                && (range.contains(&cursor_pos)
+                    || (cursor_pos == range.end && cursor_pos == text.len()))
+            {
+                let cursor_x = font.x_for_byte_offset(line.trim_end(), cursor_pos - start);
+                cursor_point = Some([pos.x + cursor_x, pos.y].into());
+            }
+        }
+
+        if let Some(cursor_point) =
+            cursor_point.or_else(|| cursor_visible.then(|| [0., baseline_y].into()))
+        {
+            if let Some(cursor_rect) = rect_to_path(Rect::new(
+                cursor_point,
+                Size::new(text_input.text_cursor_width() * self.scale_factor, font_height),
+            )) {
+                self.fill_path(&cursor_rect, &paint, FillRule::nonzero);
+            }
+        }
+    }
+
+    fn draw_path(&mut self, path: Pin<&items::Path>) {
+        let elements = path.elements();
+        if matches!(elements, sixtyfps_corelib::PathData::None) {
+            return;
+        }
+
+        let (offset, path_events) = path.fitted_path_events();
+        let scale_factor = self.scale_factor;
+
+        let mut builder = tiny_skia::PathBuilder::new();
+        for x in path_events.iter() {
+            match x {
+                lyon_path::Event::Begin { at } => {
+                    builder.move_to(at.x * scale_factor, at.y * scale_factor);
+                }
+                lyon_path::Event::Line { from: _, to } => {
+                    builder.line_to(to.x * scale_factor, to.y * scale_factor);
+                }
+                lyon_path::Event::Quadratic { from: _, ctrl, to } => {
+                    builder.quad_to(
+                        ctrl.x * scale_factor,
+                        ctrl.y * scale_factor,
+                        to.x * scale_factor,
+                        to.y * scale_factor,
+                    );
+                }
+                lyon_path::Event::Cubic { from: _, ctrl1, ctrl2, to } => {
+                    builder.cubic_to(
+                        ctrl1.x * scale_factor,
+                        ctrl1.y * scale_factor,
+                        ctrl2.x * scale_factor,
+                        ctrl2.y * scale_factor,
+                        to.x * scale_factor,
+                        to.y * scale_factor,
+                    );
+                }
+                lyon_path::Event::End { last: _, first: _, close } => {
+                    if close {
+                        builder.close()
+                    }
+                }
+            }
+        }
+        let skia_path = match builder.finish() {
+            Some(path) => path,
+            None => return,
+        };
+        let bounds = skia_path.bounds();
+        let bounds = euclid::rect(bounds.x(), bounds.y(), bounds.width(), bounds.height());
+
+        let fill_paint = self.brush_to_paint(path.fill(), bounds);
+        let stroke_paint = self.brush_to_paint(path.stroke(), bounds);
+
+        self.save_state();
+        self.translate(offset.x, offset.y);
+        if let Some(fill_paint) = fill_paint {
+            self.fill_path(&skia_path, &fill_paint, path.fill_rule());
+        }
+        if let Some(stroke_paint) = stroke_paint {
+            self.stroke_path(&skia_path, &stroke_paint, path.stroke_width() * scale_factor);
+        }
+        self.restore_state();
+    }
+
+    /// Draws a rectangular shadow shape, which is usually placed underneath another rectangular shape
+    /// with an offset (the drop-shadow-offset-x/y). The shadow is rendered into an image of the size
+    /// of the shape extended by the blur radius, which is then blurred and cached.
+    fn draw_box_shadow(&mut self, box_shadow: Pin<&items::BoxShadow>) {
+        if box_shadow.color().alpha() == 0
+            || (box_shadow.blur() == 0.0
+                && box_shadow.offset_x() == 0.
+                && box_shadow.offset_y() == 0.)
+        {
+            return;
+        }
+
+        let scale_factor = self.scale_factor;
+        let shadow =
+            box_shadow.cached_rendering_data.get_or_update(&self.window.graphics_cache, || {
+                let blur = box_shadow.blur() * scale_factor;
+                let width = box_shadow.width() * scale_factor;
+                let height = box_shadow.height() * scale_factor;
+                let radius = box_shadow.border_radius() * scale_factor;
+
+                let mut shadow = tiny_skia::Pixmap::new(
+                    (width + 2. * blur).ceil() as u32,
+                    (height + 2. * blur).ceil() as u32,
+                )?;
+                let path =
+                    rect_with_radius_to_path(euclid::rect(blur, blur, width, height), radius)?;
+                let mut paint = tiny_skia::Paint::default();
+                paint.set_color(to_skia_color(&box_shadow.color(), 1.));
+                paint.anti_alias = true;
+                shadow.fill_path(
+                    &path,
+                    &paint,
+                    tiny_skia::FillRule::Winding,
+                    tiny_skia::Transform::identity(),
+                    None,
+                );
+                blur_pixmap(&mut shadow, blur / 2.);
+                Some(Rc::new(shadow))
+            });
+
+        let shadow = match shadow {
+            Some(shadow) => shadow,
+            None => return, // Zero width or height shadow
+        };
+
+        let blur = box_shadow.blur() * scale_factor;
+        self.draw_pixmap(
+            &shadow,
+            box_shadow.offset_x() * scale_factor - blur,
+            box_shadow.offset_y() * scale_factor - blur,
+        );
+    }
+
+    fn combine_clip(&mut self, mut clip_rect: Rect, mut radius: f32, mut border_width: f32) {
+        let scale_factor = self.scale_factor;
+        let pixmap_size = (self.pixmap.width(), self.pixmap.height());
+        let state = self.current_state_mut();
+        state.clip = state.clip.intersection(&clip_rect).unwrap_or_default();
+
+        // The clip is the area inside the border.
+        radius -= border_width * KAPPA90;
+        border_width *= 2.;
+
+        // Convert from logical to physical pixels
+        border_width *= scale_factor;
+        radius *= scale_factor;
+        clip_rect *= scale_factor;
+
+        adjust_rect_and_border_for_inner_drawing(&mut clip_rect, &mut border_width);
+
+        let path = match rect_with_radius_to_path(clip_rect, radius)
+            .and_then(|path| path.transform(state.transform))
+        {
+            Some(path) if !state.clip.is_empty() => path,
+            _ => {
+                // Nothing can be drawn anymore, see is_clipped_out()
+                state.clip = Rect::default();
+                return;
+            }
+        };
+
+        let mask = match &state.clip_mask {
+            Some(mask) => {
+                let mut mask = tiny_skia::ClipMask::clone(mask);
+                mask.intersect_path(&path, tiny_skia::FillRule::Winding, true);
+                mask
+            }
+            None => {
+                let mut mask = tiny_skia::ClipMask::new();
+                mask.set_path(
+                    pixmap_size.0,
+                    pixmap_size.1,
+                    &path,
+                    tiny_skia::FillRule::Winding,
+                    true,
+                );
+                mask
+            }
+        };
+        state.clip_mask = Some(Rc::new(mask));
+    }
+
+    fn get_current_clip(&self) -> Rect {
+        self.current_state().clip
+    }
+
+    fn translate(&mut self, x: f32, y: f32) {
+        let scale_factor = self.scale_factor;
+        let state = self.current_state_mut();
+        state.transform = state.transform.pre_translate(x * scale_factor, y * scale_factor);
+        state.clip = state.clip.translate((-x, -y).into());
+    }
+
+    fn rotate(&mut self, angle_in_degrees: f32) {
+        let angle_in_radians = angle_in_degrees.to_radians();
+        let (sin, cos) = angle_in_radians.sin_cos();
+        let state = self.current_state_mut();
+        state.transform =
+            state.transform.pre_concat(tiny_skia::Transform::from_row(cos, sin, -sin, cos, 0., 0.));
+
+        // Compute the bounding box of the rotated clip rectangle
+        let clip = &mut state.clip;
+        let rotate_point = |p: Point| (p.x * cos - p.y * sin, p.x * sin + p.y * cos);
+        let corners = [
+            rotate_point(clip.origin),
+            rotate_point(clip.origin + euclid::vec2(clip.width(), 0.)),
+            rotate_point(clip.origin + euclid::vec2(0., clip.height())),
+            rotate_point(clip.origin + clip.size),
+        ];
+        let origin: Point = (
+            corners.iter().fold(f32::MAX, |a, b| b.0.min(a)),
+            corners.iter().fold(f32::MAX, |a, b| b.1.min(a)),
+        )
+            .into();
+        let end: Point = (
+            corners.iter().fold(f32::MIN, |a, b| b.0.max(a)),
+            corners.iter().fold(f32::MIN, |a, b| b.1.max(a)),
+        )
+            .into();
+        *clip = Rect::new(origin, (end - origin).into());
+    }
+
+    fn apply_opacity(&mut self, opacity: f32) {
+        self.current_state_mut().alpha *= opacity;
+    }
+
+    fn save_state(&mut self) {
+        self.state.push(self.current_state().clone());
+    }
+
+    fn restore_state(&mut self) {
+        self.state.pop();
+    }
+
+    fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    fn draw_cached_pixmap(
+        &mut self,
+        item_cache: &CachedRenderingData,
+        update_fn: &dyn Fn(&mut dyn FnMut(u32, u32, &[u8])),
+    ) {
+        let cached = item_cache.get_or_update(&self.window.graphics_cache, || {
+            let mut cached = None;
+            update_fn(&mut |width: u32, height: u32, data: &[u8]| {
+                cached = tiny_skia::Pixmap::new(width, height).map(|mut pixmap| {
+                    pixmap.data_mut().copy_from_slice(data);
+                    Rc::new(pixmap)
+                });
+            });
+            cached
+        });
+        if let Some(pixmap) = cached {
+            self.draw_pixmap(&pixmap, 0., 0.);
+        }
+    }
+
+    fn draw_string(&mut self, string: &str, color: Color) {
+        let font = self.font(Default::default());
+        let mut paint = tiny_skia::Paint::default();
+        paint.set_color(to_skia_color(&color, self.current_state().alpha));
+        paint.anti_alias = true;
+        self.draw_text_line(&font, string, Point::default(), &paint);
+    }
+
+    fn window(&self) -> WindowRc {
+        self.window.runtime_window()
+    }
+
+    fn as_any(&mut self) -> &mut dyn std::any::Any {
+        // The renderer borrows the target pixmap, so it is not `'static` and cannot be
+        // returned itself. Nothing downcasts it anyway, this is only used by the Qt backend.
+        &mut self.state
+    }
+}
+
+/// Approximates a gaussian blur with the given standard deviation by three box blurs.
+fn blur_pixmap(pixmap: &mut tiny_skia::Pixmap, sigma: f32) {
+    if sigma <= 0. {
+        return;
+    }
+    // Size of the boxes, from https://www.w3.org/TR/filter-effects-1/#feGaussianBlurElement
+    let d = (sigma * 3. * (2. * std::f32::consts::PI).sqrt() / 4. + 0.5).floor() as usize;
+    let radius = d / 2;
+    if radius == 0 {
+        return;
+    }
+    let (width, height) = (pixmap.width() as usize, pixmap.height() as usize);
+    let data = pixmap.data_mut();
+    let mut line = Vec::new();
+    for _ in 0..3 {
+        for y in 0..height {
+            box_blur_line(data, y * width * 4, 4, width, radius, &mut line);
+        }
+        for x in 0..width {
+            box_blur_line(data, x * 4, width * 4, height, radius, &mut line);
+        }
+    }
+}
+
+/// Blurs `len` pixels of the data, starting at `start` and separated by `step` bytes.
+fn box_blur_line(
+    data: &mut [u8],
+    start: usize,
+    step: usize,
+    len: usize,
+    radius: usize,
+    line: &mut Vec<[u8; 4]>,
+) {
+    line.clear();
+    line.extend((0..len).map(|i| {
+        let offset = start + i * step;
+        [data[offset], data[offset + 1], data[offset + 2], data[offset + 3]]
+    }));
+    let window = (2 * radius + 1) as u32;
+    let mut sum = [0u32; 4];
+    let pixel = |i: isize| -> [u8; 4] {
+        if i < 0 || i as usize >= len {
+            [0; 4]
+        } else {
+            line[i as usize]
+        }
+    };
+    for i in -(radius as isize)..=(radius as isize) {
+        let p = pixel(i);
+        for c in 0..4 {
+            sum[c] += p[c] as u32;
+        }
+    }
+    for i in 0..len {
+        let offset = start + i * step;
+        for c in 0..4 {
+            data[offset + c] = (sum[c] / window) as u8;
+        }
+        let removed = pixel(i as isize - radius as isize);
+        let added = pixel(i as isize + radius as isize + 1);
+        for c in 0..4 {
+            sum[c] = sum[c] + added[c] as u32 - removed[c] as u32;
+        }
+    }
+}
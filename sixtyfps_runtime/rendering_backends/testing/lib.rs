@@ -13,8 +13,9 @@
 use image::GenericImageView;
 use sixtyfps_corelib::component::ComponentRc;
 use sixtyfps_corelib::graphics::{Image, Point, Size};
-use sixtyfps_corelib::window::{PlatformWindow, Window};
+use sixtyfps_corelib::window::{PlatformWindow, Window, WindowState};
 use sixtyfps_corelib::ImageInner;
+use std::cell::Cell;
 use std::path::Path;
 use std::pin::Pin;
 use std::rc::Rc;
@@ -27,6 +28,8 @@ pub struct TestingBackend {
 
 impl sixtyfps_corelib::backend::Backend for TestingBackend {
     fn create_window(&'static self) -> Rc<Window> {
+        // The thread creating the window is the one running its mocked event loop
+        sixtyfps_corelib::tests::set_runs_mocked_event_loop();
         Window::new(|_| Rc::new(TestingWindow::default()))
     }
 
@@ -36,6 +39,16 @@ fn run_event_loop(&'static self, _behavior: sixtyfps_corelib::backend::EventLoop
 
     fn quit_event_loop(&'static self) {}
 
+    fn process_events(
+        &'static self,
+        timeout: core::time::Duration,
+        _behavior: sixtyfps_corelib::backend::EventLoopQuitBehavior,
+    ) -> bool {
+        // Waiting for the timeout lets the mocked time elapse
+        sixtyfps_corelib::tests::sixtyfps_mock_elapsed_time(timeout.as_millis() as u64);
+        true
+    }
+
     fn register_font_from_memory(
         &'static self,
         _data: &'static [u8],
@@ -58,8 +71,12 @@ fn clipboard_text(&'static self) -> Option<String> {
         self.clipboard.lock().unwrap().clone()
     }
 
-    fn post_event(&'static self, _event: Box<dyn FnOnce() + Send>) {
-        unimplemented!("event with the testing backend");
+    fn post_event(&'static self, event: Box<dyn FnOnce() + Send>) {
+        sixtyfps_corelib::tests::post_mocked_event(event);
+    }
+
+    fn duration_since_start(&'static self) -> core::time::Duration {
+        sixtyfps_corelib::tests::mocked_duration_since_start()
     }
 
     fn image_size(&'static self, image: &Image) -> Size {
@@ -90,13 +107,16 @@ fn image_size(&'static self, image: &Image) -> Size {
     }
 }
 
+/// A window that is never shown. The position and state requested by the application are
+/// recorded, so that tests can check them.
 #[derive(Default)]
-pub struct TestingWindow {}
+pub struct TestingWindow {
+    position: Cell<Point>,
+    window_state: Cell<WindowState>,
+}
 
 impl PlatformWindow for TestingWindow {
-    fn show(self: Rc<Self>) {
-        unimplemented!("showing a testing window")
-    }
+    fn show(self: Rc<Self>) {}
 
     fn hide(self: Rc<Self>) {}
 
@@ -114,9 +134,7 @@ fn show_popup(&self, _popup: &ComponentRc, _position: sixtyfps_corelib::graphics
 
     fn request_window_properties_update(&self) {}
 
-    fn apply_window_properties(&self, _window_item: Pin<&sixtyfps_corelib::items::WindowItem>) {
-        todo!()
-    }
+    fn apply_window_properties(&self, _window_item: Pin<&sixtyfps_corelib::items::WindowItem>) {}
 
     fn apply_geometry_constraint(
         &self,
@@ -155,6 +173,22 @@ fn text_input_position_for_byte_offset(
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn position(&self) -> Point {
+        self.position.get()
+    }
+
+    fn set_position(&self, position: Point) {
+        self.position.set(position);
+    }
+
+    fn window_state(&self) -> WindowState {
+        self.window_state.get()
+    }
+
+    fn set_window_state(&self, state: WindowState) {
+        self.window_state.set(state);
+    }
 }
 
 /// Initialize the testing backend.
@@ -18,12 +18,14 @@ pub fn identifier(&self) -> String {
     }
 }
 
-/// Returns a list of all the `.60` files in the `tests/cases` subfolders.
-pub fn collect_test_cases() -> std::io::Result<Vec<TestCase>> {
+/// Returns a list of all the `.60` files in the subfolders of `tests/<sub_folders>`,
+/// for example `tests/cases`.
+pub fn collect_test_cases(sub_folders: &str) -> std::io::Result<Vec<TestCase>> {
     let mut results = vec![];
 
-    let case_root_dir: std::path::PathBuf =
-        [env!("CARGO_MANIFEST_DIR"), "..", "..", "cases"].iter().collect();
+    let mut case_root_dir: std::path::PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "..", ".."].iter().collect();
+    case_root_dir.push(sub_folders);
 
     println!("cargo:rerun-if-env-changed=SIXTYFPS_TEST_FILTER");
     let filter = std::env::var("SIXTYFPS_TEST_FILTER").ok();
@@ -0,0 +1,154 @@
+// Copyright © SixtyFPS GmbH <info@sixtyfps.io>
+// SPDX-License-Identifier: (GPL-3.0-only OR LicenseRef-SixtyFPS-commercial)
+
+use itertools::Itertools;
+use sixtyfps_corelib::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// The maximum difference for each color channel of a pixel before it is reported as different
+/// from the reference. This accounts for small differences in anti-aliasing.
+const DEFAULT_TOLERANCE: u8 = 8;
+
+/// Returns the location of the reference screenshot of the test case, in `tests/screenshots/references`
+/// with the same relative path as the test case in `tests/screenshots/cases`.
+fn reference_path(testcase: &test_driver_lib::TestCase) -> PathBuf {
+    let mut path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "..", "..", "screenshots", "references"].iter().collect();
+    path.push(&testcase.relative_path);
+    path.set_extension("png");
+    path
+}
+
+/// Renders the test case with the software backend and compares the result with the reference
+/// screenshot.
+///
+/// A test case without a reference screenshot is an error. If the `SIXTYFPS_CREATE_SCREENSHOTS`
+/// environment variable is set, the reference screenshots are written instead of compared.
+/// When the comparison fails, the rendering and an image highlighting the differences are written
+/// next to the reference, with the `.actual.png` and `.diff.png` extensions.
+pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>> {
+    sixtyfps_rendering_backend_software::init();
+
+    let reference = reference_path(testcase);
+    let create_references = std::env::var_os("SIXTYFPS_CREATE_SCREENSHOTS").is_some();
+    if !create_references && !reference.exists() {
+        return Err(format!(
+            "{}: the reference screenshot {} is missing, set SIXTYFPS_CREATE_SCREENSHOTS=1 to create it",
+            testcase.relative_path.display(),
+            reference.display()
+        )
+        .into());
+    }
+
+    let source = std::fs::read_to_string(&testcase.absolute_path)?;
+    let include_paths = test_driver_lib::extract_include_paths(&source)
+        .map(std::path::PathBuf::from)
+        .collect::<Vec<_>>();
+    let mut compiler = sixtyfps_interpreter::ComponentCompiler::default();
+    compiler.set_include_paths(include_paths);
+    compiler.set_style(String::from("fluent"));
+
+    let component =
+        spin_on::spin_on(compiler.build_from_source(source, testcase.absolute_path.clone()));
+    let component = match component {
+        None => {
+            sixtyfps_interpreter::print_diagnostics(&compiler.diagnostics());
+            return Err(compiler.diagnostics().iter().map(|d| d.to_string()).join("\n").into());
+        }
+        Some(c) => c,
+    };
+
+    let instance = component.create();
+    let screenshot = sixtyfps_rendering_backend_software::render_to_buffer(instance.window());
+
+    if create_references {
+        std::fs::create_dir_all(reference.parent().unwrap())?;
+        save_png(&screenshot, &reference)?;
+        return Ok(());
+    }
+
+    let reference_image = image::open(&reference)?.into_rgba8();
+    let tolerance = match std::env::var("SIXTYFPS_SCREENSHOT_TOLERANCE") {
+        Ok(tolerance) => tolerance.parse()?,
+        Err(_) => DEFAULT_TOLERANCE,
+    };
+
+    if let Some(diff) = compare(&screenshot, &reference_image, tolerance) {
+        save_png(&screenshot, &reference.with_extension("actual.png"))?;
+        diff.save(reference.with_extension("diff.png"))?;
+        return Err(format!(
+            "{}: the rendering differs from the reference screenshot {}",
+            testcase.relative_path.display(),
+            reference.display()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn save_png(buffer: &SharedPixelBuffer<Rgba8Pixel>, path: &Path) -> Result<(), Box<dyn Error>> {
+    image::save_buffer(
+        path,
+        buffer.as_bytes(),
+        buffer.width() as u32,
+        buffer.height() as u32,
+        image::ColorType::Rgba8,
+    )?;
+    Ok(())
+}
+
+/// Returns None if every pixel of the screenshot is within the tolerance of the reference.
+/// Otherwise returns an image where the differing pixels are red, on top of a faded copy of
+/// the reference.
+fn compare(
+    screenshot: &SharedPixelBuffer<Rgba8Pixel>,
+    reference: &image::RgbaImage,
+    tolerance: u8,
+) -> Option<image::RgbaImage> {
+    let (width, height) = (screenshot.width() as u32, screenshot.height() as u32);
+    if reference.dimensions() != (width, height) {
+        eprintln!(
+            "The size of the screenshot {}x{} differs from the size of the reference {}x{}",
+            width,
+            height,
+            reference.width(),
+            reference.height()
+        );
+    }
+    let diff_width = width.max(reference.width());
+    let diff_height = height.max(reference.height());
+
+    let mut different_pixels = 0;
+    let diff = image::RgbaImage::from_fn(diff_width, diff_height, |x, y| {
+        let actual = (x < width && y < height)
+            .then(|| screenshot.as_slice()[(y * width + x) as usize])
+            .map(|p| [p.r, p.g, p.b, p.a]);
+        let expected =
+            (x < reference.width() && y < reference.height()).then(|| reference.get_pixel(x, y).0);
+        match (actual, expected) {
+            (Some(actual), Some(expected))
+                if actual
+                    .iter()
+                    .zip(expected.iter())
+                    .all(|(a, e)| (*a as i16 - *e as i16).abs() <= tolerance as i16) =>
+            {
+                // Fade the matching pixels, so that the differences stand out
+                let [r, g, b, a] = expected;
+                image::Rgba([r, g, b, a / 4])
+            }
+            _ => {
+                different_pixels += 1;
+                image::Rgba([255, 0, 0, 255])
+            }
+        }
+    });
+
+    if different_pixels == 0 {
+        None
+    } else {
+        eprintln!("{} pixels differ from the reference", different_pixels);
+        Some(diff)
+    }
+}
@@ -24,6 +24,10 @@ struct Cli {
     #[clap(long, name = "style name")]
     style: Option<String>,
 
+    /// The gettext domain used to look up the translations of the `@tr(...)` strings
+    #[clap(long = "translation-domain", name = "domain")]
+    translation_domain: Option<String>,
+
     /// Generate a dependency file
     #[clap(name = "dependency file", long = "depfile", number_of_values = 1, parse(from_os_str))]
     depfile: Option<std::path::PathBuf>,
@@ -48,6 +52,7 @@ fn main() -> std::io::Result<()> {
     if let Some(style) = args.style {
         compiler_config.style = Some(style);
     }
+    compiler_config.translation_domain = args.translation_domain;
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
     let (doc, diag) = spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
@@ -62,7 +62,9 @@ pub fn goto_definition(
                         } => e.upgrade()?.borrow().node.clone()?.into(),
                         LookupResult::Expression {
                             expression:
-                                Expression::CallbackReference(nr) | Expression::PropertyReference(nr),
+                                Expression::CallbackReference(nr)
+                                | Expression::PropertyReference(nr)
+                                | Expression::FunctionReference(nr),
                             ..
                         } => {
                             let mut el = nr.element();
@@ -70,6 +72,9 @@ pub fn goto_definition(
                                 if let Some(x) = el.borrow().property_declarations.get(nr.name()) {
                                     break (**x.node.as_ref()?).clone();
                                 }
+                                if let Some(f) = el.borrow().functions.get(nr.name()) {
+                                    break f.node.clone()?.into();
+                                }
                                 let base = el.borrow().base_type.clone();
                                 if let Type::Component(c) = base {
                                     el = c.root_element.clone();
@@ -579,6 +579,7 @@ fn get_document_symbols(
 
     let inner_components = doc.inner_components.clone();
     let inner_structs = doc.inner_structs.clone();
+    let inner_enums = doc.inner_enums.clone();
     let mut make_range = |node: &SyntaxNode| {
         let r = node.text_range();
         Some(Range::new(
@@ -612,6 +613,15 @@ fn get_document_symbols(
         _ => None,
     }));
 
+    r.extend(inner_enums.iter().filter_map(|e| {
+        Some(SymbolInformation {
+            location: Location::new(uri.clone(), make_range(e.node.as_ref()?)?),
+            name: e.name.clone(),
+            kind: lsp_types::SymbolKind::ENUM,
+            ..si.clone()
+        })
+    }));
+
     Some(r.into())
 
     // TODO: add the structs
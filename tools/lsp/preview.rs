@@ -3,11 +3,8 @@
 
 use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
-use std::future::Future;
 use std::path::{Path, PathBuf};
-use std::pin::Pin;
-use std::sync::{Arc, Condvar, Mutex};
-use std::task::Wake;
+use std::sync::{Condvar, Mutex};
 
 use lsp_server::Message;
 use lsp_types::notification::Notification;
@@ -33,32 +30,10 @@ enum RequestedGuiEventLoopState {
 static GUI_EVENT_LOOP_STATE_REQUEST: Lazy<Mutex<RequestedGuiEventLoopState>> =
     Lazy::new(|| Mutex::new(RequestedGuiEventLoopState::Uninitialized));
 
-struct FutureRunner {
-    fut: Mutex<Option<Pin<Box<dyn Future<Output = ()>>>>>,
-}
-
-/// Safety: the future is only going to be run in the UI thread
-unsafe impl Send for FutureRunner {}
-/// Safety: the future is only going to be run in the UI thread
-unsafe impl Sync for FutureRunner {}
-
-impl Wake for FutureRunner {
-    fn wake(self: Arc<Self>) {
-        sixtyfps_rendering_backend_default::backend().post_event(Box::new(move || {
-            let waker = self.clone().into();
-            let mut cx = std::task::Context::from_waker(&waker);
-            let mut fut_opt = self.fut.lock().unwrap();
-            if let Some(fut) = &mut *fut_opt {
-                match fut.as_mut().poll(&mut cx) {
-                    std::task::Poll::Ready(_) => *fut_opt = None,
-                    std::task::Poll::Pending => {}
-                }
-            }
-        }));
-    }
-}
-
-fn run_in_ui_thread(fut: Pin<Box<dyn Future<Output = ()>>>) {
+/// Run the future created by `create_future` in the UI thread
+fn run_in_ui_thread<F: std::future::Future<Output = ()> + 'static>(
+    create_future: impl FnOnce() -> F + Send + 'static,
+) {
     // Wake up the main thread to start the event loop, if possible
     {
         let mut state_request = GUI_EVENT_LOOP_STATE_REQUEST.lock().unwrap();
@@ -72,7 +47,9 @@ fn run_in_ui_thread(fut: Pin<Box<dyn Future<Output = ()>>>) {
         }
     }
 
-    Arc::new(FutureRunner { fut: Mutex::new(Some(fut)) }).wake()
+    sixtyfps_rendering_backend_default::backend().post_event(Box::new(move || {
+        sixtyfps_corelib::future::spawn_local(create_future());
+    }));
 }
 
 pub fn start_ui_event_loop() {
@@ -139,10 +116,10 @@ pub fn load_preview(
         return;
     }
     PENDING_EVENTS.fetch_add(1, Ordering::SeqCst);
-    run_in_ui_thread(Box::pin(async move {
+    run_in_ui_thread(move || async move {
         PENDING_EVENTS.fetch_sub(1, Ordering::SeqCst);
         reload_preview(sender, component, post_load_behavior).await
-    }));
+    });
 }
 
 #[derive(Default, Clone)]
@@ -217,9 +194,9 @@ async fn reload_preview(
         if let Some(component) = &preview_component.component {
             from_cache = format!("{}\n_Preview := {} {{ }}\n", from_cache, component);
         }
-        builder.build_from_source(from_cache, preview_component.path).await
+        builder.build_from_source(from_cache, preview_component.path.clone()).await
     } else {
-        builder.build_from_path(preview_component.path).await
+        builder.build_from_path(&preview_component.path).await
     };
 
     notify_diagnostics(builder.diagnostics(), &sender);
@@ -228,13 +205,26 @@ async fn reload_preview(
         #[derive(Default)]
         struct PreviewState {
             handle: Option<sixtyfps_interpreter::ComponentInstance>,
+            /// The component shown by `handle`
+            component: PreviewComponent,
         }
         thread_local! {static PREVIEW_STATE: std::cell::RefCell<PreviewState> = Default::default();}
         PREVIEW_STATE.with(|preview_state| {
             let mut preview_state = preview_state.borrow_mut();
+            let same_component = preview_state.component.path == preview_component.path
+                && preview_state.component.component == preview_component.component;
+            preview_state.component = preview_component;
             if let Some(handle) = preview_state.handle.take() {
-                let window = handle.window();
-                let handle = compiled.create_with_existing_window(window);
+                let handle = if same_component {
+                    // Keep the state of the preview while editing the component
+                    let (handle, report) = handle.reload_from(&compiled);
+                    for issue in report.issues() {
+                        eprintln!("Preview: {}", issue);
+                    }
+                    handle
+                } else {
+                    compiled.create_with_existing_window(handle.window())
+                };
                 match post_load_behavior {
                     PostLoadBehavior::ShowAfterLoad => handle.show(),
                     PostLoadBehavior::DoNothing => {}
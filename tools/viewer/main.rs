@@ -6,12 +6,9 @@
 use sixtyfps_corelib::model::{Model, ModelHandle};
 use sixtyfps_corelib::SharedVector;
 use sixtyfps_interpreter::{ComponentInstance, SharedString, Value};
-use std::future::Future;
-use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
-use std::task::Wake;
 use std::time::Duration;
 
 use clap::Parser;
@@ -214,7 +211,10 @@ fn start_fswatch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatche
                 && PENDING_EVENTS.load(Ordering::SeqCst) == 0
             {
                 PENDING_EVENTS.fetch_add(1, Ordering::SeqCst);
-                run_in_ui_thread(Box::pin(reload(args.clone(), w2.clone())));
+                let (args, w2) = (args.clone(), w2.clone());
+                sixtyfps_rendering_backend_default::backend().post_event(Box::new(move || {
+                    sixtyfps_corelib::future::spawn_local(reload(args, w2));
+                }));
             }
         }
     });
@@ -230,8 +230,10 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
         CURRENT_INSTANCE.with(|current| {
             let mut current = current.borrow_mut();
             if let Some(handle) = current.take() {
-                let window = handle.window();
-                let new_handle = c.create_with_existing_window(window);
+                let (new_handle, report) = handle.reload_from(&c);
+                for issue in report.issues() {
+                    eprintln!("Warning: {}", issue);
+                }
                 init_dialog(&new_handle);
                 current.replace(new_handle);
             } else {
@@ -339,33 +341,3 @@ fn execute_cmd(cmd: &str, callback_args: &[Value]) -> Result<()> {
     command.spawn()?;
     Ok(())
 }
-
-/// This type is duplicated with lsp/preview.rs
-struct FutureRunner {
-    fut: Mutex<Option<Pin<Box<dyn Future<Output = ()>>>>>,
-}
-
-/// Safety: the future is only going to be run in the UI thread
-unsafe impl Send for FutureRunner {}
-/// Safety: the future is only going to be run in the UI thread
-unsafe impl Sync for FutureRunner {}
-
-impl Wake for FutureRunner {
-    fn wake(self: Arc<Self>) {
-        sixtyfps_rendering_backend_default::backend().post_event(Box::new(move || {
-            let waker = self.clone().into();
-            let mut cx = std::task::Context::from_waker(&waker);
-            let mut fut_opt = self.fut.lock().unwrap();
-            if let Some(fut) = &mut *fut_opt {
-                match fut.as_mut().poll(&mut cx) {
-                    std::task::Poll::Ready(_) => *fut_opt = None,
-                    std::task::Poll::Pending => {}
-                }
-            }
-        }));
-    }
-}
-
-fn run_in_ui_thread(fut: Pin<Box<dyn Future<Output = ()>>>) {
-    Arc::new(FutureRunner { fut: Mutex::new(Some(fut)) }).wake()
-}